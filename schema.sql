@@ -6,12 +6,39 @@
 CREATE DATABASE IF NOT EXISTS classicmap CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;
 USE classicmap;
 
+-- 검색용 FULLTEXT 인덱스는 ngram 파서 사용 (MySQL 8.0, ngram_token_size 기본값 2)
+-- 기본 불용어 목록은 한 글자 영단어(a, i 등)를 포함한 토큰을 모두 빼버리므로 인덱스 생성 전에 끔
+-- 기존 DB: SET SESSION innodb_ft_enable_stopword = OFF; 후 아래 ALTER TABLE 실행
+--   ALTER TABLE concerts ADD FULLTEXT INDEX ft_concerts_title (title) WITH PARSER ngram;
+--   ALTER TABLE concerts ADD FULLTEXT INDEX ft_concerts_search (title, composer_info, cast, facility_name) WITH PARSER ngram;
+--   ALTER TABLE artists ADD FULLTEXT INDEX ft_artists_name (name, english_name) WITH PARSER ngram;
+--   ALTER TABLE artists ADD FULLTEXT INDEX ft_artists_search (name, english_name, category, nationality, bio, style) WITH PARSER ngram;
+--   ALTER TABLE composers ADD FULLTEXT INDEX ft_composers_name (name, full_name, english_name) WITH PARSER ngram;
+--   ALTER TABLE pieces ADD FULLTEXT INDEX ft_pieces_search (title, title_en, description) WITH PARSER ngram;
+--   ALTER TABLE venues ADD FULLTEXT INDEX ft_venues_search (name, address, city) WITH PARSER ngram;
+--   ALTER TABLE recordings ADD FULLTEXT INDEX ft_recordings_search (title, label) WITH PARSER ngram;
+SET SESSION innodb_ft_enable_stopword = OFF;
+
 -- ============================================
 -- 기존 테이블 및 뷰 삭제
 -- ============================================
 DROP VIEW IF EXISTS v_artists_full;
-DROP VIEW IF EXISTS v_composers_full;
-
+DROP VIEW IF EXISTS v_composers_full; -- 미사용 (주요 곡은 composer_major_pieces에서 직접 조회)
+
+DROP TABLE IF EXISTS composer_relations;
+DROP TABLE IF EXISTS artist_memberships;
+DROP TABLE IF EXISTS competition_results;
+DROP TABLE IF EXISTS competition_editions;
+DROP TABLE IF EXISTS competitions;
+DROP TABLE IF EXISTS artist_similarities;
+DROP TABLE IF EXISTS concert_prices;
+DROP TABLE IF EXISTS concert_artists;
+DROP TABLE IF EXISTS featured_settings;
+DROP TABLE IF EXISTS featured_slots;
+DROP TABLE IF EXISTS boxoffice_snapshots;
+DROP TABLE IF EXISTS kopis_pending_concerts;
+DROP TABLE IF EXISTS scheduler_jobs;
+DROP TABLE IF EXISTS kopis_sync_settings;
 DROP TABLE IF EXISTS sync_metadata;
 DROP TABLE IF EXISTS user_favorite_pieces; -- 미사용
 DROP TABLE IF EXISTS user_favorite_artists; -- 미사용
@@ -49,12 +76,13 @@ CREATE TABLE composers (
     cover_image_url VARCHAR(500) COMMENT '커버 이미지 URL',
     bio TEXT COMMENT '소개',
     style TEXT COMMENT '음악 스타일',
-    influence TEXT COMMENT '영향력',
+    influence TEXT COMMENT '영향력 (자유 서술, composer_relations로 옮기는 중)',
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     INDEX idx_period (period),
     INDEX idx_tier (tier),
-    INDEX idx_birth_year (birth_year)
+    INDEX idx_birth_year (birth_year),
+    FULLTEXT INDEX ft_composers_name (name, full_name, english_name) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ============================================
@@ -63,23 +91,50 @@ CREATE TABLE composers (
 CREATE TABLE pieces (
     id INT AUTO_INCREMENT PRIMARY KEY,
     composer_id INT NOT NULL,
+    -- 기존 DB: ALTER TABLE pieces MODIFY type ENUM('album', 'song', 'collection', 'work', 'movement') NOT NULL DEFAULT 'work';
+    --          UPDATE pieces SET type = 'collection' WHERE type = 'album';
+    --          UPDATE pieces SET type = 'work' WHERE type = 'song';
+    --          ALTER TABLE pieces MODIFY type ENUM('collection', 'work', 'movement') NOT NULL DEFAULT 'work',
+    --          ADD COLUMN parent_id INT AFTER composer_id, ADD COLUMN position INT NOT NULL DEFAULT 0 AFTER parent_id,
+    --          ADD COLUMN tempo_marking VARCHAR(100) AFTER duration_minutes,
+    --          ADD FOREIGN KEY (parent_id) REFERENCES pieces(id) ON DELETE SET NULL,
+    --          ADD INDEX idx_parent_position (parent_id, position);
+    --          (parent_id를 CASCADE로 추가한 DB: SHOW CREATE TABLE pieces로 FK 이름 확인 후
+    --          ALTER TABLE pieces DROP FOREIGN KEY pieces_ibfk_2,
+    --          ADD FOREIGN KEY (parent_id) REFERENCES pieces(id) ON DELETE SET NULL;)
+    parent_id INT COMMENT '상위 곡 (악장 → 작품, 작품 → 모음집)',
+    position INT NOT NULL DEFAULT 0 COMMENT '상위 곡 안에서의 순서',
     title VARCHAR(300) NOT NULL COMMENT '곡 제목',
     title_en VARCHAR(300) COMMENT '곡 영문 제목',
-    type ENUM('album', 'song') NOT NULL DEFAULT 'song' COMMENT '곡 타입 (album: 앨범/모음집, song: 단일곡)',
+    type ENUM('collection', 'work', 'movement') NOT NULL DEFAULT 'work' COMMENT '곡 단위 (collection: 모음집, work: 작품, movement: 악장)',
     description TEXT COMMENT '곡 설명',
     opus_number VARCHAR(50) COMMENT 'Opus 번호',
+    -- 기존 DB: ALTER TABLE pieces ADD COLUMN catalog_system VARCHAR(10) AFTER opus_number,
+    --          ADD COLUMN catalog_number INT AFTER catalog_system, ADD COLUMN catalog_sub_number INT AFTER catalog_number,
+    --          ADD COLUMN catalog_range_end INT AFTER catalog_sub_number,
+    --          ADD INDEX idx_catalog (catalog_system, catalog_number, catalog_sub_number);
+    --          이후 POST /api/pieces/catalog/reparse로 기존 opus_number에서 채움
+    catalog_system VARCHAR(10) COMMENT '작품 번호 체계 (Op., BWV, K., D., Hob., WoO)',
+    catalog_number INT COMMENT '작품 번호 (Hob.은 분류 그룹)',
+    catalog_sub_number INT COMMENT '세부 번호 (Op. 9 No. 2의 2, Hob. XVI:52의 52)',
+    catalog_range_end INT COMMENT '범위 끝 번호 (BWV 1046-1051의 1051)',
     composition_year INT COMMENT '작곡 연도',
     difficulty_level INT COMMENT '난이도 (1-10)',
     duration_minutes INT COMMENT '연주 시간 (분)',
+    tempo_marking VARCHAR(100) COMMENT '빠르기말 (예: Allegro con brio)',
     spotify_url VARCHAR(500) COMMENT 'Spotify 링크',
     apple_music_url VARCHAR(500) COMMENT 'Apple Music 링크',
     youtube_music_url VARCHAR(500) COMMENT 'YouTube Music 링크',
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     FOREIGN KEY (composer_id) REFERENCES composers(id) ON DELETE CASCADE,
+    FOREIGN KEY (parent_id) REFERENCES pieces(id) ON DELETE SET NULL,
     INDEX idx_composer_id (composer_id),
+    INDEX idx_parent_position (parent_id, position),
     INDEX idx_type (type),
-    INDEX idx_difficulty_level (difficulty_level)
+    INDEX idx_difficulty_level (difficulty_level),
+    INDEX idx_catalog (catalog_system, catalog_number, catalog_sub_number),
+    FULLTEXT INDEX ft_pieces_search (title, title_en, description) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ============================================
@@ -106,23 +161,55 @@ CREATE TABLE artists (
     name VARCHAR(100) NOT NULL COMMENT '한글명',
     english_name VARCHAR(200) NOT NULL COMMENT '영문명',
     category VARCHAR(50) NOT NULL COMMENT '악기/분야 (피아니스트, 바이올리니스트 등)',
+    -- 기존 DB: ALTER TABLE artists ADD COLUMN artist_type ENUM('soloist', 'conductor', 'orchestra', 'chamber_ensemble', 'choir') NOT NULL DEFAULT 'soloist' AFTER category,
+    --          ADD INDEX idx_artist_type (artist_type);
+    --          UPDATE artists SET artist_type = 'conductor' WHERE category = '지휘자';
+    --          -- v_artists_full의 a.*는 뷰 생성 시점 컬럼으로 고정되므로 뷰도 다시 생성
+    --          CREATE OR REPLACE VIEW v_artists_full AS
+    --          SELECT a.*,
+    --              GROUP_CONCAT(DISTINCT CONCAT(aw.year, ':', aw.award_name) ORDER BY aw.display_order SEPARATOR '|') as awards,
+    --              top_aw.award_name as top_award_name, top_aw.year as top_award_year, top_aw.ranking as top_award_ranking
+    --          FROM artists a
+    --          LEFT JOIN artist_awards aw ON a.id = aw.artist_id
+    --          LEFT JOIN artist_awards top_aw ON a.top_award_id = top_aw.id
+    --          GROUP BY a.id;
+    artist_type ENUM('soloist', 'conductor', 'orchestra', 'chamber_ensemble', 'choir') NOT NULL DEFAULT 'soloist' COMMENT '유형 (독주자, 지휘자, 오케스트라, 실내악단, 합창단)',
     tier ENUM('S', 'A', 'B', 'Rising') NOT NULL DEFAULT 'B' COMMENT '티어',
-    rating DECIMAL(2,1) DEFAULT 0.0 COMMENT '평점 (0.0-5.0)',
+    rating DECIMAL(2,1) DEFAULT 0.0 COMMENT '평점 (0.0-5.0, 출연 공연 사용자 평점과 연주 영상 평점 평균)',
     image_url VARCHAR(500) COMMENT '프로필 이미지 URL',
     cover_image_url VARCHAR(500) COMMENT '커버 이미지 URL',
     birth_year VARCHAR(10) COMMENT '출생연도',
     nationality VARCHAR(50) NOT NULL COMMENT '국적',
     bio TEXT COMMENT '소개',
     style TEXT COMMENT '연주 스타일',
-    concert_count INT DEFAULT 0 COMMENT '공연 횟수',
+    -- 통계 (concert_artists, recordings, 평점에서 계산, artist_stats 작업이 갱신)
+    -- 기존 DB: ALTER TABLE artists ADD COLUMN upcoming_concert_count INT DEFAULT 0 AFTER concert_count,
+    --          ADD COLUMN past_concert_count INT DEFAULT 0 AFTER upcoming_concert_count,
+    --          ADD COLUMN stats_override BOOLEAN DEFAULT FALSE AFTER album_count;
+    --          -- v_artists_full의 a.*는 뷰 생성 시점 컬럼으로 고정되므로 뷰도 다시 생성
+    --          CREATE OR REPLACE VIEW v_artists_full AS
+    --          SELECT a.*,
+    --              GROUP_CONCAT(DISTINCT CONCAT(aw.year, ':', aw.award_name) ORDER BY aw.display_order SEPARATOR '|') as awards,
+    --              top_aw.award_name as top_award_name, top_aw.year as top_award_year, top_aw.ranking as top_award_ranking
+    --          FROM artists a
+    --          LEFT JOIN artist_awards aw ON a.id = aw.artist_id
+    --          LEFT JOIN artist_awards top_aw ON a.top_award_id = top_aw.id
+    --          GROUP BY a.id;
+    concert_count INT DEFAULT 0 COMMENT '공연 횟수 (예정 + 지난 공연)',
+    upcoming_concert_count INT DEFAULT 0 COMMENT '예정/진행 중 공연 수',
+    past_concert_count INT DEFAULT 0 COMMENT '지난 공연 수',
     album_count INT DEFAULT 0 COMMENT '음반 수',
+    stats_override BOOLEAN DEFAULT FALSE COMMENT '통계 수동 지정 (TRUE면 자동 계산하지 않음)',
     top_award_id INT COMMENT '대표 수상 ID',
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     INDEX idx_category (category),
+    INDEX idx_artist_type (artist_type),
     INDEX idx_tier (tier),
     INDEX idx_rating (rating),
-    INDEX idx_top_award (top_award_id)
+    INDEX idx_top_award (top_award_id),
+    FULLTEXT INDEX ft_artists_name (name, english_name) WITH PARSER ngram,
+    FULLTEXT INDEX ft_artists_search (name, english_name, category, nationality, bio, style) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 
@@ -203,7 +290,8 @@ CREATE TABLE recordings (
     INDEX idx_year (year),
     INDEX idx_release_date (release_date),
     INDEX idx_apple_music_id (apple_music_id),
-    INDEX idx_upc (upc)
+    INDEX idx_upc (upc),
+    FULLTEXT INDEX ft_recordings_search (title, label) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
 
 -- ============================================
@@ -236,7 +324,8 @@ CREATE TABLE venues (
     INDEX idx_kopis_id (kopis_id),
     INDEX idx_name (name),
     INDEX idx_location (country, province, city),
-    INDEX idx_data_source (data_source)
+    INDEX idx_data_source (data_source),
+    FULLTEXT INDEX ft_venues_search (name, address, city) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
 COMMENT='공연장 정보 테이블';
 
@@ -310,6 +399,14 @@ CREATE TABLE concerts (
 
     -- 가격 및 미디어 정보
     price_info TEXT COMMENT '가격 정보 (pcseguidance)',
+    -- 가격 요약 (price_info 저장 시 concert_prices와 함께 갱신)
+    -- 기존 DB: ALTER TABLE concerts ADD COLUMN min_price INT AFTER price_info, ADD COLUMN max_price INT AFTER min_price,
+    --          ADD COLUMN is_free BOOLEAN DEFAULT FALSE AFTER max_price, ADD COLUMN is_price_undecided BOOLEAN DEFAULT TRUE AFTER is_free,
+    --          ADD INDEX idx_price (min_price, max_price);
+    min_price INT COMMENT '최저 가격 (원, price_info에서 추출, 무료=0)',
+    max_price INT COMMENT '최고 가격 (원, price_info에서 추출)',
+    is_free BOOLEAN DEFAULT FALSE COMMENT '전석 무료 여부',
+    is_price_undecided BOOLEAN DEFAULT TRUE COMMENT '가격 미정 여부 (price_info에 금액 없음)',
     poster_url VARCHAR(500) COMMENT '포스터 이미지 URL',
     program TEXT COMMENT '프로그램 상세',
 
@@ -320,7 +417,10 @@ CREATE TABLE concerts (
     is_festival BOOLEAN DEFAULT FALSE COMMENT '축제공연 여부 (festival)',
 
     -- 상태 및 평점
-    status ENUM('upcoming', 'ongoing', 'completed', 'cancelled', '공연예정', '공연중', '공연완료') DEFAULT 'upcoming' COMMENT '공연 상태',
+    -- stale: KOPIS 목록/상세에서 사라진 공연 (삭제하지 않고 목록에서만 제외)
+    -- 기존 DB: UPDATE concerts SET status = CASE status WHEN '공연예정' THEN 'upcoming' WHEN '공연중' THEN 'ongoing' WHEN '공연완료' THEN 'completed' ELSE status END;
+    --          이후 아래 ENUM으로 ALTER TABLE concerts MODIFY status ...
+    status ENUM('upcoming', 'ongoing', 'completed', 'cancelled', 'stale') DEFAULT 'upcoming' COMMENT '공연 상태',
     rating DECIMAL(2,1) DEFAULT 0.0 COMMENT '평균 평점 (0.0-5.0)',
     rating_count INT DEFAULT 0 COMMENT '평점 개수',
 
@@ -346,7 +446,10 @@ CREATE TABLE concerts (
     INDEX idx_is_visit (is_visit),
     INDEX idx_is_child (is_child),
     INDEX idx_is_daehakro (is_daehakro),
-    INDEX idx_is_festival (is_festival)
+    INDEX idx_is_festival (is_festival),
+    INDEX idx_price (min_price, max_price),
+    FULLTEXT INDEX ft_concerts_title (title) WITH PARSER ngram,
+    FULLTEXT INDEX ft_concerts_search (title, composer_info, cast, facility_name) WITH PARSER ngram
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
 COMMENT='공연 정보 테이블 (KOPIS API 연동 지원)';
 
@@ -391,11 +494,15 @@ CREATE TABLE performance_sectors (
     id INT AUTO_INCREMENT PRIMARY KEY,
     piece_id INT NOT NULL,
     sector_name VARCHAR(200) NOT NULL COMMENT '섹터명 (예: 1악장, 빠른 템포, 라이브 버전)',
+    -- 기존 DB: ALTER TABLE performance_sectors ADD COLUMN movement_id INT AFTER piece_id,
+    --          ADD FOREIGN KEY (movement_id) REFERENCES pieces(id) ON DELETE SET NULL;
+    movement_id INT COMMENT '섹터가 나타내는 악장 (piece_id의 하위 곡)',
     description TEXT COMMENT '섹터 설명',
     display_order INT DEFAULT 0 COMMENT '표시 순서',
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     FOREIGN KEY (piece_id) REFERENCES pieces(id) ON DELETE CASCADE,
+    FOREIGN KEY (movement_id) REFERENCES pieces(id) ON DELETE SET NULL,
     INDEX idx_piece_id (piece_id),
     INDEX idx_display_order (display_order),
     INDEX idx_piece_sector_order (piece_id, display_order)
@@ -409,6 +516,9 @@ CREATE TABLE performances (
     id INT AUTO_INCREMENT PRIMARY KEY,
     sector_id INT NOT NULL COMMENT '섹터 ID (FK to performance_sectors)',
     piece_id INT NOT NULL COMMENT '곡 ID (하위 호환성)',
+    -- 기존 DB: ALTER TABLE performances ADD COLUMN movement_id INT AFTER piece_id,
+    --          ADD FOREIGN KEY (movement_id) REFERENCES pieces(id) ON DELETE SET NULL, ADD INDEX idx_movement_id (movement_id);
+    movement_id INT COMMENT '악장만 연주한 영상이면 악장 ID (NULL이면 작품 전체)',
     artist_id INT NOT NULL,
     video_platform ENUM('youtube', 'vimeo', 'other') DEFAULT 'youtube',
     video_id VARCHAR(100) NOT NULL COMMENT '플랫폼별 비디오 ID',
@@ -421,9 +531,11 @@ CREATE TABLE performances (
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
     FOREIGN KEY (sector_id) REFERENCES performance_sectors(id) ON DELETE CASCADE,
     FOREIGN KEY (piece_id) REFERENCES pieces(id) ON DELETE CASCADE,
+    FOREIGN KEY (movement_id) REFERENCES pieces(id) ON DELETE SET NULL,
     FOREIGN KEY (artist_id) REFERENCES artists(id) ON DELETE CASCADE,
     INDEX idx_sector_id (sector_id),
     INDEX idx_piece_id (piece_id),
+    INDEX idx_movement_id (movement_id),
     INDEX idx_artist_id (artist_id),
     INDEX idx_rating (rating),
     INDEX idx_piece_sector (piece_id, sector_id)
@@ -520,8 +632,9 @@ CREATE TABLE concert_boxoffice_rankings (
     area_name VARCHAR(50) COMMENT '지역명 (서울, 경기, 인천 등)',
 
     -- 순위 정보
-    ranking INT NOT NULL COMMENT '순위 (1-3만 저장)',
-    seat_scale VARCHAR(20) COMMENT '좌석규모 (100, 300, 500, 1000, 5000, 10000)',
+    period VARCHAR(10) NOT NULL DEFAULT 'month' COMMENT '집계 기간 (day, week, month)',
+    seat_scale VARCHAR(20) NOT NULL DEFAULT '0' COMMENT '좌석규모 (0=전체, 100, 300, 500, 1000, 5000, 10000)',
+    ranking INT NOT NULL COMMENT '순위 (DB에 있는 공연만 저장)',
 
     -- KOPIS 예매상황판 응답 데이터
     performance_count INT DEFAULT 0 COMMENT '상연횟수 (prfdtcnt)',
@@ -534,22 +647,22 @@ CREATE TABLE concert_boxoffice_rankings (
     synced_at DATETIME DEFAULT CURRENT_TIMESTAMP COMMENT '동기화 시각',
 
     -- 메타 정보
-    is_featured BOOLEAN DEFAULT TRUE COMMENT '주목 공연 여부 (TOP 3는 항상 true)',
+    is_featured BOOLEAN DEFAULT FALSE COMMENT '주목 공연 여부 (월별, 전체 좌석규모 TOP 3)',
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
 
     -- 외래키 및 인덱스
     FOREIGN KEY (concert_id) REFERENCES concerts(id) ON DELETE CASCADE,
     INDEX idx_concert_id (concert_id),
-    INDEX idx_genre_area (kopis_genre_code, kopis_area_code),
+    INDEX idx_genre_area (kopis_genre_code, kopis_area_code, period, seat_scale),
     INDEX idx_ranking (ranking),
     INDEX idx_sync_dates (sync_start_date, sync_end_date),
     INDEX idx_featured (is_featured),
 
-    -- 장르/지역별로 1, 2, 3등 슬롯을 고정 (공연은 업데이트됨)
-    UNIQUE KEY unique_ranking_slot (kopis_genre_code, kopis_area_code, ranking)
+    -- 장르/지역/기간/좌석규모별 순위 슬롯을 고정 (공연은 업데이트됨)
+    UNIQUE KEY unique_ranking_slot (kopis_genre_code, kopis_area_code, period, seat_scale, ranking)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
-COMMENT='KOPIS 예매상황판 TOP 3 공연 순위 정보';
+COMMENT='KOPIS 예매상황판 최신 순위 정보';
 
 -- ============================================
 -- 21. 동기화 메타데이터 (Sync Metadata) 테이블
@@ -576,6 +689,257 @@ INSERT INTO sync_metadata (sync_type, last_sync_date, status) VALUES
 ('concerts', '2020-01-01', 'success'),
 ('boxoffice', '2020-01-01', 'success');
 
+-- ============================================
+-- 22. KOPIS 동기화 범위 설정 (KOPIS Sync Settings) 테이블
+-- ============================================
+-- 값이 없는 키는 환경변수(KOPIS_*) 또는 기본값 사용
+CREATE TABLE kopis_sync_settings (
+    setting_key VARCHAR(50) PRIMARY KEY COMMENT '설정 키 (concert_genres, boxoffice_genres, areas, lookahead_days, batch_days)',
+    setting_value TEXT NOT NULL COMMENT '설정 값 (코드 목록은 쉼표로 구분, 예: CCCA,GGGA)',
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='KOPIS 동기화 대상 장르/지역/조회 기간 설정';
+
+-- ============================================
+-- 23. 작업 스케줄러 (Scheduler Jobs) 테이블
+-- ============================================
+-- 실행 주기(cron)는 코드/환경변수(JOB_<NAME>_CRON)에서 관리, 여기는 상태만 저장
+CREATE TABLE scheduler_jobs (
+    job_name VARCHAR(50) PRIMARY KEY COMMENT '작업 이름 (venue_sync, concert_sync 등)',
+    enabled BOOLEAN NULL COMMENT '관리자 활성화 설정 (NULL이면 환경변수/기본값 사용)',
+    last_started_at TIMESTAMP NULL COMMENT '마지막 실행 시작 시각 (놓친 실행 보충 기준)',
+    last_finished_at TIMESTAMP NULL COMMENT '마지막 실행 종료 시각',
+    last_status ENUM('running', 'success', 'failed', 'skipped') NULL COMMENT '마지막 실행 결과',
+    last_message TEXT COMMENT '마지막 실행 요약 또는 에러 메시지',
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='백그라운드 작업 실행 상태';
+
+-- ============================================
+-- 24. 공연장 미확인 공연 대기열 (KOPIS Pending Concerts) 테이블
+-- ============================================
+-- 공연 동기화 시 공연장(mt10id)을 KOPIS에서도 찾지 못한 공연, 다음 동기화 때 재시도
+CREATE TABLE kopis_pending_concerts (
+    kopis_id VARCHAR(20) PRIMARY KEY COMMENT 'KOPIS 공연ID (mt20id)',
+    title VARCHAR(300) NOT NULL COMMENT '공연명',
+    venue_kopis_id VARCHAR(20) NOT NULL COMMENT 'KOPIS 공연시설ID (mt10id)',
+    attempts INT DEFAULT 0 COMMENT '재시도 횟수',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    INDEX idx_venue_kopis_id (venue_kopis_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='공연장 미확인으로 동기화되지 않은 KOPIS 공연';
+
+-- ============================================
+-- 25. 예매상황판 순위 이력 (Boxoffice Snapshots) 테이블
+-- ============================================
+-- 동기화마다 KOPIS가 돌려준 전체 순위 보관 (같은 날 재동기화 시 교체)
+-- DB에 없는 공연도 저장하므로 concerts와는 kopis_id로 연결
+CREATE TABLE boxoffice_snapshots (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    snapshot_date DATE NOT NULL COMMENT '수집일 (KST)',
+    kopis_genre_code VARCHAR(10) NOT NULL COMMENT 'KOPIS 장르 코드',
+    kopis_area_code VARCHAR(10) NOT NULL DEFAULT '00' COMMENT 'KOPIS 지역 코드 (00=전국)',
+    period VARCHAR(10) NOT NULL DEFAULT 'day' COMMENT '집계 기간 (day, week, month)',
+    seat_scale VARCHAR(20) NOT NULL DEFAULT '0' COMMENT '좌석규모 (0=전체)',
+    ranking INT NOT NULL COMMENT '순위',
+    kopis_id VARCHAR(20) NOT NULL COMMENT 'KOPIS 공연ID (mt20id)',
+    performance_name VARCHAR(300) NOT NULL COMMENT '공연명 (prfnm)',
+    venue_name VARCHAR(200) COMMENT '공연장명 (prfplcnm)',
+    seat_count INT COMMENT '좌석수 (seatcnt)',
+    performance_count INT COMMENT '상연횟수 (prfdtcnt)',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_snapshot_rank (snapshot_date, kopis_genre_code, kopis_area_code, period, seat_scale, ranking),
+    INDEX idx_kopis_id_date (kopis_id, snapshot_date),
+    INDEX idx_scope_date (kopis_genre_code, kopis_area_code, period, seat_scale, snapshot_date)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='예매상황판 일별 순위 이력';
+
+-- ============================================
+-- 26. 홈 주목 공연 고정 (Featured Slots) 테이블
+-- ============================================
+-- 모더레이터가 지역별 홈 화면에 공연을 기간 지정으로 고정 (00=전국, 모든 지역에 노출)
+CREATE TABLE featured_slots (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    concert_id INT NOT NULL,
+    area_code VARCHAR(10) NOT NULL DEFAULT '00' COMMENT 'KOPIS 지역 코드 (00=전국)',
+    start_date DATE NOT NULL COMMENT '노출 시작일 (KST)',
+    end_date DATE NOT NULL COMMENT '노출 종료일 (KST, 포함)',
+    display_order INT NOT NULL DEFAULT 0 COMMENT '노출 순서 (작을수록 먼저)',
+    note VARCHAR(255) COMMENT '고정 사유 (관리용 메모)',
+    created_by INT COMMENT '등록한 사용자',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (concert_id) REFERENCES concerts(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE SET NULL,
+    INDEX idx_area_period (area_code, start_date, end_date)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='홈 주목 공연 고정 슬롯';
+
+-- ============================================
+-- 27. 주목 공연 구성 정책 (Featured Settings) 테이블
+-- ============================================
+CREATE TABLE featured_settings (
+    setting_key VARCHAR(50) PRIMARY KEY COMMENT '설정 키 (sources, limit, boxoffice_top, rating_limit, min_rating, min_rating_count)',
+    setting_value TEXT NOT NULL COMMENT '설정 값 (sources는 쉼표로 구분, 예: pinned,boxoffice,rating)',
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='주목 공연 구성 정책 (고정/예매 순위/평점 순서와 개수)';
+
+-- ============================================
+-- 28. 공연 출연 아티스트 (Concert Artists) 테이블
+-- ============================================
+-- KOPIS 출연진(prfcast)을 아티스트 이름과 매칭해 저장
+CREATE TABLE concert_artists (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    concert_id INT NOT NULL,
+    artist_id INT NOT NULL,
+    role VARCHAR(100) COMMENT '역할 (협연, 지휘 등)',
+    -- 기존 DB: ALTER TABLE concert_artists ADD COLUMN ensemble_id INT AFTER role,
+    --          ADD FOREIGN KEY (ensemble_id) REFERENCES artists(id) ON DELETE SET NULL;
+    ensemble_id INT COMMENT '함께 출연한 앙상블 (지휘자/협연자가 어느 오케스트라와 함께인지)',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (concert_id) REFERENCES concerts(id) ON DELETE CASCADE,
+    FOREIGN KEY (artist_id) REFERENCES artists(id) ON DELETE CASCADE,
+    FOREIGN KEY (ensemble_id) REFERENCES artists(id) ON DELETE SET NULL,
+    UNIQUE KEY unique_concert_artist (concert_id, artist_id),
+    INDEX idx_artist_id (artist_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='공연 출연 아티스트';
+
+-- ============================================
+-- 29. 공연 좌석 등급별 가격 (Concert Prices) 테이블
+-- ============================================
+-- price_info(KOPIS pcseguidance)를 분석해 저장 (공연 동기화/수정 시 다시 생성)
+CREATE TABLE concert_prices (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    concert_id INT NOT NULL,
+    seat_class VARCHAR(100) COMMENT '좌석 등급 (R석, 전석 등, 안내에 없으면 NULL)',
+    amount INT NOT NULL COMMENT '가격 (원, 무료=0)',
+    display_order INT NOT NULL DEFAULT 0 COMMENT '안내 순서',
+    FOREIGN KEY (concert_id) REFERENCES concerts(id) ON DELETE CASCADE,
+    INDEX idx_concert_id (concert_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='공연 좌석 등급별 가격';
+
+-- ============================================
+-- 30. 관련 아티스트 (Artist Similarities) 테이블
+-- ============================================
+-- 함께 출연한 공연, 같은 곡 연주, 공연 평점을 함께 준 사용자, 분야/티어/국적으로 계산 (artist_similarity 작업이 다시 생성)
+CREATE TABLE artist_similarities (
+    artist_id INT NOT NULL,
+    related_artist_id INT NOT NULL,
+    score DOUBLE NOT NULL COMMENT '유사도 점수',
+    shared_concerts INT NOT NULL DEFAULT 0 COMMENT '함께 출연한 공연 수',
+    shared_pieces INT NOT NULL DEFAULT 0 COMMENT '둘 다 연주 영상이 있는 곡 수',
+    co_rating_users INT NOT NULL DEFAULT 0 COMMENT '두 아티스트 공연을 모두 높게 평가한 사용자 수',
+    same_category BOOLEAN NOT NULL DEFAULT FALSE,
+    same_tier BOOLEAN NOT NULL DEFAULT FALSE,
+    same_nationality BOOLEAN NOT NULL DEFAULT FALSE,
+    computed_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (artist_id, related_artist_id),
+    FOREIGN KEY (artist_id) REFERENCES artists(id) ON DELETE CASCADE,
+    FOREIGN KEY (related_artist_id) REFERENCES artists(id) ON DELETE CASCADE,
+    INDEX idx_artist_score (artist_id, score)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='관련 아티스트 추천';
+
+-- ============================================
+-- 31. 대회 (Competitions) 테이블
+-- ============================================
+-- 콩쿠르 카탈로그 (artist_awards의 자유 입력 수상 내역은 이름/영문명/별칭으로 매칭)
+CREATE TABLE competitions (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(200) NOT NULL COMMENT '한글명',
+    english_name VARCHAR(200) COMMENT '영문명',
+    organization VARCHAR(200) COMMENT '주관 기관',
+    instrument VARCHAR(100) COMMENT '부문 악기 (피아노, 바이올린, 성악 등, 여러 부문이면 NULL)',
+    cycle_years INT COMMENT '개최 주기 (년, 매년=1)',
+    aliases TEXT COMMENT '수상 내역 매칭용 별칭 (쉼표로 구분)',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY unique_name (name),
+    INDEX idx_instrument (instrument)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='콩쿠르 카탈로그';
+
+-- ============================================
+-- 32. 대회 회차 (Competition Editions) 테이블
+-- ============================================
+CREATE TABLE competition_editions (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    competition_id INT NOT NULL,
+    year INT NOT NULL COMMENT '개최 연도',
+    edition_number INT COMMENT '회차 (제18회 → 18)',
+    FOREIGN KEY (competition_id) REFERENCES competitions(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_competition_year (competition_id, year)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='대회 회차';
+
+-- ============================================
+-- 33. 대회 입상 결과 (Competition Results) 테이블
+-- ============================================
+-- 수상 내역 매칭(POST /api/competitions/cluster)으로 만든 결과는 pending, 모더레이터가 확정/거절
+-- 기존 DB: 위 세 테이블 생성 후 POST /api/competitions/cluster 실행 → GET /api/competitions/results/pending 에서 확정
+CREATE TABLE competition_results (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    edition_id INT NOT NULL,
+    artist_id INT NOT NULL,
+    placement VARCHAR(50) NOT NULL COMMENT '순위 표기 (1st Prize, 2위, Gold Medal 등)',
+    placement_rank INT COMMENT '등수 (우승=1, 알 수 없으면 NULL)',
+    award_id INT COMMENT '매칭된 원본 수상 내역',
+    status ENUM('pending', 'confirmed', 'rejected') NOT NULL DEFAULT 'confirmed' COMMENT '확정 상태',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (edition_id) REFERENCES competition_editions(id) ON DELETE CASCADE,
+    FOREIGN KEY (artist_id) REFERENCES artists(id) ON DELETE CASCADE,
+    FOREIGN KEY (award_id) REFERENCES artist_awards(id) ON DELETE SET NULL,
+    UNIQUE KEY unique_edition_artist_placement (edition_id, artist_id, placement),
+    UNIQUE KEY unique_award_id (award_id),
+    INDEX idx_status (status),
+    INDEX idx_artist_id (artist_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='대회 입상 결과';
+
+-- ============================================
+-- 34. 앙상블 단원 (Artist Memberships) 테이블
+-- ============================================
+-- 오케스트라/실내악단/합창단(ensemble_id)과 단원(member_id), 예: 악장, 상임 지휘자, 제1바이올린
+CREATE TABLE artist_memberships (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    ensemble_id INT NOT NULL COMMENT '앙상블 (artist_type이 orchestra, chamber_ensemble, choir)',
+    member_id INT NOT NULL COMMENT '단원',
+    role VARCHAR(100) COMMENT '역할 (악장, 상임 지휘자, 첼로 등)',
+    start_year INT COMMENT '시작 연도',
+    end_year INT COMMENT '종료 연도 (현재 단원이면 NULL)',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (ensemble_id) REFERENCES artists(id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES artists(id) ON DELETE CASCADE,
+    INDEX idx_ensemble_id (ensemble_id),
+    INDEX idx_member_id (member_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='앙상블 단원';
+
+-- ============================================
+-- 35. 작곡가 관계 (Composer Relations) 테이블
+-- ============================================
+-- from → to 방향: 스승 → 제자, 영향을 준 작곡가 → 받은 작곡가
+CREATE TABLE composer_relations (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    from_composer_id INT NOT NULL COMMENT '스승 / 영향을 준 작곡가',
+    to_composer_id INT NOT NULL COMMENT '제자 / 영향을 받은 작곡가',
+    relation_type ENUM('teacher', 'influence') NOT NULL COMMENT '관계 유형',
+    notes VARCHAR(500) COMMENT '설명',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    FOREIGN KEY (from_composer_id) REFERENCES composers(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_composer_id) REFERENCES composers(id) ON DELETE CASCADE,
+    UNIQUE KEY unique_composer_relation (from_composer_id, to_composer_id, relation_type),
+    INDEX idx_to_composer_id (to_composer_id)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci
+COMMENT='작곡가 관계 (스승, 영향)';
+
 -- ============================================
 -- 샘플 데이터 삽입
 -- ============================================
@@ -604,16 +968,23 @@ INSERT INTO composers (name, full_name, english_name, period, birth_year, death_
  '피아노 음악의 혁명, 리스트, 드뷔시 등에게 영향');
 
 -- 곡 샘플 데이터
-INSERT INTO pieces (composer_id, title, description, opus_number, composition_year) VALUES
-(1, '마태 수난곡', '바흐의 대표적인 종교 음악 작품', 'BWV 244', 1727),
-(1, '브란덴부르크 협주곡', '6곡으로 이루어진 협주곡 모음', 'BWV 1046-1051', 1721),
-(1, '골드베르크 변주곡', '30개의 변주로 이루어진 피아노 작품', 'BWV 988', 1741),
-(2, '피가로의 결혼', '모차르트의 대표 오페라', 'K. 492', 1786),
-(2, '돈 조반니', '드라마 지오코소 오페라', 'K. 527', 1787),
-(2, '교향곡 40번', '모차르트의 대표적인 교향곡', 'K. 550', 1788),
-(3, '발라드 1번', '쇼팽의 첫 번째 발라드', 'Op. 23', 1835),
-(3, '녹턴 작품 9-2', '쇼팽의 가장 유명한 녹턴', 'Op. 9 No. 2', 1832),
-(3, '에튀드 작품 10, 25', '쇼팽의 혁명적인 연습곡', 'Op. 10, Op. 25', 1833);
+INSERT INTO pieces (composer_id, title, description, opus_number, catalog_system, catalog_number, catalog_sub_number, catalog_range_end, composition_year) VALUES
+(1, '마태 수난곡', '바흐의 대표적인 종교 음악 작품', 'BWV 244', 'BWV', 244, NULL, NULL, 1727),
+(1, '브란덴부르크 협주곡', '6곡으로 이루어진 협주곡 모음', 'BWV 1046-1051', 'BWV', 1046, NULL, 1051, 1721),
+(1, '골드베르크 변주곡', '30개의 변주로 이루어진 피아노 작품', 'BWV 988', 'BWV', 988, NULL, NULL, 1741),
+(2, '피가로의 결혼', '모차르트의 대표 오페라', 'K. 492', 'K.', 492, NULL, NULL, 1786),
+(2, '돈 조반니', '드라마 지오코소 오페라', 'K. 527', 'K.', 527, NULL, NULL, 1787),
+(2, '교향곡 40번', '모차르트의 대표적인 교향곡', 'K. 550', 'K.', 550, NULL, NULL, 1788),
+(3, '발라드 1번', '쇼팽의 첫 번째 발라드', 'Op. 23', 'Op.', 23, NULL, NULL, 1835),
+(3, '녹턴 작품 9-2', '쇼팽의 가장 유명한 녹턴', 'Op. 9 No. 2', 'Op.', 9, 2, NULL, 1832),
+(3, '에튀드 작품 10, 25', '쇼팽의 혁명적인 연습곡', 'Op. 10, Op. 25', 'Op.', 10, NULL, NULL, 1833);
+
+-- 악장 샘플 데이터 (교향곡 40번)
+INSERT INTO pieces (composer_id, parent_id, position, title, type, tempo_marking, duration_minutes) VALUES
+(2, 6, 1, '1악장', 'movement', 'Molto allegro', 8),
+(2, 6, 2, '2악장', 'movement', 'Andante', 8),
+(2, 6, 3, '3악장', 'movement', 'Menuetto. Allegretto – Trio', 5),
+(2, 6, 4, '4악장', 'movement', 'Allegro assai', 7);
 
 -- 작곡가 주요 곡 연결
 INSERT INTO composer_major_pieces (composer_id, piece_id, display_order) VALUES
@@ -627,6 +998,12 @@ INSERT INTO composer_major_pieces (composer_id, piece_id, display_order) VALUES
 (3, 8, 2),
 (3, 9, 3);
 
+-- 작곡가 관계 샘플 데이터
+INSERT INTO composer_relations (from_composer_id, to_composer_id, relation_type, notes) VALUES
+(1, 2, 'influence', '모차르트는 반 스비텐 남작의 서재에서 바흐의 푸가를 연구하며 대위법을 익힘'),
+(1, 3, 'influence', '쇼팽은 평균율 클라비어곡집을 평생 연습했고 24개의 전주곡에 영향을 줌'),
+(2, 3, 'influence', '쇼팽이 가장 존경한 작곡가');
+
 -- 아티스트 샘플 데이터
 INSERT INTO artists (name, english_name, category, tier, rating, nationality, bio, style, concert_count, album_count, top_award_id) VALUES
 ('조성진', 'Seong-Jin Cho', '피아니스트', 'S', 4.9, '대한민국',
@@ -646,6 +1023,24 @@ INSERT INTO artist_awards (artist_id, year, award_name, award_type, organization
 (1, '2011', '차이콥스키 국제 콩쿠르 3위', 'Competition', 'Tchaikovsky Foundation', 'Piano Competition', '3rd Prize', 2),
 (2, '2022', '반 클라이번 국제 피아노 콩쿠르 1위', 'Competition', 'Van Cliburn Foundation', 'Piano Competition', '1st Prize', 1);
 
+-- 대회 카탈로그 샘플 데이터
+INSERT INTO competitions (name, english_name, organization, instrument, cycle_years, aliases) VALUES
+('쇼팽 국제 피아노 콩쿠르', 'International Chopin Piano Competition', 'Chopin Institute', '피아노', 5, '쇼팽 콩쿠르, Chopin Competition'),
+('차이콥스키 국제 콩쿠르', 'International Tchaikovsky Competition', 'Tchaikovsky Foundation', NULL, 4, '차이콥스키 콩쿠르, Tchaikovsky Competition'),
+('반 클라이번 국제 피아노 콩쿠르', 'Van Cliburn International Piano Competition', 'Van Cliburn Foundation', '피아노', 4, '반 클라이번 콩쿠르, Cliburn Competition'),
+('퀸 엘리자베스 콩쿠르', 'Queen Elisabeth Competition', 'Queen Elisabeth Competition', NULL, 1, '퀸 엘리자베스 국제 콩쿠르, Concours Reine Elisabeth');
+
+INSERT INTO competition_editions (competition_id, year, edition_number) VALUES
+(1, 2015, 17),
+(2, 2011, 14),
+(3, 2022, 16);
+
+-- 위 수상 내역과 연결된 확정 입상 결과
+INSERT INTO competition_results (edition_id, artist_id, placement, placement_rank, award_id) VALUES
+(1, 1, '1st Prize', 1, 1),
+(2, 1, '3rd Prize', 3, 2),
+(3, 2, '1st Prize', 1, 3);
+
 -- 아티스트 대표 수상 설정 (top_award_id 업데이트)
 UPDATE artists SET top_award_id = 1 WHERE id = 1; -- 조성진 -> 쇼팽 콩쿠르 1위
 UPDATE artists SET top_award_id = 3 WHERE id = 2; -- 임윤찬 -> 반 클라이번 1위
@@ -689,16 +1084,6 @@ UPDATE artists SET top_award_id = 3 WHERE id = 2; -- 임윤찬 -> 반 클라이
 -- API용 뷰 (Views) 생성
 -- ============================================
 
--- 작곡가 전체 정보 뷰 (주요 곡 포함)
-CREATE VIEW v_composers_full AS
-SELECT 
-    c.*,
-    GROUP_CONCAT(DISTINCT CONCAT(p.id, ':', p.title) ORDER BY cmp.display_order SEPARATOR '|') as major_pieces
-FROM composers c
-LEFT JOIN composer_major_pieces cmp ON c.id = cmp.composer_id
-LEFT JOIN pieces p ON cmp.piece_id = p.id
-GROUP BY c.id;
-
 -- 아티스트 전체 정보 뷰
 CREATE VIEW v_artists_full AS
 SELECT
@@ -1,4 +1,8 @@
-use super::model::{Artist, CreateArtist, UpdateArtist, ArtistWithAwards, CreateArtistAward};
+use super::model::{
+    Artist, ArtistProfile, ArtistWithAwards, AwardSearchFilter, AwardWithArtist, CreateArtist,
+    CreateArtistAward, CreateArtistMembership, MembershipWithArtist, ReorderArtistAwards,
+    UpdateArtist, UpdateArtistAward, UpdateArtistMembership, is_ensemble_type,
+};
 use super::service::ArtistService;
 use crate::auth::ModeratorUser;
 use crate::concert::model::Concert;
@@ -36,13 +40,45 @@ pub async fn get_artist(pool: &State<DbPool>, id: i32) -> Result<Json<Option<Art
     }
 }
 
+/// 아티스트 상세 페이지 (수상, 예정 공연, 지난 공연 페이지, 최근 음반, 곡별 연주 영상, 관련 아티스트)
+#[get("/artists/<id>/profile?<past_offset>&<past_limit>")]
+pub async fn get_artist_profile(
+    pool: &State<DbPool>,
+    id: i32,
+    past_offset: Option<i64>,
+    past_limit: Option<i64>,
+) -> Result<Json<ArtistProfile>, Status> {
+    match ArtistService::get_artist_profile(pool, id, past_offset, past_limit).await {
+        Ok(Some(profile)) => Ok(Json(profile)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get profile for artist {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
 #[post("/artists", data = "<artist>")]
 pub async fn create_artist(
     pool: &State<DbPool>,
     artist: Json<CreateArtist>,
     _moderator: ModeratorUser,
 ) -> Result<Json<i32>, Status> {
-    match ArtistService::create_artist(pool, artist.into_inner()).await {
+    if let Err(e) = artist.validate() {
+        Logger::warn("API", &format!("Invalid artist: {}", e));
+        return Err(Status::BadRequest);
+    }
+
+    // 대표 수상은 해당 아티스트의 수상이어야 하므로 생성 후 수정으로 지정
+    let mut artist = artist.into_inner();
+    if let Some(award_id) = artist.top_award_id.take() {
+        Logger::warn(
+            "API",
+            &format!("Ignoring topAwardId {} on artist create; set it after adding the award", award_id),
+        );
+    }
+
+    match ArtistService::create_artist(pool, artist).await {
         Ok(id) => Ok(Json(id)),
         Err(e) => {
             Logger::error("API", &format!("Failed to create artist: {}", e));
@@ -58,6 +94,25 @@ pub async fn update_artist(
     artist: Json<UpdateArtist>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
+    if let Err(e) = artist.validate() {
+        Logger::warn("API", &format!("Invalid artist {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+    // 대표 수상은 이 아티스트의 수상이어야 함
+    if let Some(award_id) = artist.top_award_id {
+        match ArtistService::get_artist_award(pool, id, award_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                Logger::warn("API", &format!("Award {} does not belong to artist {}", award_id, id));
+                return Err(Status::BadRequest);
+            }
+            Err(e) => {
+                Logger::error("API", &format!("Failed to get award {} for artist {}: {}", award_id, id, e));
+                return Err(Status::InternalServerError);
+            }
+        }
+    }
+
     match ArtistService::update_artist(pool, id, artist.into_inner()).await {
         Ok(rows) => Ok(Json(rows)),
         Err(e) => {
@@ -109,6 +164,55 @@ pub async fn create_artist_award(
     }
 }
 
+/// 수상 내역 순서 변경 (awardIds: 아티스트의 모든 수상 id를 표시 순서대로)
+#[put("/artists/<artist_id>/awards/order", data = "<order>")]
+pub async fn reorder_artist_awards(
+    pool: &State<DbPool>,
+    artist_id: i32,
+    order: Json<ReorderArtistAwards>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let current = match ArtistService::get_artist_awards(pool, artist_id).await {
+        Ok(current) => current,
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get awards for artist {}: {}", artist_id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+    if let Err(e) = order.validate(&current) {
+        Logger::warn("API", &format!("Invalid award order for artist {}: {}", artist_id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match ArtistService::reorder_artist_awards(pool, artist_id, &order.award_ids).await {
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to reorder awards for artist {}: {}", artist_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 다른 아티스트의 수상이면 404
+#[put("/artists/<artist_id>/awards/<award_id>", data = "<award>", rank = 2)]
+pub async fn update_artist_award(
+    pool: &State<DbPool>,
+    artist_id: i32,
+    award_id: i32,
+    award: Json<UpdateArtistAward>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match ArtistService::update_artist_award(pool, artist_id, award_id, award.into_inner()).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update award {} for artist {}: {}", award_id, artist_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 다른 아티스트의 수상이면 404, 대표 수상이었으면 남은 첫 번째 수상으로 바뀜
 #[delete("/artists/<artist_id>/awards/<award_id>")]
 pub async fn delete_artist_award(
     pool: &State<DbPool>,
@@ -116,7 +220,8 @@ pub async fn delete_artist_award(
     award_id: i32,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match ArtistService::delete_artist_award(pool, award_id).await {
+    match ArtistService::delete_artist_award(pool, artist_id, award_id).await {
+        Ok(0) => Err(Status::NotFound),
         Ok(rows) => Ok(Json(rows)),
         Err(e) => {
             Logger::error("API", &format!("Failed to delete award {} for artist {}: {}", award_id, artist_id, e));
@@ -125,6 +230,158 @@ pub async fn delete_artist_award(
     }
 }
 
+/// 전체 아티스트 수상 내역 검색 (예: ?q=쇼팽&ranking=1st Prize → 쇼팽 콩쿠르 우승자)
+/// 필터: q(상 이름/기관/부문), organization, award_type, ranking, year
+#[get("/awards?<offset>&<limit>&<filter..>")]
+pub async fn search_awards(
+    pool: &State<DbPool>,
+    offset: Option<i64>,
+    limit: Option<i64>,
+    filter: AwardSearchFilter,
+) -> Result<Json<Vec<AwardWithArtist>>, Status> {
+    match ArtistService::search_awards(pool, filter, offset, limit).await {
+        Ok(awards) => Ok(Json(awards)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to search awards: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 앙상블 단원 (current=true면 현재 단원만)
+#[get("/artists/<id>/members?<current>")]
+pub async fn get_artist_members(
+    pool: &State<DbPool>,
+    id: i32,
+    current: Option<bool>,
+) -> Result<Json<Vec<MembershipWithArtist>>, Status> {
+    match ArtistService::get_artist_members(pool, id, current.unwrap_or(false)).await {
+        Ok(members) => Ok(Json(members)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get members of artist {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 소속 앙상블 (current=true면 현재 소속만)
+#[get("/artists/<id>/affiliations?<current>")]
+pub async fn get_artist_affiliations(
+    pool: &State<DbPool>,
+    id: i32,
+    current: Option<bool>,
+) -> Result<Json<Vec<MembershipWithArtist>>, Status> {
+    match ArtistService::get_artist_affiliations(pool, id, current.unwrap_or(false)).await {
+        Ok(affiliations) => Ok(Json(affiliations)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get affiliations of artist {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 단원 추가 (앙상블 유형이 아니면 400)
+#[post("/artists/<id>/members", data = "<membership>")]
+pub async fn create_artist_membership(
+    pool: &State<DbPool>,
+    id: i32,
+    membership: Json<CreateArtistMembership>,
+    _moderator: ModeratorUser,
+) -> Result<Json<i32>, Status> {
+    let membership = membership.into_inner();
+    if let Err(e) = membership.validate() {
+        Logger::warn("API", &format!("Invalid membership for artist {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match ArtistService::get_artist_by_id(pool, id).await {
+        Ok(Some(ensemble)) if is_ensemble_type(&ensemble.artist_type) => {}
+        Ok(Some(ensemble)) => {
+            Logger::warn("API", &format!("Artist {} is not an ensemble ({})", id, ensemble.artist_type));
+            return Err(Status::BadRequest);
+        }
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get artist {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    }
+    if membership.member_id == id {
+        Logger::warn("API", &format!("Artist {} cannot be a member of itself", id));
+        return Err(Status::BadRequest);
+    }
+    match ArtistService::get_artist_by_id(pool, membership.member_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            Logger::warn("API", &format!("Member {} not found", membership.member_id));
+            return Err(Status::BadRequest);
+        }
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get artist {}: {}", membership.member_id, e));
+            return Err(Status::InternalServerError);
+        }
+    }
+
+    match ArtistService::create_artist_membership(pool, id, membership).await {
+        Ok(membership_id) => Ok(Json(membership_id)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to create membership for artist {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 다른 앙상블의 단원 관계면 404
+#[put("/artists/<artist_id>/members/<membership_id>", data = "<membership>")]
+pub async fn update_artist_membership(
+    pool: &State<DbPool>,
+    artist_id: i32,
+    membership_id: i32,
+    membership: Json<UpdateArtistMembership>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let current = match ArtistService::get_artist_membership(pool, artist_id, membership_id).await {
+        Ok(Some(current)) => current,
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get membership {} of artist {}: {}", membership_id, artist_id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+
+    let membership = membership.into_inner();
+    if let Err(e) = membership.validate(&current) {
+        Logger::warn("API", &format!("Invalid membership {}: {}", membership_id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match ArtistService::update_artist_membership(pool, artist_id, membership_id, membership).await {
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update membership {} of artist {}: {}", membership_id, artist_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 다른 앙상블의 단원 관계면 404
+#[delete("/artists/<artist_id>/members/<membership_id>")]
+pub async fn delete_artist_membership(
+    pool: &State<DbPool>,
+    artist_id: i32,
+    membership_id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match ArtistService::delete_artist_membership(pool, artist_id, membership_id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to delete membership {} of artist {}: {}", membership_id, artist_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
 #[get("/artists/search?<q>&<tier>&<category>&<offset>&<limit>")]
 pub async fn search_artists(
     pool: &State<DbPool>,
@@ -0,0 +1,24 @@
+use super::service::ArtistService;
+use crate::scheduler::{JobDefinition, JobFuture};
+use sqlx::MySqlPool;
+
+pub const ARTIST_STATS_JOB: &str = "artist_stats";
+
+/// 아티스트 통계 (공연 수, 음반 수, 평점) 재계산 (매시 20분)
+pub fn stats_recompute() -> JobDefinition {
+    JobDefinition {
+        name: ARTIST_STATS_JOB,
+        description: "아티스트 통계 재계산 (공연 수, 음반 수, 평점)",
+        cron: "0 20 * * * *",
+        depends_on: &[],
+        run: run_stats_recompute,
+    }
+}
+
+fn run_stats_recompute(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        ArtistService::recompute_stats(&pool)
+            .await
+            .map(|updated| format!("{} artists updated", updated).into())
+    })
+}
@@ -1,4 +1,5 @@
 pub mod api;
+pub mod jobs;
 pub mod model;
 pub mod repository;
 pub mod service;
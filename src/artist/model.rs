@@ -1,7 +1,22 @@
+use crate::concert::model::ConcertListItem;
+use crate::performance::model::Performance;
+use crate::recommendation::model::RecommendedArtist;
+use crate::recording::model::Recording;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+/// 아티스트 유형
+pub const ARTIST_TYPE_SOLOIST: &str = "soloist";
+pub const ARTIST_TYPE_CONDUCTOR: &str = "conductor";
+pub const ARTIST_TYPES: [&str; 5] = [ARTIST_TYPE_SOLOIST, ARTIST_TYPE_CONDUCTOR, "orchestra", "chamber_ensemble", "choir"];
+/// 단원을 둘 수 있는 유형
+pub const ENSEMBLE_TYPES: [&str; 3] = ["orchestra", "chamber_ensemble", "choir"];
+
+pub fn is_ensemble_type(artist_type: &str) -> bool {
+    ENSEMBLE_TYPES.contains(&artist_type)
+}
+
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 #[serde(rename_all = "camelCase")]
 pub struct Artist {
@@ -9,6 +24,8 @@ pub struct Artist {
     pub name: String,
     pub english_name: String,
     pub category: String,
+    /// soloist, conductor, orchestra, chamber_ensemble, choir
+    pub artist_type: String,
     pub tier: String,
     pub rating: Option<Decimal>,
     pub image_url: Option<String>,
@@ -17,8 +34,13 @@ pub struct Artist {
     pub nationality: String,
     pub bio: Option<String>,
     pub style: Option<String>,
+    // 통계 (연결된 공연/음반/평점에서 계산, 읽기 전용)
     pub concert_count: i32,
+    pub upcoming_concert_count: i32,
+    pub past_concert_count: i32,
     pub album_count: i32,
+    /// 통계를 수동으로 지정해 자동 계산에서 제외됨
+    pub stats_override: bool,
     pub top_award_id: Option<i32>,
 }
 
@@ -46,12 +68,66 @@ pub struct ArtistWithAwards {
     pub awards: Vec<ArtistAward>,
 }
 
+/// 아티스트 상세 페이지 응답 (한 번의 요청으로 필요한 데이터 전체)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistProfile {
+    #[serde(flatten)]
+    pub artist: Artist,
+    pub awards: Vec<ArtistAward>,
+    /// 예정/진행 중 공연 (가까운 순)
+    pub upcoming_concerts: Vec<ConcertListItem>,
+    /// 지난 공연 (최근 순, 페이지)
+    pub past_concerts: ArtistConcertPage,
+    /// 최근 음반
+    pub recordings: Vec<Recording>,
+    /// 평점 높은 연주 영상 (곡별 묶음)
+    pub performances: Vec<PiecePerformances>,
+    pub related_artists: Vec<RecommendedArtist>,
+    /// 단원 (앙상블일 때, 현재 단원 먼저)
+    pub members: Vec<MembershipWithArtist>,
+    /// 소속 앙상블 (현재 소속 먼저)
+    pub affiliations: Vec<MembershipWithArtist>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistConcertPage {
+    pub items: Vec<ConcertListItem>,
+    pub total: i64,
+    pub offset: i64,
+    pub limit: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PiecePerformances {
+    pub piece_id: i32,
+    pub piece_title: String,
+    pub composer_name: Option<String>,
+    pub performances: Vec<Performance>,
+}
+
+/// 관련 아티스트 (목록 표시용 요약)
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct RelatedArtist {
+    pub id: i32,
+    pub name: String,
+    pub english_name: String,
+    pub category: String,
+    pub tier: String,
+    pub image_url: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateArtist {
     pub name: String,
     pub english_name: String,
     pub category: String,
+    /// 지정하지 않으면 soloist
+    pub artist_type: Option<String>,
     pub tier: String,
     pub nationality: String,
     pub rating: Option<Decimal>,
@@ -60,9 +136,12 @@ pub struct CreateArtist {
     pub birth_year: Option<String>,
     pub bio: Option<String>,
     pub style: Option<String>,
+    /// 통계(rating, concertCount, albumCount)는 overrideStats가 true일 때만 지정 가능
     pub concert_count: Option<i32>,
     pub album_count: Option<i32>,
     pub top_award_id: Option<i32>,
+    /// true: 통계 수동 지정, false: 자동 계산으로 되돌림
+    pub override_stats: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -71,6 +150,7 @@ pub struct UpdateArtist {
     pub name: Option<String>,
     pub english_name: Option<String>,
     pub category: Option<String>,
+    pub artist_type: Option<String>,
     pub tier: Option<String>,
     pub nationality: Option<String>,
     pub rating: Option<Decimal>,
@@ -79,9 +159,57 @@ pub struct UpdateArtist {
     pub birth_year: Option<String>,
     pub bio: Option<String>,
     pub style: Option<String>,
+    /// 통계(rating, concertCount, albumCount)는 overrideStats가 true일 때만 지정 가능
     pub concert_count: Option<i32>,
     pub album_count: Option<i32>,
     pub top_award_id: Option<i32>,
+    /// true: 통계 수동 지정, false: 자동 계산으로 되돌림
+    pub override_stats: Option<bool>,
+}
+
+impl CreateArtist {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_artist_type(self.artist_type.as_deref())?;
+        validate_stats(self.override_stats, self.rating, self.concert_count, self.album_count)
+    }
+}
+
+impl UpdateArtist {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_artist_type(self.artist_type.as_deref())?;
+        validate_stats(self.override_stats, self.rating, self.concert_count, self.album_count)
+    }
+}
+
+fn validate_artist_type(artist_type: Option<&str>) -> Result<(), String> {
+    match artist_type {
+        Some(t) if !ARTIST_TYPES.contains(&t) => Err(format!(
+            "Unknown artist type: {} (expected one of {})",
+            t,
+            ARTIST_TYPES.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 계산되는 통계는 overrideStats 없이 지정할 수 없음
+fn validate_stats(
+    override_stats: Option<bool>,
+    rating: Option<Decimal>,
+    concert_count: Option<i32>,
+    album_count: Option<i32>,
+) -> Result<(), String> {
+    let has_stats = rating.is_some() || concert_count.is_some() || album_count.is_some();
+    if has_stats && override_stats != Some(true) {
+        return Err("rating, concertCount and albumCount are computed; set overrideStats to change them".to_string());
+    }
+    if concert_count.is_some_and(|c| c < 0) || album_count.is_some_and(|c| c < 0) {
+        return Err("counts must not be negative".to_string());
+    }
+    if rating.is_some_and(|r| r < Decimal::ZERO || r > Decimal::from(5)) {
+        return Err("rating must be between 0 and 5".to_string());
+    }
+    Ok(())
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -97,3 +225,133 @@ pub struct CreateArtistAward {
     pub notes: Option<String>,
     pub display_order: Option<i32>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateArtistAward {
+    pub year: Option<String>,
+    pub award_name: Option<String>,
+    pub award_type: Option<String>,
+    pub organization: Option<String>,
+    pub category: Option<String>,
+    pub ranking: Option<String>,
+    pub source: Option<String>,
+    pub notes: Option<String>,
+    pub display_order: Option<i32>,
+}
+
+/// 수상 내역 순서 변경 (아티스트의 모든 수상 id를 표시할 순서대로)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderArtistAwards {
+    pub award_ids: Vec<i32>,
+}
+
+impl ReorderArtistAwards {
+    /// 아티스트의 수상 id를 빠짐없이, 중복 없이 포함해야 함
+    pub fn validate(&self, current: &[ArtistAward]) -> Result<(), String> {
+        let mut requested = self.award_ids.clone();
+        requested.sort_unstable();
+        let mut existing: Vec<i32> = current.iter().map(|a| a.id).collect();
+        existing.sort_unstable();
+
+        if requested != existing {
+            return Err("awardIds must list every award of the artist exactly once".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 전체 아티스트 수상 내역 검색 조건 (쿼리 파라미터 이름 그대로)
+#[derive(Debug, Default, FromForm)]
+pub struct AwardSearchFilter {
+    /// 상 이름/주관 기관/부문에 포함된 문자열 (예: 쇼팽, Chopin)
+    pub q: Option<String>,
+    pub organization: Option<String>,
+    pub award_type: Option<String>,
+    /// 순위 (예: 1st Prize)
+    pub ranking: Option<String>,
+    pub year: Option<String>,
+}
+
+/// 아티스트 정보를 포함한 수상 내역
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct AwardWithArtist {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub award: ArtistAward,
+    pub artist_name: String,
+    pub artist_english_name: String,
+    pub artist_image_url: Option<String>,
+}
+
+/// 앙상블 단원 관계 (artist_memberships)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistMembership {
+    pub id: i32,
+    pub ensemble_id: i32,
+    pub member_id: i32,
+    /// 예: 악장, 상임 지휘자, 첼로
+    pub role: Option<String>,
+    pub start_year: Option<i32>,
+    /// 현재 단원이면 None
+    pub end_year: Option<i32>,
+}
+
+/// 단원 관계와 상대 아티스트 (단원 목록이면 단원, 소속 목록이면 앙상블)
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipWithArtist {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub membership: ArtistMembership,
+    pub artist_id: i32,
+    pub artist_name: String,
+    pub artist_english_name: String,
+    pub artist_type: String,
+    pub artist_image_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateArtistMembership {
+    pub member_id: i32,
+    pub role: Option<String>,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateArtistMembership {
+    pub role: Option<String>,
+    pub start_year: Option<i32>,
+    pub end_year: Option<i32>,
+}
+
+impl CreateArtistMembership {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_membership_years(self.start_year, self.end_year)
+    }
+}
+
+impl UpdateArtistMembership {
+    /// 기존 관계에 변경값을 합친 결과 검증
+    pub fn validate(&self, current: &ArtistMembership) -> Result<(), String> {
+        validate_membership_years(
+            self.start_year.or(current.start_year),
+            self.end_year.or(current.end_year),
+        )
+    }
+}
+
+fn validate_membership_years(start_year: Option<i32>, end_year: Option<i32>) -> Result<(), String> {
+    if let (Some(start), Some(end)) = (start_year, end_year) {
+        if start > end {
+            return Err(format!("Start year {} is after end year {}", start, end));
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,16 @@
 use crate::db::DbPool;
-use super::model::{Artist, CreateArtist, UpdateArtist, ArtistWithAwards, ArtistAward, CreateArtistAward};
+use super::model::{
+    Artist, ArtistAward, ArtistMembership, ArtistWithAwards, AwardSearchFilter, AwardWithArtist,
+    CreateArtist, CreateArtistAward, CreateArtistMembership, MembershipWithArtist, RelatedArtist,
+    UpdateArtist, UpdateArtistAward, UpdateArtistMembership, ARTIST_TYPE_SOLOIST,
+};
+use crate::search::SearchText;
 use sqlx::Error;
 
+/// FULLTEXT 인덱스 ft_artists_name, ft_artists_search 컬럼
+const ARTIST_NAME_COLUMNS: [&str; 2] = ["name", "english_name"];
+const ARTIST_SEARCH_COLUMNS: [&str; 6] = ["name", "english_name", "category", "nationality", "bio", "style"];
+
 pub struct ArtistRepository;
 
 impl ArtistRepository {
@@ -24,23 +33,27 @@ impl ArtistRepository {
             .await
         }
     pub async fn create(pool: &DbPool, artist: CreateArtist) -> Result<i32, Error> {
+        // 통계는 수동 지정(override_stats)일 때만 저장, 아니면 기본값으로 두고 계산에 맡김
+        let override_stats = artist.override_stats.unwrap_or(false);
         let result = sqlx::query(
-            "INSERT INTO artists (name, english_name, category, tier, nationality, rating, image_url, cover_image_url, birth_year, bio, style, concert_count, album_count, top_award_id)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO artists (name, english_name, category, artist_type, tier, nationality, rating, image_url, cover_image_url, birth_year, bio, style, concert_count, album_count, stats_override, top_award_id)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(&artist.name)
         .bind(&artist.english_name)
         .bind(&artist.category)
+        .bind(artist.artist_type.as_deref().unwrap_or(ARTIST_TYPE_SOLOIST))
         .bind(&artist.tier)
         .bind(&artist.nationality)
-        .bind(artist.rating)
+        .bind(artist.rating.filter(|_| override_stats).unwrap_or_default())
         .bind(&artist.image_url)
         .bind(&artist.cover_image_url)
         .bind(&artist.birth_year)
         .bind(&artist.bio)
         .bind(&artist.style)
-        .bind(artist.concert_count.unwrap_or(0))
-        .bind(artist.album_count.unwrap_or(0))
+        .bind(artist.concert_count.filter(|_| override_stats).unwrap_or(0))
+        .bind(artist.album_count.filter(|_| override_stats).unwrap_or(0))
+        .bind(override_stats)
         .bind(artist.top_award_id)
         .execute(pool)
         .await?;
@@ -55,30 +68,88 @@ impl ArtistRepository {
         }
         let current = current.unwrap();
 
+        // 통계는 override_stats = true일 때만 변경
+        let override_stats = artist.override_stats.unwrap_or(current.stats_override);
+        let (rating, concert_count, album_count) = if artist.override_stats == Some(true) {
+            (
+                artist.rating.or(current.rating),
+                artist.concert_count.unwrap_or(current.concert_count),
+                artist.album_count.unwrap_or(current.album_count),
+            )
+        } else {
+            (current.rating, current.concert_count, current.album_count)
+        };
+
         let result = sqlx::query(
-            "UPDATE artists SET name = ?, english_name = ?, category = ?, tier = ?, nationality = ?,
+            "UPDATE artists SET name = ?, english_name = ?, category = ?, artist_type = ?, tier = ?, nationality = ?,
              rating = ?, image_url = ?, cover_image_url = ?, birth_year = ?, bio = ?, style = ?,
-             concert_count = ?, album_count = ?, top_award_id = ?
+             concert_count = ?, album_count = ?, stats_override = ?, top_award_id = ?
              WHERE id = ?"
         )
         .bind(artist.name.unwrap_or(current.name))
         .bind(artist.english_name.unwrap_or(current.english_name))
         .bind(artist.category.unwrap_or(current.category))
+        .bind(artist.artist_type.unwrap_or(current.artist_type))
         .bind(artist.tier.unwrap_or(current.tier))
         .bind(artist.nationality.unwrap_or(current.nationality))
-        .bind(artist.rating.or(current.rating))
+        .bind(rating)
         .bind(artist.image_url.or(current.image_url))
         .bind(artist.cover_image_url.or(current.cover_image_url))
         .bind(artist.birth_year.or(current.birth_year))
         .bind(artist.bio.or(current.bio))
         .bind(artist.style.or(current.style))
-        .bind(artist.concert_count.unwrap_or(current.concert_count))
-        .bind(artist.album_count.unwrap_or(current.album_count))
+        .bind(concert_count)
+        .bind(album_count)
+        .bind(override_stats)
         .bind(artist.top_award_id.or(current.top_award_id))
         .bind(id)
         .execute(pool)
         .await?;
 
+        // 수동 지정을 해제하면 바로 다시 계산
+        if !override_stats && current.stats_override {
+            Self::recompute_stats(pool, Some(id)).await?;
+        }
+
+        Ok(result.rows_affected())
+    }
+
+    /// 연결된 데이터에서 통계 계산 (artist_id가 없으면 전체, 수동 지정 아티스트 제외)
+    /// - 공연 수: concert_artists 기준, 예정(upcoming/ongoing)과 지난 공연(completed)
+    /// - 음반 수: recordings
+    /// - 평점: 출연 공연의 사용자 평점과 연주 영상 평점(0 제외)을 합친 평균
+    pub async fn recompute_stats(pool: &DbPool, artist_id: Option<i32>) -> Result<u64, Error> {
+        let mut query = String::from(
+            "UPDATE artists a SET
+             upcoming_concert_count = (
+                 SELECT COUNT(*) FROM concert_artists ca JOIN concerts c ON ca.concert_id = c.id
+                 WHERE ca.artist_id = a.id AND c.status IN ('upcoming', 'ongoing')),
+             past_concert_count = (
+                 SELECT COUNT(*) FROM concert_artists ca JOIN concerts c ON ca.concert_id = c.id
+                 WHERE ca.artist_id = a.id AND c.status = 'completed'),
+             concert_count = upcoming_concert_count + past_concert_count,
+             album_count = (SELECT COUNT(*) FROM recordings r WHERE r.artist_id = a.id),
+             rating = COALESCE(ROUND(
+                 ((SELECT COALESCE(SUM(ucr.rating), 0) FROM user_concert_ratings ucr
+                   JOIN concert_artists ca ON ucr.concert_id = ca.concert_id WHERE ca.artist_id = a.id)
+                  + (SELECT COALESCE(SUM(p.rating), 0) FROM performances p WHERE p.artist_id = a.id AND p.rating > 0))
+                 / NULLIF(
+                     (SELECT COUNT(*) FROM user_concert_ratings ucr
+                      JOIN concert_artists ca ON ucr.concert_id = ca.concert_id WHERE ca.artist_id = a.id)
+                     + (SELECT COUNT(*) FROM performances p WHERE p.artist_id = a.id AND p.rating > 0), 0),
+             1), 0)
+             WHERE a.stats_override = FALSE",
+        );
+        if artist_id.is_some() {
+            query.push_str(" AND a.id = ?");
+        }
+
+        let mut sql_query = sqlx::query(&query);
+        if let Some(id) = artist_id {
+            sql_query = sql_query.bind(id);
+        }
+
+        let result = sql_query.execute(pool).await?;
         Ok(result.rows_affected())
     }
 
@@ -103,6 +174,22 @@ impl ArtistRepository {
         }
     }
 
+    /// 같은 분야의 다른 아티스트 (평점 순)
+    pub async fn find_related(pool: &DbPool, id: i32, limit: i64) -> Result<Vec<RelatedArtist>, Error> {
+        sqlx::query_as::<_, RelatedArtist>(
+            "SELECT a.id, a.name, a.english_name, a.category, a.tier, a.image_url
+             FROM artists a
+             JOIN artists base ON base.id = ? AND a.category = base.category
+             WHERE a.id <> base.id
+             ORDER BY a.rating DESC, a.tier ASC, a.id
+             LIMIT ?"
+        )
+        .bind(id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
     // Award CRUD
     pub async fn find_awards_by_artist(pool: &DbPool, artist_id: i32) -> Result<Vec<ArtistAward>, Error> {
         sqlx::query_as::<_, ArtistAward>(
@@ -134,12 +221,96 @@ impl ArtistRepository {
         Ok(result.last_insert_id() as i32)
     }
 
-    pub async fn delete_award(pool: &DbPool, award_id: i32) -> Result<u64, Error> {
-        let result = sqlx::query("DELETE FROM artist_awards WHERE id = ?")
+    pub async fn find_award(pool: &DbPool, artist_id: i32, award_id: i32) -> Result<Option<ArtistAward>, Error> {
+        sqlx::query_as::<_, ArtistAward>(
+            "SELECT * FROM artist_awards WHERE id = ? AND artist_id = ?"
+        )
+        .bind(award_id)
+        .bind(artist_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn update_award(
+        pool: &DbPool,
+        artist_id: i32,
+        award_id: i32,
+        award: UpdateArtistAward,
+    ) -> Result<u64, Error> {
+        let current = match Self::find_award(pool, artist_id, award_id).await? {
+            Some(current) => current,
+            None => return Ok(0),
+        };
+
+        let result = sqlx::query(
+            "UPDATE artist_awards SET year = ?, award_name = ?, award_type = ?, organization = ?,
+             category = ?, ranking = ?, source = ?, notes = ?, display_order = ?
+             WHERE id = ? AND artist_id = ?"
+        )
+        .bind(award.year.unwrap_or(current.year))
+        .bind(award.award_name.unwrap_or(current.award_name))
+        .bind(award.award_type.or(current.award_type))
+        .bind(award.organization.or(current.organization))
+        .bind(award.category.or(current.category))
+        .bind(award.ranking.or(current.ranking))
+        .bind(award.source.or(current.source))
+        .bind(award.notes.or(current.notes))
+        .bind(award.display_order.unwrap_or(current.display_order))
+        .bind(award_id)
+        .bind(artist_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// award_ids 순서대로 display_order를 0부터 다시 매김
+    pub async fn reorder_awards(pool: &DbPool, artist_id: i32, award_ids: &[i32]) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        let mut updated = 0;
+        for (order, award_id) in award_ids.iter().enumerate() {
+            let result = sqlx::query(
+                "UPDATE artist_awards SET display_order = ? WHERE id = ? AND artist_id = ?"
+            )
+            .bind(order as i32)
             .bind(award_id)
-            .execute(pool)
+            .bind(artist_id)
+            .execute(&mut *tx)
+            .await?;
+            updated += result.rows_affected();
+        }
+
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    /// 아티스트의 수상 삭제
+    /// 대표 수상이었으면 남은 수상 중 첫 번째(표시 순서)로 바꾸고, 남은 수상이 없으면 비움
+    pub async fn delete_award(pool: &DbPool, artist_id: i32, award_id: i32) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query("DELETE FROM artist_awards WHERE id = ? AND artist_id = ?")
+            .bind(award_id)
+            .bind(artist_id)
+            .execute(&mut *tx)
             .await?;
 
+        if result.rows_affected() > 0 {
+            sqlx::query(
+                "UPDATE artists SET top_award_id = (
+                     SELECT id FROM artist_awards WHERE artist_id = ?
+                     ORDER BY display_order, year DESC, id LIMIT 1)
+                 WHERE id = ? AND top_award_id = ?"
+            )
+            .bind(artist_id)
+            .bind(artist_id)
+            .bind(award_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
         Ok(result.rows_affected())
     }
 
@@ -149,68 +320,230 @@ impl ArtistRepository {
             .execute(pool)
             .await?;
 
+        sqlx::query("UPDATE artists SET top_award_id = NULL WHERE id = ?")
+            .bind(artist_id)
+            .execute(pool)
+            .await?;
+
         Ok(result.rows_affected())
     }
 
-    /// Full-text search across artists with pagination
-    pub async fn search_artists_by_text(
+    /// 전체 아티스트 수상 내역 검색 (예: 쇼팽 콩쿠르 1위 수상자 전체), 최근 연도 순
+    pub async fn search_awards(
         pool: &DbPool,
-        search_query: Option<&str>,
-        tier: Option<&str>,
-        category: Option<&str>,
+        filter: &AwardSearchFilter,
         offset: i64,
         limit: i64,
-    ) -> Result<Vec<Artist>, Error> {
-        // Prepare search pattern early to avoid lifetime issues
-        let search_pattern = search_query
-            .filter(|q| !q.trim().is_empty())
-            .map(|q| format!("%{}%", q));
-
+    ) -> Result<Vec<AwardWithArtist>, Error> {
         let mut query = String::from(
-            "SELECT * FROM v_artists_full WHERE 1=1"
+            "SELECT aw.*, a.name as artist_name, a.english_name as artist_english_name, a.image_url as artist_image_url
+             FROM artist_awards aw
+             JOIN artists a ON aw.artist_id = a.id
+             WHERE 1=1",
         );
+        let mut bindings: Vec<String> = Vec::new();
 
-        // Text search across multiple fields
-        if search_pattern.is_some() {
+        if let Some(q) = filter.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
             query.push_str(
-                " AND (name LIKE ? OR english_name LIKE ? OR category LIKE ? OR nationality LIKE ? OR bio LIKE ? OR style LIKE ?)"
+                " AND (aw.award_name LIKE CONCAT('%', ?, '%')
+                   OR aw.organization LIKE CONCAT('%', ?, '%')
+                   OR aw.category LIKE CONCAT('%', ?, '%'))",
             );
+            bindings.extend([q.to_string(), q.to_string(), q.to_string()]);
         }
-
-        // Tier filter
-        if tier.is_some() {
-            query.push_str(" AND tier = ?");
+        for (column, value) in [
+            ("aw.organization", &filter.organization),
+            ("aw.award_type", &filter.award_type),
+            ("aw.ranking", &filter.ranking),
+            ("aw.year", &filter.year),
+        ] {
+            if let Some(value) = value {
+                query.push_str(&format!(" AND {} = ?", column));
+                bindings.push(value.clone());
+            }
         }
 
-        // Category filter
-        if category.is_some() {
-            query.push_str(" AND category = ?");
+        query.push_str(" ORDER BY aw.year DESC, a.name, aw.id LIMIT ? OFFSET ?");
+
+        let mut sql_query = sqlx::query_as::<_, AwardWithArtist>(&query);
+        for value in bindings {
+            sql_query = sql_query.bind(value);
         }
 
-        // Order by rating and tier
-        query.push_str(" ORDER BY rating DESC, tier ASC LIMIT ? OFFSET ?");
+        sql_query.bind(limit).bind(offset).fetch_all(pool).await
+    }
 
-        let mut sql_query = sqlx::query_as::<_, Artist>(&query);
+    // ============================================
+    // 앙상블 단원 (artist_memberships)
+    // ============================================
 
-        // Bind search query with wildcards
-        if let Some(ref pattern) = search_pattern {
-            sql_query = sql_query
-                .bind(pattern) // name
-                .bind(pattern) // english_name
-                .bind(pattern) // category
-                .bind(pattern) // nationality
-                .bind(pattern) // bio
-                .bind(pattern); // style
+    /// 앙상블 단원 (current: 종료 연도가 없는 현재 단원만), 현재 단원 먼저
+    pub async fn find_members(
+        pool: &DbPool,
+        ensemble_id: i32,
+        current_only: bool,
+    ) -> Result<Vec<MembershipWithArtist>, Error> {
+        Self::find_memberships(pool, "m.ensemble_id", "m.member_id", ensemble_id, current_only).await
+    }
+
+    /// 소속 앙상블 (current: 현재 소속만), 현재 소속 먼저
+    pub async fn find_affiliations(
+        pool: &DbPool,
+        member_id: i32,
+        current_only: bool,
+    ) -> Result<Vec<MembershipWithArtist>, Error> {
+        Self::find_memberships(pool, "m.member_id", "m.ensemble_id", member_id, current_only).await
+    }
+
+    /// key_column으로 찾고 other_column 쪽 아티스트 정보를 붙임
+    async fn find_memberships(
+        pool: &DbPool,
+        key_column: &str,
+        other_column: &str,
+        id: i32,
+        current_only: bool,
+    ) -> Result<Vec<MembershipWithArtist>, Error> {
+        let mut query = format!(
+            "SELECT m.id, m.ensemble_id, m.member_id, m.role, m.start_year, m.end_year,
+             a.id as artist_id, a.name as artist_name, a.english_name as artist_english_name,
+             a.artist_type, a.image_url as artist_image_url
+             FROM artist_memberships m
+             JOIN artists a ON {} = a.id
+             WHERE {} = ?",
+            other_column, key_column
+        );
+        if current_only {
+            query.push_str(" AND m.end_year IS NULL");
         }
+        query.push_str(" ORDER BY m.end_year IS NOT NULL, m.end_year DESC, m.start_year, a.name");
+
+        sqlx::query_as::<_, MembershipWithArtist>(&query)
+            .bind(id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn find_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership_id: i32,
+    ) -> Result<Option<ArtistMembership>, Error> {
+        sqlx::query_as::<_, ArtistMembership>(
+            "SELECT id, ensemble_id, member_id, role, start_year, end_year
+             FROM artist_memberships WHERE id = ? AND ensemble_id = ?",
+        )
+        .bind(membership_id)
+        .bind(ensemble_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership: CreateArtistMembership,
+    ) -> Result<i32, Error> {
+        let result = sqlx::query(
+            "INSERT INTO artist_memberships (ensemble_id, member_id, role, start_year, end_year)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(ensemble_id)
+        .bind(membership.member_id)
+        .bind(&membership.role)
+        .bind(membership.start_year)
+        .bind(membership.end_year)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i32)
+    }
+
+    /// 다른 앙상블의 단원 관계면 0
+    pub async fn update_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership_id: i32,
+        membership: UpdateArtistMembership,
+    ) -> Result<u64, Error> {
+        let current = match Self::find_membership(pool, ensemble_id, membership_id).await? {
+            Some(current) => current,
+            None => return Ok(0),
+        };
+
+        let result = sqlx::query(
+            "UPDATE artist_memberships SET role = ?, start_year = ?, end_year = ?
+             WHERE id = ? AND ensemble_id = ?",
+        )
+        .bind(membership.role.or(current.role))
+        .bind(membership.start_year.or(current.start_year))
+        .bind(membership.end_year.or(current.end_year))
+        .bind(membership_id)
+        .bind(ensemble_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_membership(pool: &DbPool, ensemble_id: i32, membership_id: i32) -> Result<u64, Error> {
+        let result = sqlx::query("DELETE FROM artist_memberships WHERE id = ? AND ensemble_id = ?")
+            .bind(membership_id)
+            .bind(ensemble_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
 
-        // Bind tier filter
+    /// Full-text search across artists with pagination
+    /// 검색어가 있으면 관련도 순 (이름 일치 가중), 없으면 평점 순
+    pub async fn search_artists_by_text(
+        pool: &DbPool,
+        search: Option<&SearchText>,
+        tier: Option<&str>,
+        category: Option<&str>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Artist>, Error> {
+        let mut bindings: Vec<String> = Vec::new();
+
+        // 뷰(v_artists_full)에는 FULLTEXT 인덱스가 없으므로 artists에서 찾은 뒤 조인
+        let mut query = match search {
+            Some(search) => {
+                let (name_score, name_values) = search.relevance(&ARTIST_NAME_COLUMNS);
+                let (text_score, text_values) = search.relevance(&ARTIST_SEARCH_COLUMNS);
+                let (condition, condition_values) = search.condition(&ARTIST_SEARCH_COLUMNS);
+                bindings.extend(name_values);
+                bindings.extend(text_values);
+                bindings.extend(condition_values);
+                format!(
+                    "SELECT v.*, m.relevance FROM v_artists_full v
+                     JOIN (SELECT id, ({}) * 2 + ({}) as relevance FROM artists WHERE {}) m ON v.id = m.id
+                     WHERE 1=1",
+                    name_score, text_score, condition
+                )
+            }
+            None => String::from("SELECT v.*, 0 as relevance FROM v_artists_full v WHERE 1=1"),
+        };
+
+        // Tier filter
         if let Some(t) = tier {
-            sql_query = sql_query.bind(t);
+            query.push_str(" AND v.tier = ?");
+            bindings.push(t.to_string());
         }
 
-        // Bind category filter
+        // Category filter
         if let Some(c) = category {
-            sql_query = sql_query.bind(c);
+            query.push_str(" AND v.category = ?");
+            bindings.push(c.to_string());
+        }
+
+        // Order by relevance, then rating and tier
+        query.push_str(" ORDER BY relevance DESC, v.rating DESC, v.tier ASC LIMIT ? OFFSET ?");
+
+        let mut sql_query = sqlx::query_as::<_, Artist>(&query);
+        for value in bindings {
+            sql_query = sql_query.bind(value);
         }
 
         // Bind pagination
@@ -1,6 +1,29 @@
 use crate::db::DbPool;
-use super::model::{Artist, CreateArtist, UpdateArtist, ArtistWithAwards, CreateArtistAward};
+use super::model::{
+    Artist, ArtistAward, ArtistConcertPage, ArtistMembership, ArtistProfile, ArtistWithAwards,
+    AwardSearchFilter, AwardWithArtist, CreateArtist, CreateArtistAward, CreateArtistMembership,
+    MembershipWithArtist, PiecePerformances, UpdateArtist, UpdateArtistAward,
+    UpdateArtistMembership,
+};
 use super::repository::ArtistRepository;
+use crate::concert::repository::ConcertRepository;
+use crate::performance::repository::PerformanceRepository;
+use crate::recommendation::service::RecommendationService;
+use crate::recording::repository::RecordingRepository;
+use crate::search::SearchText;
+
+/// 프로필에 포함할 예정 공연 수
+const PROFILE_UPCOMING_LIMIT: i64 = 20;
+/// 지난 공연 페이지 크기 (기본, 최대)
+const PROFILE_PAST_LIMIT: i64 = 10;
+const PROFILE_PAST_MAX_LIMIT: i64 = 50;
+const PROFILE_RECORDING_LIMIT: i64 = 6;
+/// 곡별로 묶기 전 연주 영상 수
+const PROFILE_PERFORMANCE_LIMIT: i64 = 20;
+const PROFILE_RELATED_LIMIT: i64 = 6;
+/// 수상 내역 검색 페이지 크기 (기본, 최대)
+const AWARD_SEARCH_LIMIT: i64 = 20;
+const AWARD_SEARCH_MAX_LIMIT: i64 = 100;
 
 pub struct ArtistService;
 
@@ -23,6 +46,78 @@ impl ArtistService {
             .map_err(|e| e.to_string())
     }
 
+    /// 아티스트 상세 페이지 데이터 (수상, 예정/지난 공연, 음반, 연주 영상, 관련 아티스트)
+    /// past_offset/past_limit: 지난 공연 페이지
+    pub async fn get_artist_profile(
+        pool: &DbPool,
+        id: i32,
+        past_offset: Option<i64>,
+        past_limit: Option<i64>,
+    ) -> Result<Option<ArtistProfile>, String> {
+        let artist = match ArtistRepository::find_by_id(pool, id).await.map_err(|e| e.to_string())? {
+            Some(artist) => artist,
+            None => return Ok(None),
+        };
+        let past_offset = past_offset.unwrap_or(0).max(0);
+        let past_limit = past_limit.unwrap_or(PROFILE_PAST_LIMIT).clamp(1, PROFILE_PAST_MAX_LIMIT);
+
+        let awards = ArtistRepository::find_awards_by_artist(pool, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let upcoming_concerts = ConcertRepository::find_list_by_artist(pool, id, true, 0, PROFILE_UPCOMING_LIMIT)
+            .await
+            .map_err(|e| e.to_string())?;
+        let past_concerts = ArtistConcertPage {
+            items: ConcertRepository::find_list_by_artist(pool, id, false, past_offset, past_limit)
+                .await
+                .map_err(|e| e.to_string())?,
+            total: ConcertRepository::count_by_artist(pool, id, false)
+                .await
+                .map_err(|e| e.to_string())?,
+            offset: past_offset,
+            limit: past_limit,
+        };
+        let recordings = RecordingRepository::find_latest_by_artist(pool, id, PROFILE_RECORDING_LIMIT)
+            .await
+            .map_err(|e| e.to_string())?;
+        let rows = PerformanceRepository::find_top_rated_by_artist(pool, id, PROFILE_PERFORMANCE_LIMIT)
+            .await
+            .map_err(|e| e.to_string())?;
+        let related_artists = RecommendationService::get_related_artists(pool, id, Some(PROFILE_RELATED_LIMIT)).await?;
+        let members = ArtistRepository::find_members(pool, id, false)
+            .await
+            .map_err(|e| e.to_string())?;
+        let affiliations = ArtistRepository::find_affiliations(pool, id, false)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // 평점 순서를 유지하며 곡별로 묶음 (가장 높은 평점의 곡이 먼저)
+        let mut performances: Vec<PiecePerformances> = Vec::new();
+        for row in rows {
+            match performances.iter_mut().find(|p| p.piece_id == row.performance.piece_id) {
+                Some(group) => group.performances.push(row.performance),
+                None => performances.push(PiecePerformances {
+                    piece_id: row.performance.piece_id,
+                    piece_title: row.piece_title,
+                    composer_name: row.composer_name,
+                    performances: vec![row.performance],
+                }),
+            }
+        }
+
+        Ok(Some(ArtistProfile {
+            artist,
+            awards,
+            upcoming_concerts,
+            past_concerts,
+            recordings,
+            performances,
+            related_artists,
+            members,
+            affiliations,
+        }))
+    }
+
     pub async fn create_artist(pool: &DbPool, artist: CreateArtist) -> Result<i32, String> {
         ArtistRepository::create(pool, artist)
             .await
@@ -41,14 +136,121 @@ impl ArtistService {
             .map_err(|e| e.to_string())
     }
 
+    /// 연결된 공연/음반/평점에서 아티스트 통계 다시 계산 → 변경된 아티스트 수
+    pub async fn recompute_stats(pool: &DbPool) -> Result<u64, String> {
+        ArtistRepository::recompute_stats(pool, None)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     pub async fn create_artist_award(pool: &DbPool, artist_id: i32, award: CreateArtistAward) -> Result<i32, String> {
         ArtistRepository::create_award(pool, artist_id, award)
             .await
             .map_err(|e| e.to_string())
     }
 
-    pub async fn delete_artist_award(pool: &DbPool, award_id: i32) -> Result<u64, String> {
-        ArtistRepository::delete_award(pool, award_id)
+    pub async fn get_artist_awards(pool: &DbPool, artist_id: i32) -> Result<Vec<ArtistAward>, String> {
+        ArtistRepository::find_awards_by_artist(pool, artist_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_artist_award(pool: &DbPool, artist_id: i32, award_id: i32) -> Result<Option<ArtistAward>, String> {
+        ArtistRepository::find_award(pool, artist_id, award_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn update_artist_award(
+        pool: &DbPool,
+        artist_id: i32,
+        award_id: i32,
+        award: UpdateArtistAward,
+    ) -> Result<u64, String> {
+        ArtistRepository::update_award(pool, artist_id, award_id, award)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn reorder_artist_awards(pool: &DbPool, artist_id: i32, award_ids: &[i32]) -> Result<u64, String> {
+        ArtistRepository::reorder_awards(pool, artist_id, award_ids)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_artist_award(pool: &DbPool, artist_id: i32, award_id: i32) -> Result<u64, String> {
+        ArtistRepository::delete_award(pool, artist_id, award_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn search_awards(
+        pool: &DbPool,
+        filter: AwardSearchFilter,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<Vec<AwardWithArtist>, String> {
+        let offset = offset.unwrap_or(0).max(0);
+        let limit = limit.unwrap_or(AWARD_SEARCH_LIMIT).clamp(1, AWARD_SEARCH_MAX_LIMIT);
+
+        ArtistRepository::search_awards(pool, &filter, offset, limit)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_artist_members(
+        pool: &DbPool,
+        ensemble_id: i32,
+        current_only: bool,
+    ) -> Result<Vec<MembershipWithArtist>, String> {
+        ArtistRepository::find_members(pool, ensemble_id, current_only)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_artist_affiliations(
+        pool: &DbPool,
+        member_id: i32,
+        current_only: bool,
+    ) -> Result<Vec<MembershipWithArtist>, String> {
+        ArtistRepository::find_affiliations(pool, member_id, current_only)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_artist_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership_id: i32,
+    ) -> Result<Option<ArtistMembership>, String> {
+        ArtistRepository::find_membership(pool, ensemble_id, membership_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn create_artist_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership: CreateArtistMembership,
+    ) -> Result<i32, String> {
+        ArtistRepository::create_membership(pool, ensemble_id, membership)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn update_artist_membership(
+        pool: &DbPool,
+        ensemble_id: i32,
+        membership_id: i32,
+        membership: UpdateArtistMembership,
+    ) -> Result<u64, String> {
+        ArtistRepository::update_membership(pool, ensemble_id, membership_id, membership)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_artist_membership(pool: &DbPool, ensemble_id: i32, membership_id: i32) -> Result<u64, String> {
+        ArtistRepository::delete_membership(pool, ensemble_id, membership_id)
             .await
             .map_err(|e| e.to_string())
     }
@@ -63,10 +265,13 @@ impl ArtistService {
     ) -> Result<Vec<Artist>, String> {
         let offset_val = offset.unwrap_or(0);
         let limit_val = limit.unwrap_or(20);
+        let search = SearchText::build(pool, search_query.as_deref())
+            .await
+            .map_err(|e| e.to_string())?;
 
         ArtistRepository::search_artists_by_text(
             pool,
-            search_query.as_deref(),
+            search.as_ref(),
             tier.as_deref(),
             category.as_deref(),
             offset_val,
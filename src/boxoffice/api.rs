@@ -1,9 +1,67 @@
-use super::model::BoxofficeConcert;
-use super::service::BoxofficeService;
+use super::model::{BoxofficeConcert, BoxofficeHistoryPoint, BoxofficeMovers, BoxofficeTopDays};
+use super::service::{BoxofficeQuery, BoxofficeService};
 use crate::db::DbPool;
+use crate::kopis::codes;
 use crate::logger::Logger;
 use rocket::{http::Status, serde::json::Json, State};
 
+/// 쿼리 파라미터 검증 (알 수 없는 KOPIS 코드는 400)
+fn parse_query(
+    area_code: Option<String>,
+    genre_code: Option<String>,
+    period: Option<String>,
+    seat_scale: Option<String>,
+) -> Result<BoxofficeQuery, Status> {
+    let checks = [
+        (&area_code, codes::area_name as fn(&str) -> Option<&'static str>),
+        (&genre_code, codes::genre_name),
+        (&period, codes::period_name),
+        (&seat_scale, codes::seat_scale_name),
+    ];
+
+    for (value, lookup) in checks {
+        if let Some(code) = value {
+            if lookup(code).is_none() {
+                Logger::warn("API", &format!("Unknown boxoffice filter code: {}", code));
+                return Err(Status::BadRequest);
+            }
+        }
+    }
+
+    Ok(BoxofficeQuery {
+        area_code,
+        genre_code,
+        period,
+        seat_scale,
+    })
+}
+
+#[get("/boxoffice?<period>&<area_code>&<genre_code>&<seat_scale>&<limit>")]
+pub async fn get_rankings(
+    pool: &State<DbPool>,
+    period: Option<String>,
+    area_code: Option<String>,
+    genre_code: Option<String>,
+    seat_scale: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<Vec<BoxofficeConcert>>, Status> {
+    let query = parse_query(area_code, genre_code, period, seat_scale)?;
+
+    match BoxofficeService::get_rankings(pool, &query, limit).await {
+        Ok(concerts) => {
+            Logger::info(
+                "API_RESPONSE",
+                &format!("Boxoffice rankings returned: {} concerts", concerts.len()),
+            );
+            Ok(Json(concerts))
+        }
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get boxoffice rankings: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
 #[get("/concerts/boxoffice/top3?<area_code>&<genre_code>")]
 pub async fn get_top3(
     pool: &State<DbPool>,
@@ -24,3 +82,68 @@ pub async fn get_top3(
         }
     }
 }
+
+#[get("/concerts/boxoffice/<concert_id>/history?<area_code>&<genre_code>&<period>&<days>")]
+pub async fn get_history(
+    pool: &State<DbPool>,
+    concert_id: i32,
+    area_code: Option<String>,
+    genre_code: Option<String>,
+    period: Option<String>,
+    days: Option<i64>,
+) -> Result<Json<Vec<BoxofficeHistoryPoint>>, Status> {
+    let query = parse_query(area_code, genre_code, period, None)?;
+
+    match BoxofficeService::get_history(pool, concert_id, &query, days).await {
+        Ok(history) => Ok(Json(history)),
+        Err(e) => {
+            Logger::error(
+                "API",
+                &format!("Failed to get boxoffice history for concert {}: {}", concert_id, e),
+            );
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+#[get("/concerts/boxoffice/movers?<area_code>&<genre_code>&<period>&<limit>")]
+pub async fn get_movers(
+    pool: &State<DbPool>,
+    area_code: Option<String>,
+    genre_code: Option<String>,
+    period: Option<String>,
+    limit: Option<usize>,
+) -> Result<Json<BoxofficeMovers>, Status> {
+    let query = parse_query(area_code, genre_code, period, None)?;
+
+    match BoxofficeService::get_movers(pool, &query, limit).await {
+        Ok(movers) => Ok(Json(movers)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get boxoffice movers: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+#[get("/concerts/boxoffice/<concert_id>/top-days?<area_code>&<genre_code>&<period>&<top>")]
+pub async fn get_top_days(
+    pool: &State<DbPool>,
+    concert_id: i32,
+    area_code: Option<String>,
+    genre_code: Option<String>,
+    period: Option<String>,
+    top: Option<i32>,
+) -> Result<Json<BoxofficeTopDays>, Status> {
+    let query = parse_query(area_code, genre_code, period, None)?;
+
+    match BoxofficeService::get_top_days(pool, concert_id, &query, top).await {
+        Ok(top_days) => Ok(Json(top_days)),
+        Err(e) => {
+            Logger::error(
+                "API",
+                &format!("Failed to get boxoffice top days for concert {}: {}", concert_id, e),
+            );
+            Err(Status::InternalServerError)
+        }
+    }
+}
@@ -8,6 +8,8 @@ pub struct BoxofficeConcert {
     pub id: i32,
     pub concert_id: i32,
     pub ranking: i32,
+    pub period: String,
+    pub seat_scale: String,
     pub genre_name: Option<String>,
     pub area_name: Option<String>,
     pub sync_start_date: String,
@@ -26,3 +28,76 @@ pub struct BoxofficeConcert {
     pub genre: Option<String>,
     pub area: Option<String>,
 }
+
+/// 예매상황판 이력 저장용 순위 1건
+#[derive(Debug, Clone)]
+pub struct NewBoxofficeSnapshot {
+    pub ranking: i32,
+    pub kopis_id: String,
+    pub performance_name: String,
+    pub venue_name: Option<String>,
+    pub seat_count: Option<i32>,
+    pub performance_count: Option<i32>,
+}
+
+/// 특정 날짜의 순위 (DB에 있는 공연이면 concert 정보 포함)
+#[derive(Debug, FromRow)]
+pub struct BoxofficeSnapshotEntry {
+    pub ranking: i32,
+    pub kopis_id: String,
+    pub performance_name: String,
+    pub concert_id: Option<i32>,
+    pub poster_url: Option<String>,
+}
+
+/// 공연의 날짜별 순위
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxofficeHistoryPoint {
+    pub snapshot_date: String,
+    pub ranking: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxofficeMover {
+    pub concert_id: Option<i32>,
+    pub kopis_id: String,
+    pub title: String,
+    pub poster_url: Option<String>,
+    pub ranking: i32,
+    /// 비교 시점 순위 (새로 진입한 공연은 None)
+    pub previous_ranking: Option<i32>,
+    /// 상승 폭 (양수 = 상승, 새로 진입한 공연은 None)
+    pub change: Option<i32>,
+}
+
+/// 이번 주 순위 변동 (from_date → to_date)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxofficeMovers {
+    pub area_code: String,
+    pub genre_code: String,
+    pub period: String,
+    pub from_date: Option<String>,
+    pub to_date: Option<String>,
+    pub movers: Vec<BoxofficeMover>,
+}
+
+/// TOP N 유지 기간 (스냅샷 수집일 기준)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxofficeTopDays {
+    pub concert_id: i32,
+    pub area_code: String,
+    pub genre_code: String,
+    pub period: String,
+    pub top: i32,
+    /// TOP N에 든 총 일수
+    pub total_days: i32,
+    /// 최근 수집일까지 연속으로 TOP N에 든 일수 (현재 밖이면 0)
+    pub current_streak: i32,
+    pub longest_streak: i32,
+    pub first_date: Option<String>,
+    pub last_date: Option<String>,
+}
@@ -1,9 +1,23 @@
+use super::model::{BoxofficeHistoryPoint, BoxofficeSnapshotEntry, NewBoxofficeSnapshot};
 use crate::db::DbPool;
+use crate::kopis::codes::ALL_SEAT_SCALE;
 use chrono::NaiveDate;
 use sqlx::Error;
 
+/// 주목 공연(is_featured) 기준 집계 기간
+pub const FEATURED_PERIOD: &str = "month";
+
 pub struct BoxofficeRepository;
 
+/// 순위 구분 (장르, 지역, 집계 기간, 좌석규모)
+#[derive(Debug, Clone, Copy)]
+pub struct RankingScope<'a> {
+    pub genre_code: &'a str,
+    pub area_code: &'a str,
+    pub period: &'a str,
+    pub seat_scale: &'a str,
+}
+
 impl BoxofficeRepository {
     /// 특정 기간의 순위 데이터 삭제 (새 데이터로 교체하기 전)
     pub async fn delete_rankings_for_period(
@@ -35,16 +49,34 @@ impl BoxofficeRepository {
         Ok(result.rows_affected())
     }
 
-    /// 순위 데이터 UPSERT (장르/지역별 1, 2, 3등 슬롯 업데이트)
+    /// 장르/지역/기간/좌석규모 순위 전체 삭제 (새 순위로 교체하기 전)
+    pub async fn delete_rankings_for_scope(
+        pool: &DbPool,
+        scope: &RankingScope<'_>,
+    ) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "DELETE FROM concert_boxoffice_rankings
+             WHERE kopis_genre_code = ? AND kopis_area_code = ? AND period = ? AND seat_scale = ?",
+        )
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// 순위 데이터 UPSERT (장르/지역/기간/좌석규모별 순위 슬롯 업데이트)
+    /// 월별 전체 좌석규모 TOP 3만 주목 공연(is_featured)
     pub async fn upsert_ranking(
         pool: &DbPool,
         concert_id: i32,
-        kopis_genre_code: Option<&str>,
+        scope: &RankingScope<'_>,
         genre_name: Option<&str>,
-        kopis_area_code: Option<&str>,
         area_name: Option<&str>,
         ranking: i32,
-        seat_scale: Option<&str>,
         performance_count: i32,
         venue_name: Option<&str>,
         seat_count: Option<i32>,
@@ -54,15 +86,14 @@ impl BoxofficeRepository {
         let result = sqlx::query(
             "INSERT INTO concert_boxoffice_rankings (
                 concert_id, kopis_genre_code, genre_name,
-                kopis_area_code, area_name, ranking, seat_scale,
+                kopis_area_code, area_name, period, ranking, seat_scale,
                 performance_count, venue_name, seat_count,
                 sync_start_date, sync_end_date, is_featured
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON DUPLICATE KEY UPDATE
                 concert_id = VALUES(concert_id),
                 genre_name = VALUES(genre_name),
                 area_name = VALUES(area_name),
-                seat_scale = VALUES(seat_scale),
                 performance_count = VALUES(performance_count),
                 venue_name = VALUES(venue_name),
                 seat_count = VALUES(seat_count),
@@ -72,90 +103,199 @@ impl BoxofficeRepository {
                 synced_at = CURRENT_TIMESTAMP",
         )
         .bind(concert_id)
-        .bind(kopis_genre_code)
+        .bind(scope.genre_code)
         .bind(genre_name)
-        .bind(kopis_area_code.unwrap_or("00"))
+        .bind(scope.area_code)
         .bind(area_name)
+        .bind(scope.period)
         .bind(ranking)
-        .bind(seat_scale)
+        .bind(scope.seat_scale)
         .bind(performance_count)
         .bind(venue_name)
         .bind(seat_count)
         .bind(sync_start_date)
         .bind(sync_end_date)
-        .bind(ranking <= 3) // TOP 3만 featured
+        .bind(
+            ranking <= 3 && scope.period == FEATURED_PERIOD && scope.seat_scale == ALL_SEAT_SCALE,
+        )
         .execute(pool)
         .await?;
 
-        println!(
-            "concert_id: {}, kopis_genre_code: {:?}, kopis_area_code: {:?}, ranking: {}",
-            concert_id,
-            kopis_genre_code,
-            kopis_area_code.unwrap_or("00"),
-            ranking,
-        );
-
         Ok(result.rows_affected())
     }
 
-    /// 장르/지역별 현재 주목 공연 (TOP 3) 조회
-    pub async fn get_featured_concerts(
+    /// 지역별 월별 TOP N 공연 (홈 주목 공연 후보, 진행 중/예정 공연만)
+    pub async fn find_top_concert_ids(
         pool: &DbPool,
-        genre_code: Option<&str>,
-        area_code: Option<&str>,
-        limit: i32,
-    ) -> Result<Vec<FeaturedConcert>, Error> {
-        let mut query = String::from(
-            "SELECT
-                cbr.id, cbr.concert_id, cbr.ranking,
-                cbr.genre_name, cbr.area_name,
-                cbr.sync_start_date, cbr.sync_end_date,
-                c.title, c.poster_url, c.start_date, c.end_date
+        area_code: &str,
+        top: i32,
+    ) -> Result<Vec<i32>, Error> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT cbr.concert_id
              FROM concert_boxoffice_rankings cbr
              JOIN concerts c ON cbr.concert_id = c.id
-             WHERE cbr.is_featured = TRUE",
-        );
+             WHERE cbr.kopis_area_code = ?
+               AND cbr.period = ?
+               AND cbr.seat_scale = ?
+               AND cbr.ranking <= ?
+               AND c.status IN ('upcoming', 'ongoing')
+             GROUP BY cbr.concert_id
+             ORDER BY MIN(cbr.ranking) ASC, cbr.concert_id ASC",
+        )
+        .bind(area_code)
+        .bind(FEATURED_PERIOD)
+        .bind(ALL_SEAT_SCALE)
+        .bind(top)
+        .fetch_all(pool)
+        .await
+    }
 
-        let mut bindings: Vec<String> = Vec::new();
+    // ============================================
+    // 순위 이력 (boxoffice_snapshots)
+    // ============================================
 
-        if let Some(genre) = genre_code {
-            query.push_str(" AND cbr.kopis_genre_code = ?");
-            bindings.push(genre.to_string());
-        }
+    /// 하루치 장르/지역/기간/좌석규모 순위 저장 (같은 날 기존 이력은 교체)
+    pub async fn replace_snapshot(
+        pool: &DbPool,
+        snapshot_date: NaiveDate,
+        scope: &RankingScope<'_>,
+        entries: &[NewBoxofficeSnapshot],
+    ) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
 
-        // Area filter
-        if let Some(area) = area_code {
-            // 특정 지역 필터
-            query.push_str(" AND cbr.kopis_area_code = ?");
-            bindings.push(area.to_string());
-        } else {
-            // area_code가 없으면 전국 순위만 (area_code가 NULL인 레코드)
-            query.push_str(" AND cbr.kopis_area_code IS NULL");
+        sqlx::query(
+            "DELETE FROM boxoffice_snapshots
+             WHERE snapshot_date = ? AND kopis_genre_code = ? AND kopis_area_code = ?
+               AND period = ? AND seat_scale = ?",
+        )
+        .bind(snapshot_date)
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .execute(&mut *tx)
+        .await?;
+
+        let mut inserted = 0;
+        for entry in entries {
+            let result = sqlx::query(
+                "INSERT INTO boxoffice_snapshots (
+                    snapshot_date, kopis_genre_code, kopis_area_code, period, seat_scale, ranking,
+                    kopis_id, performance_name, venue_name, seat_count, performance_count
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(snapshot_date)
+            .bind(scope.genre_code)
+            .bind(scope.area_code)
+            .bind(scope.period)
+            .bind(scope.seat_scale)
+            .bind(entry.ranking)
+            .bind(&entry.kopis_id)
+            .bind(&entry.performance_name)
+            .bind(&entry.venue_name)
+            .bind(entry.seat_count)
+            .bind(entry.performance_count)
+            .execute(&mut *tx)
+            .await?;
+            inserted += result.rows_affected();
         }
 
-        query.push_str(" ORDER BY cbr.ranking ASC LIMIT ?");
-        bindings.push(limit.to_string());
+        tx.commit().await?;
+        Ok(inserted)
+    }
 
-        let mut q = sqlx::query_as::<_, FeaturedConcert>(&query);
-        for binding in bindings {
-            q = q.bind(binding);
-        }
+    /// 이력 수집일 (since 이후, 오름차순)
+    pub async fn find_snapshot_dates(
+        pool: &DbPool,
+        scope: &RankingScope<'_>,
+        since: NaiveDate,
+    ) -> Result<Vec<NaiveDate>, Error> {
+        sqlx::query_scalar::<_, NaiveDate>(
+            "SELECT DISTINCT snapshot_date FROM boxoffice_snapshots
+             WHERE kopis_genre_code = ? AND kopis_area_code = ? AND period = ? AND seat_scale = ?
+               AND snapshot_date >= ?
+             ORDER BY snapshot_date ASC",
+        )
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
 
-        q.fetch_all(pool).await
+    /// 특정 날짜의 순위 목록
+    pub async fn find_snapshot(
+        pool: &DbPool,
+        snapshot_date: NaiveDate,
+        scope: &RankingScope<'_>,
+    ) -> Result<Vec<BoxofficeSnapshotEntry>, Error> {
+        sqlx::query_as::<_, BoxofficeSnapshotEntry>(
+            "SELECT s.ranking, s.kopis_id, s.performance_name,
+                    c.id as concert_id, c.poster_url
+             FROM boxoffice_snapshots s
+             LEFT JOIN concerts c ON c.kopis_id = s.kopis_id
+             WHERE s.snapshot_date = ? AND s.kopis_genre_code = ? AND s.kopis_area_code = ?
+               AND s.period = ? AND s.seat_scale = ?
+             ORDER BY s.ranking ASC",
+        )
+        .bind(snapshot_date)
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 공연의 날짜별 순위 (since 이후)
+    pub async fn find_concert_history(
+        pool: &DbPool,
+        concert_id: i32,
+        scope: &RankingScope<'_>,
+        since: NaiveDate,
+    ) -> Result<Vec<BoxofficeHistoryPoint>, Error> {
+        sqlx::query_as::<_, BoxofficeHistoryPoint>(
+            "SELECT DATE_FORMAT(s.snapshot_date, '%Y-%m-%d') as snapshot_date, s.ranking
+             FROM boxoffice_snapshots s
+             JOIN concerts c ON c.kopis_id = s.kopis_id
+             WHERE c.id = ? AND s.kopis_genre_code = ? AND s.kopis_area_code = ?
+               AND s.period = ? AND s.seat_scale = ? AND s.snapshot_date >= ?
+             ORDER BY s.snapshot_date ASC",
+        )
+        .bind(concert_id)
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .bind(since)
+        .fetch_all(pool)
+        .await
     }
-}
 
-#[derive(Debug, sqlx::FromRow)]
-pub struct FeaturedConcert {
-    pub id: i32,
-    pub concert_id: i32,
-    pub ranking: i32,
-    pub genre_name: Option<String>,
-    pub area_name: Option<String>,
-    pub sync_start_date: NaiveDate,
-    pub sync_end_date: NaiveDate,
-    pub title: String,
-    pub poster_url: Option<String>,
-    pub start_date: NaiveDate,
-    pub end_date: Option<NaiveDate>,
+    /// 공연이 TOP N에 든 수집일 (오름차순)
+    pub async fn find_concert_top_dates(
+        pool: &DbPool,
+        concert_id: i32,
+        scope: &RankingScope<'_>,
+        top: i32,
+    ) -> Result<Vec<NaiveDate>, Error> {
+        sqlx::query_scalar::<_, NaiveDate>(
+            "SELECT s.snapshot_date
+             FROM boxoffice_snapshots s
+             JOIN concerts c ON c.kopis_id = s.kopis_id
+             WHERE c.id = ? AND s.kopis_genre_code = ? AND s.kopis_area_code = ?
+               AND s.period = ? AND s.seat_scale = ? AND s.ranking <= ?
+             ORDER BY s.snapshot_date ASC",
+        )
+        .bind(concert_id)
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .bind(top)
+        .fetch_all(pool)
+        .await
+    }
 }
@@ -1,11 +1,41 @@
-use super::model::BoxofficeConcert;
+use super::model::{
+    BoxofficeConcert, BoxofficeHistoryPoint, BoxofficeMover, BoxofficeMovers, BoxofficeTopDays,
+};
+use super::repository::{BoxofficeRepository, RankingScope};
+use crate::clock::today;
 use crate::db::DbPool;
+use crate::kopis::codes::{ALL_SEAT_SCALE, NATIONWIDE_AREA_CODE};
+use chrono::{Duration, NaiveDate};
 use sqlx::Error;
+use std::collections::{HashMap, HashSet};
+
+const DEFAULT_GENRE_CODE: &str = "CCCA";
+const DEFAULT_PERIOD: &str = "day";
+
+/// 순위 조회 조건 (지정하지 않으면 클래식, 전국, 일별, 전체 좌석규모)
+#[derive(Debug, Default)]
+pub struct BoxofficeQuery {
+    pub area_code: Option<String>,
+    pub genre_code: Option<String>,
+    pub period: Option<String>,
+    pub seat_scale: Option<String>,
+}
+
+impl BoxofficeQuery {
+    fn scope(&self) -> RankingScope<'_> {
+        RankingScope {
+            genre_code: self.genre_code.as_deref().unwrap_or(DEFAULT_GENRE_CODE),
+            area_code: self.area_code.as_deref().unwrap_or(NATIONWIDE_AREA_CODE),
+            period: self.period.as_deref().unwrap_or(DEFAULT_PERIOD),
+            seat_scale: self.seat_scale.as_deref().unwrap_or(ALL_SEAT_SCALE),
+        }
+    }
+}
 
 pub struct BoxofficeService;
 
 impl BoxofficeService {
-    /// Get TOP 3 boxoffice concerts
+    /// Get TOP 3 boxoffice concerts (월별 순위)
     /// If area_code is None, returns national TOP 3
     /// If area_code is provided, returns TOP 3 for that area
     pub async fn get_top3(
@@ -13,11 +43,31 @@ impl BoxofficeService {
         area_code: Option<String>,
         genre_code: Option<String>,
     ) -> Result<Vec<BoxofficeConcert>, Error> {
-        let mut query = String::from(
+        let query = BoxofficeQuery {
+            area_code,
+            genre_code,
+            period: Some("month".to_string()),
+            seat_scale: None,
+        };
+
+        Self::get_rankings(pool, &query, Some(3)).await
+    }
+
+    /// 저장된 예매 순위 조회 (DB에 있는 진행 중/예정 공연만)
+    pub async fn get_rankings(
+        pool: &DbPool,
+        query: &BoxofficeQuery,
+        limit: Option<i64>,
+    ) -> Result<Vec<BoxofficeConcert>, Error> {
+        let scope = query.scope();
+
+        sqlx::query_as::<_, BoxofficeConcert>(
             "SELECT
                 cbr.id,
                 cbr.concert_id,
                 cbr.ranking,
+                cbr.period,
+                cbr.seat_scale,
                 cbr.genre_name,
                 cbr.area_name,
                 DATE_FORMAT(cbr.sync_start_date, '%Y-%m-%d') as sync_start_date,
@@ -35,35 +85,144 @@ impl BoxofficeService {
                 c.area
              FROM concert_boxoffice_rankings cbr
              JOIN concerts c ON cbr.concert_id = c.id
-             WHERE cbr.is_featured = TRUE
+             WHERE cbr.kopis_genre_code = ?
+               AND cbr.kopis_area_code = ?
+               AND cbr.period = ?
+               AND cbr.seat_scale = ?
                AND c.status IN ('upcoming', 'ongoing')
-               AND c.start_date >= CURDATE()",
-        );
-
-        let mut bindings: Vec<String> = Vec::new();
-
-        // Genre filter (default: CCCA for 클래식)
-        let genre = genre_code.unwrap_or_else(|| "CCCA".to_string());
-        query.push_str(" AND cbr.kopis_genre_code = ?");
-        bindings.push(genre);
-
-        // Area filter
-        if let Some(area) = area_code {
-            // 특정 지역 필터
-            query.push_str(" AND cbr.kopis_area_code = ?");
-            bindings.push(area);
-        } else {
-            // area_code가 없으면 전국 순위만 (area_code가 NULL인 레코드)
-            query.push_str(" AND cbr.kopis_area_code = 00");
-        }
+             ORDER BY cbr.ranking ASC
+             LIMIT ?",
+        )
+        .bind(scope.genre_code)
+        .bind(scope.area_code)
+        .bind(scope.period)
+        .bind(scope.seat_scale)
+        .bind(limit.unwrap_or(10).clamp(1, 50))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 공연의 예매 순위 이력 (최근 days일)
+    pub async fn get_history(
+        pool: &DbPool,
+        concert_id: i32,
+        query: &BoxofficeQuery,
+        days: Option<i64>,
+    ) -> Result<Vec<BoxofficeHistoryPoint>, Error> {
+        let since = today() - Duration::days(days.unwrap_or(30).clamp(1, 365));
+
+        BoxofficeRepository::find_concert_history(pool, concert_id, &query.scope(), since).await
+    }
+
+    /// 이번 주 순위 변동이 큰 공연
+    /// 최근 수집일과 7일 이내 가장 오래된 수집일의 순위 비교
+    pub async fn get_movers(
+        pool: &DbPool,
+        query: &BoxofficeQuery,
+        limit: Option<usize>,
+    ) -> Result<BoxofficeMovers, Error> {
+        let scope = query.scope();
+        let limit = limit.unwrap_or(10).clamp(1, 50);
+
+        let dates =
+            BoxofficeRepository::find_snapshot_dates(pool, &scope, today() - Duration::days(7))
+                .await?;
+
+        let mut result = BoxofficeMovers {
+            area_code: scope.area_code.to_string(),
+            genre_code: scope.genre_code.to_string(),
+            period: scope.period.to_string(),
+            from_date: None,
+            to_date: dates.last().map(|d| d.format("%Y-%m-%d").to_string()),
+            movers: Vec::new(),
+        };
+
+        let (from, to) = match (dates.first(), dates.last()) {
+            (Some(from), Some(to)) if from != to => (*from, *to),
+            _ => return Ok(result),
+        };
+        result.from_date = Some(from.format("%Y-%m-%d").to_string());
+
+        let previous: HashMap<String, i32> =
+            BoxofficeRepository::find_snapshot(pool, from, &scope)
+                .await?
+                .into_iter()
+                .map(|entry| (entry.kopis_id, entry.ranking))
+                .collect();
+
+        let mut movers: Vec<BoxofficeMover> =
+            BoxofficeRepository::find_snapshot(pool, to, &scope)
+                .await?
+                .into_iter()
+                .map(|entry| {
+                    let previous_ranking = previous.get(&entry.kopis_id).copied();
+                    BoxofficeMover {
+                        concert_id: entry.concert_id,
+                        kopis_id: entry.kopis_id,
+                        title: entry.performance_name,
+                        poster_url: entry.poster_url,
+                        ranking: entry.ranking,
+                        previous_ranking,
+                        change: previous_ranking.map(|prev| prev - entry.ranking),
+                    }
+                })
+                .filter(|mover| mover.change != Some(0))
+                .collect();
+
+        // 변동 폭 큰 순, 새로 진입한 공연은 뒤에 순위 순
+        movers.sort_by_key(|mover| match mover.change {
+            Some(change) => (0, -change.abs(), mover.ranking),
+            None => (1, 0, mover.ranking),
+        });
+        movers.truncate(limit);
+
+        result.movers = movers;
+        Ok(result)
+    }
+
+    /// 공연이 TOP N에 머문 기간
+    /// 연속 여부는 수집일 기준 (수집이 빠진 날은 건너뜀)
+    pub async fn get_top_days(
+        pool: &DbPool,
+        concert_id: i32,
+        query: &BoxofficeQuery,
+        top: Option<i32>,
+    ) -> Result<BoxofficeTopDays, Error> {
+        let scope = query.scope();
+        let top = top.unwrap_or(3).clamp(1, 50);
+
+        let top_dates =
+            BoxofficeRepository::find_concert_top_dates(pool, concert_id, &scope, top).await?;
+
+        let mut result = BoxofficeTopDays {
+            concert_id,
+            area_code: scope.area_code.to_string(),
+            genre_code: scope.genre_code.to_string(),
+            period: scope.period.to_string(),
+            top,
+            total_days: top_dates.len() as i32,
+            current_streak: 0,
+            longest_streak: 0,
+            first_date: top_dates.first().map(|d| d.format("%Y-%m-%d").to_string()),
+            last_date: top_dates.last().map(|d| d.format("%Y-%m-%d").to_string()),
+        };
 
-        query.push_str(" ORDER BY cbr.ranking ASC LIMIT 3");
+        let Some(first) = top_dates.first().copied() else {
+            return Ok(result);
+        };
 
-        let mut q = sqlx::query_as::<_, BoxofficeConcert>(&query);
-        for binding in bindings {
-            q = q.bind(binding);
+        let in_top: HashSet<NaiveDate> = top_dates.into_iter().collect();
+        let mut streak = 0;
+        for date in BoxofficeRepository::find_snapshot_dates(pool, &scope, first).await? {
+            if in_top.contains(&date) {
+                streak += 1;
+                result.longest_streak = result.longest_streak.max(streak);
+            } else {
+                streak = 0;
+            }
         }
+        result.current_streak = streak;
 
-        q.fetch_all(pool).await
+        Ok(result)
     }
 }
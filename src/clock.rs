@@ -0,0 +1,9 @@
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Asia::Seoul;
+
+/// 오늘 날짜 (KST)
+///
+/// KOPIS 공연 기간/예매 순위가 한국 날짜 기준이라 동기화, 대조, 상태 갱신 모두 이 날짜를 사용
+pub fn today() -> NaiveDate {
+    Utc::now().with_timezone(&Seoul).date_naive()
+}
@@ -0,0 +1,251 @@
+use super::model::{
+    ClusterSummary, Competition, CompetitionWithEditions, ConfirmCompetitionResult,
+    CreateCompetition, CreateCompetitionResult, EditionLaureates, PendingCompetitionResult,
+    UpdateCompetition,
+};
+use super::service::CompetitionService;
+use crate::auth::ModeratorUser;
+use crate::db::DbPool;
+use crate::logger::Logger;
+use rocket::{http::Status, serde::json::Json, State};
+
+/// 대회 목록 (instrument: 피아노, 바이올린 등)
+#[get("/competitions?<instrument>")]
+pub async fn get_competitions(
+    pool: &State<DbPool>,
+    instrument: Option<String>,
+) -> Result<Json<Vec<Competition>>, Status> {
+    match CompetitionService::get_competitions(pool, instrument).await {
+        Ok(competitions) => Ok(Json(competitions)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get competitions: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 대회 정보와 회차 목록
+#[get("/competitions/<id>")]
+pub async fn get_competition(
+    pool: &State<DbPool>,
+    id: i32,
+) -> Result<Json<CompetitionWithEditions>, Status> {
+    match CompetitionService::get_competition(pool, id).await {
+        Ok(Some(competition)) => Ok(Json(competition)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get competition {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// Moderator 권한 필요
+#[post("/competitions", data = "<competition>")]
+pub async fn create_competition(
+    pool: &State<DbPool>,
+    competition: Json<CreateCompetition>,
+    _moderator: ModeratorUser,
+) -> Result<Json<i32>, Status> {
+    let competition = competition.into_inner();
+    if let Err(e) = competition.validate() {
+        Logger::warn("API", &format!("Invalid competition: {}", e));
+        return Err(Status::BadRequest);
+    }
+
+    match CompetitionService::create_competition(pool, competition).await {
+        Ok(id) => Ok(Json(id)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to create competition: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// Moderator 권한 필요
+#[put("/competitions/<id>", data = "<competition>")]
+pub async fn update_competition(
+    pool: &State<DbPool>,
+    id: i32,
+    competition: Json<UpdateCompetition>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let competition = competition.into_inner();
+    if let Err(e) = competition.validate() {
+        Logger::warn("API", &format!("Invalid competition {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match CompetitionService::update_competition(pool, id, competition).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update competition {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 회차와 입상 결과도 함께 삭제
+/// Moderator 권한 필요
+#[delete("/competitions/<id>")]
+pub async fn delete_competition(
+    pool: &State<DbPool>,
+    id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match CompetitionService::delete_competition(pool, id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to delete competition {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 회차별 입상자 (최근 회차부터, 확정된 결과만)
+#[get("/competitions/<id>/laureates")]
+pub async fn get_competition_laureates(
+    pool: &State<DbPool>,
+    id: i32,
+) -> Result<Json<Vec<EditionLaureates>>, Status> {
+    match CompetitionService::get_laureates(pool, id, None).await {
+        Ok(Some(editions)) => Ok(Json(editions)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get laureates for competition {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 한 회차의 입상자 (예: /competitions/1/editions/2015)
+#[get("/competitions/<id>/editions/<year>")]
+pub async fn get_edition_laureates(
+    pool: &State<DbPool>,
+    id: i32,
+    year: i32,
+) -> Result<Json<EditionLaureates>, Status> {
+    match CompetitionService::get_laureates(pool, id, Some(year)).await {
+        Ok(Some(editions)) => editions.into_iter().next().map(Json).ok_or(Status::NotFound),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get laureates for competition {} ({}): {}", id, year, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 입상 결과 등록 (회차가 없으면 만들고 바로 확정)
+/// Moderator 권한 필요
+#[post("/competitions/<id>/results", data = "<result>")]
+pub async fn create_competition_result(
+    pool: &State<DbPool>,
+    id: i32,
+    result: Json<CreateCompetitionResult>,
+    _moderator: ModeratorUser,
+) -> Result<Json<i32>, Status> {
+    let result = result.into_inner();
+    if let Err(e) = result.validate() {
+        Logger::warn("API", &format!("Invalid result for competition {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match CompetitionService::create_result(pool, id, result).await {
+        Ok(Some(result_id)) => Ok(Json(result_id)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to create result for competition {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 기존 수상 내역을 대회 카탈로그에 매칭 (매칭 결과는 확정 대기)
+/// Moderator 권한 필요
+#[post("/competitions/cluster")]
+pub async fn cluster_competition_awards(
+    pool: &State<DbPool>,
+    _moderator: ModeratorUser,
+) -> Result<Json<ClusterSummary>, Status> {
+    match CompetitionService::cluster_awards(pool).await {
+        Ok(summary) => Ok(Json(summary)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to cluster awards: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 확정 대기 중인 입상 결과 (원본 수상 내역 포함)
+/// Moderator 권한 필요
+#[get("/competitions/results/pending")]
+pub async fn get_pending_competition_results(
+    pool: &State<DbPool>,
+    _moderator: ModeratorUser,
+) -> Result<Json<Vec<PendingCompetitionResult>>, Status> {
+    match CompetitionService::get_pending_results(pool).await {
+        Ok(results) => Ok(Json(results)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get pending competition results: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 확정 대기 결과 확정 (competitionId/placement로 바로잡아 확정 가능)
+/// Moderator 권한 필요
+#[put("/competitions/results/<id>/confirm", data = "<confirm>")]
+pub async fn confirm_competition_result(
+    pool: &State<DbPool>,
+    id: i32,
+    confirm: Json<ConfirmCompetitionResult>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let confirm = confirm.into_inner();
+    if let Some(competition_id) = confirm.competition_id {
+        match CompetitionService::get_competition(pool, competition_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                Logger::warn("API", &format!("Unknown competition {} for result {}", competition_id, id));
+                return Err(Status::BadRequest);
+            }
+            Err(e) => {
+                Logger::error("API", &format!("Failed to get competition {}: {}", competition_id, e));
+                return Err(Status::InternalServerError);
+            }
+        }
+    }
+    if confirm.placement.as_deref().is_some_and(|p| p.trim().is_empty()) {
+        Logger::warn("API", &format!("Empty placement for result {}", id));
+        return Err(Status::BadRequest);
+    }
+
+    match CompetitionService::confirm_result(pool, id, confirm).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to confirm competition result {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 확정 대기 결과 거절 (원본 수상 내역은 다시 매칭하지 않음)
+/// Moderator 권한 필요
+#[put("/competitions/results/<id>/reject")]
+pub async fn reject_competition_result(
+    pool: &State<DbPool>,
+    id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match CompetitionService::reject_result(pool, id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to reject competition result {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use super::model::{AwardCluster, ClusteredResult, Competition, UnlinkedAward};
+use std::collections::{BTreeMap, HashMap};
+
+/// 이름에 이 단어가 있으면 콩쿠르로 봄 (award_type이 비어 있는 수상 내역용)
+const COMPETITION_KEYWORDS: [&str; 7] = [
+    "콩쿠르", "콩쿨", "competition", "concours", "wettbewerb", "concorso", "concurso",
+];
+
+/// 순위/입상 표기 (대회 이름 비교에서 제외, 숫자가 들어간 단어도 제외)
+const PLACEMENT_WORDS: [&str; 11] = [
+    "우승", "준우승", "입상", "수상", "winner", "prize", "laureate", "medal", "gold", "silver", "bronze",
+];
+
+/// 별칭이 이보다 짧으면 부분 일치로 매칭하지 않음 (예: "국제콩쿠르")
+const MIN_CONTAINED_KEY_CHARS: usize = 6;
+
+/// 콩쿠르 수상 내역인지
+pub fn is_competition_award(award: &UnlinkedAward) -> bool {
+    if award
+        .award_type
+        .as_deref()
+        .is_some_and(|t| t.trim().eq_ignore_ascii_case("competition"))
+    {
+        return true;
+    }
+    let name = award.award_name.to_lowercase();
+    COMPETITION_KEYWORDS.iter().any(|keyword| name.contains(keyword))
+}
+
+/// 연도 문자열에서 첫 네 자리 숫자 (예: "2015", "2015년", "2014-2015")
+pub fn parse_year(year: &str) -> Option<i32> {
+    let digits: Vec<char> = year.chars().collect();
+    digits
+        .windows(4)
+        .find(|w| w.iter().all(|c| c.is_ascii_digit()))
+        .and_then(|w| w.iter().collect::<String>().parse().ok())
+}
+
+/// 순위 표기 → 등수 (우승/Gold=1, 준우승/Silver=2, Bronze=3, "2nd Prize"/"3위"=숫자, 알 수 없으면 None)
+pub fn placement_rank(placement: &str) -> Option<i32> {
+    let lower = placement.to_lowercase();
+
+    let digits: String = lower
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    if let Ok(rank) = digits.parse::<i32>() {
+        if rank > 0 {
+            return Some(rank);
+        }
+    }
+
+    if lower.contains("준우승") || lower.contains("silver") || lower.contains("second") {
+        Some(2)
+    } else if lower.contains("우승")
+        || lower.contains("winner")
+        || lower.contains("gold")
+        || lower.contains("grand prize")
+        || lower.contains("first")
+    {
+        Some(1)
+    } else if lower.contains("bronze") || lower.contains("third") {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+/// 대회 이름 비교용 키 (순위/회차/연도 단어를 빼고 공백/기호 없이 소문자로)
+///
+/// "제18회 쇼팽 국제 피아노 콩쿠르 1위" → "쇼팽국제피아노콩쿠르"
+pub fn normalize_name(name: &str) -> String {
+    name_words(name)
+        .concat()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 새 대회 이름 (순위/회차/연도 단어를 뺀 원래 표기)
+fn display_name(name: &str) -> String {
+    name_words(name).join(" ")
+}
+
+fn name_words(name: &str) -> Vec<&str> {
+    name.split_whitespace()
+        .filter(|word| !is_placement_word(word))
+        .collect()
+}
+
+fn is_placement_word(word: &str) -> bool {
+    if word.chars().any(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    let word: String = word
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    PLACEMENT_WORDS.contains(&word.as_str())
+}
+
+/// 입상 표기 (ranking이 없으면 이름의 순위 단어, 그것도 없으면 "입상")
+fn placement(award: &UnlinkedAward) -> String {
+    if let Some(ranking) = award.ranking.as_deref().map(str::trim).filter(|r| !r.is_empty()) {
+        return ranking.to_string();
+    }
+    award
+        .award_name
+        .split_whitespace()
+        .find(|word| {
+            (word.ends_with('위') && word.starts_with(|c: char| c.is_ascii_digit()))
+                || *word == "우승"
+                || *word == "준우승"
+        })
+        .unwrap_or("입상")
+        .to_string()
+}
+
+/// 카탈로그에서 수상 이름에 맞는 대회 (완전 일치 우선, 없으면 가장 긴 별칭이 포함된 대회)
+fn match_competition(key: &str, catalog: &[(String, i32)]) -> Option<i32> {
+    if let Some((_, id)) = catalog.iter().find(|(name, _)| name == key) {
+        return Some(*id);
+    }
+    catalog
+        .iter()
+        .filter(|(name, _)| name.chars().count() >= MIN_CONTAINED_KEY_CHARS && key.contains(name.as_str()))
+        .max_by_key(|(name, _)| name.chars().count())
+        .map(|(_, id)| *id)
+}
+
+/// 가장 많이 쓰인 값 (같으면 먼저 나온 값)
+fn most_common<'a>(values: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for value in values {
+        match counts.iter_mut().find(|(v, _)| *v == value) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((value, 1)),
+        }
+    }
+    counts
+        .iter()
+        .fold(None::<(&str, usize)>, |best, &(value, count)| match best {
+            Some((_, best_count)) if best_count >= count => best,
+            _ => Some((value, count)),
+        })
+        .map(|(value, _)| value.to_string())
+}
+
+/// 콩쿠르 수상 내역을 대회별로 묶음
+///
+/// 카탈로그의 이름/영문명/별칭과 맞으면 그 대회로, 아니면 같은 이름끼리 묶어 새 대회 후보로 만듦
+/// (기존 대회 묶음은 대회 id 순, 새 대회 묶음은 처음 나온 순, 연도를 알 수 없는 수상 내역은 제외)
+pub fn cluster_awards(awards: &[UnlinkedAward], competitions: &[Competition]) -> Vec<AwardCluster> {
+    let catalog: Vec<(String, i32)> = competitions
+        .iter()
+        .flat_map(|competition| {
+            let mut names = vec![competition.name.as_str()];
+            names.extend(competition.english_name.as_deref());
+            names.extend(competition.alias_list());
+            names
+                .into_iter()
+                .map(normalize_name)
+                .filter(|key| !key.is_empty())
+                .map(move |key| (key, competition.id))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut matched: BTreeMap<i32, Vec<&UnlinkedAward>> = BTreeMap::new();
+    let mut unmatched: Vec<(String, Vec<&UnlinkedAward>)> = Vec::new();
+    let mut unmatched_index: HashMap<String, usize> = HashMap::new();
+
+    for award in awards.iter().filter(|award| is_competition_award(award)) {
+        if parse_year(&award.year).is_none() {
+            continue;
+        }
+        let key = normalize_name(&award.award_name);
+        if key.is_empty() {
+            continue;
+        }
+
+        match match_competition(&key, &catalog) {
+            Some(id) => matched.entry(id).or_default().push(award),
+            None => match unmatched_index.get(&key) {
+                Some(&index) => unmatched[index].1.push(award),
+                None => {
+                    unmatched_index.insert(key.clone(), unmatched.len());
+                    unmatched.push((key, vec![award]));
+                }
+            },
+        }
+    }
+
+    let results = |awards: &[&UnlinkedAward]| -> Vec<ClusteredResult> {
+        awards
+            .iter()
+            .map(|award| {
+                let placement = placement(award);
+                ClusteredResult {
+                    award_id: award.id,
+                    artist_id: award.artist_id,
+                    year: parse_year(&award.year).unwrap_or_default(),
+                    placement_rank: placement_rank(&placement),
+                    placement,
+                }
+            })
+            .collect()
+    };
+
+    let mut clusters: Vec<AwardCluster> = matched
+        .into_iter()
+        .map(|(id, awards)| {
+            let competition = competitions.iter().find(|c| c.id == id);
+            AwardCluster {
+                competition_id: Some(id),
+                name: competition.map(|c| c.name.clone()).unwrap_or_default(),
+                organization: competition.and_then(|c| c.organization.clone()),
+                results: results(&awards),
+            }
+        })
+        .collect();
+
+    clusters.extend(unmatched.into_iter().map(|(_, awards)| {
+        let names: Vec<String> = awards.iter().map(|a| display_name(&a.award_name)).collect();
+        AwardCluster {
+            competition_id: None,
+            name: most_common(names.iter().map(String::as_str)).unwrap_or_default(),
+            organization: most_common(awards.iter().filter_map(|a| a.organization.as_deref())),
+            results: results(&awards),
+        }
+    }));
+
+    clusters
+}
@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+pub const RESULT_STATUS_PENDING: &str = "pending";
+pub const RESULT_STATUS_CONFIRMED: &str = "confirmed";
+pub const RESULT_STATUS_REJECTED: &str = "rejected";
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct Competition {
+    pub id: i32,
+    pub name: String,
+    pub english_name: Option<String>,
+    pub organization: Option<String>,
+    /// 부문 악기 (피아노, 바이올린, 성악 등, 여러 부문이면 NULL)
+    pub instrument: Option<String>,
+    /// 개최 주기 (년, 매년=1)
+    pub cycle_years: Option<i32>,
+    /// 수상 내역 매칭용 별칭 (쉼표로 구분)
+    pub aliases: Option<String>,
+}
+
+impl Competition {
+    pub fn alias_list(&self) -> Vec<&str> {
+        self.aliases
+            .as_deref()
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|alias| !alias.is_empty())
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCompetition {
+    pub name: String,
+    pub english_name: Option<String>,
+    pub organization: Option<String>,
+    pub instrument: Option<String>,
+    pub cycle_years: Option<i32>,
+    pub aliases: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCompetition {
+    pub name: Option<String>,
+    pub english_name: Option<String>,
+    pub organization: Option<String>,
+    pub instrument: Option<String>,
+    pub cycle_years: Option<i32>,
+    pub aliases: Option<String>,
+}
+
+impl CreateCompetition {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_competition(Some(&self.name), self.cycle_years)
+    }
+}
+
+impl UpdateCompetition {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_competition(self.name.as_deref(), self.cycle_years)
+    }
+}
+
+fn validate_competition(name: Option<&str>, cycle_years: Option<i32>) -> Result<(), String> {
+    if name.is_some_and(|name| name.trim().is_empty()) {
+        return Err("Competition name must not be empty".to_string());
+    }
+    if cycle_years.is_some_and(|years| years < 1) {
+        return Err(format!("cycleYears must be at least 1: {:?}", cycle_years));
+    }
+    Ok(())
+}
+
+/// 대회 회차 (입상자 수는 확정된 결과만)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CompetitionEdition {
+    pub id: i32,
+    pub competition_id: i32,
+    pub year: i32,
+    /// 회차 (제18회 → 18)
+    pub edition_number: Option<i32>,
+    pub laureate_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompetitionWithEditions {
+    #[serde(flatten)]
+    pub competition: Competition,
+    /// 최근 회차부터
+    pub editions: Vec<CompetitionEdition>,
+}
+
+/// 회차 입상자
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct Laureate {
+    pub result_id: i32,
+    pub artist_id: i32,
+    pub artist_name: String,
+    pub artist_english_name: String,
+    pub artist_image_url: Option<String>,
+    pub placement: String,
+    pub placement_rank: Option<i32>,
+}
+
+/// 회차별 입상자 목록
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditionLaureates {
+    pub competition_id: i32,
+    pub year: i32,
+    pub edition_number: Option<i32>,
+    /// 순위 순 (순위를 알 수 없는 입상은 마지막)
+    pub laureates: Vec<Laureate>,
+}
+
+/// 모더레이터가 직접 등록하는 입상 결과 (바로 확정)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCompetitionResult {
+    pub artist_id: i32,
+    pub year: i32,
+    pub edition_number: Option<i32>,
+    /// 예: 1st Prize, 2위, Gold Medal
+    pub placement: String,
+}
+
+impl CreateCompetitionResult {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_result(self.year, &self.placement)
+    }
+}
+
+/// 확정 대기 결과를 확정 (대회/순위를 바로잡아 확정할 수 있음)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmCompetitionResult {
+    /// 다른 대회로 옮길 때 (같은 연도 회차로 이동)
+    pub competition_id: Option<i32>,
+    pub placement: Option<String>,
+}
+
+fn validate_result(year: i32, placement: &str) -> Result<(), String> {
+    if !(1800..=9999).contains(&year) {
+        return Err(format!("Invalid competition year: {}", year));
+    }
+    if placement.trim().is_empty() {
+        return Err("Placement must not be empty".to_string());
+    }
+    Ok(())
+}
+
+/// 확정 대기 중인 입상 결과 (원본 수상 내역과 함께)
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingCompetitionResult {
+    pub id: i32,
+    pub competition_id: i32,
+    pub competition_name: String,
+    pub year: i32,
+    pub artist_id: i32,
+    pub artist_name: String,
+    pub placement: String,
+    pub award_id: Option<i32>,
+    /// 원본 수상 내역 (artist_awards.award_name)
+    pub award_name: Option<String>,
+    pub award_organization: Option<String>,
+}
+
+/// 대회 매칭 대상 수상 내역 (아직 입상 결과와 연결되지 않은 것)
+#[derive(Debug, Clone, FromRow)]
+pub struct UnlinkedAward {
+    pub id: i32,
+    pub artist_id: i32,
+    pub year: String,
+    pub award_name: String,
+    pub award_type: Option<String>,
+    pub organization: Option<String>,
+    pub ranking: Option<String>,
+}
+
+/// 수상 내역 묶음 → 대회 하나 (competition_id가 없으면 새 대회로 등록)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwardCluster {
+    pub competition_id: Option<i32>,
+    pub name: String,
+    pub organization: Option<String>,
+    pub results: Vec<ClusteredResult>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusteredResult {
+    pub award_id: i32,
+    pub artist_id: i32,
+    pub year: i32,
+    pub placement: String,
+    pub placement_rank: Option<i32>,
+}
+
+/// 수상 내역 매칭 결과
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterSummary {
+    /// 기존 대회에 매칭된 수상 내역 수
+    pub matched_awards: usize,
+    /// 새로 등록한 대회 수
+    pub created_competitions: usize,
+    /// 새로 만든 확정 대기 결과 수
+    pub pending_results: u64,
+}
@@ -0,0 +1,355 @@
+use super::model::{
+    AwardCluster, Competition, CompetitionEdition, CreateCompetition, Laureate,
+    PendingCompetitionResult, UnlinkedAward, UpdateCompetition, RESULT_STATUS_CONFIRMED,
+    RESULT_STATUS_PENDING, RESULT_STATUS_REJECTED,
+};
+use crate::db::DbPool;
+use sqlx::{Error, MySql, Transaction};
+
+const COMPETITION_COLUMNS: &str =
+    "id, name, english_name, organization, instrument, cycle_years, aliases";
+
+pub struct CompetitionRepository;
+
+impl CompetitionRepository {
+    // ============================================
+    // 대회 (competitions)
+    // ============================================
+
+    pub async fn find_all(pool: &DbPool, instrument: Option<&str>) -> Result<Vec<Competition>, Error> {
+        let mut query = format!("SELECT {} FROM competitions WHERE 1=1", COMPETITION_COLUMNS);
+        if instrument.is_some() {
+            query.push_str(" AND instrument = ?");
+        }
+        query.push_str(" ORDER BY name");
+
+        let mut q = sqlx::query_as::<_, Competition>(&query);
+        if let Some(instrument) = instrument {
+            q = q.bind(instrument);
+        }
+        q.fetch_all(pool).await
+    }
+
+    pub async fn find_by_id(pool: &DbPool, id: i32) -> Result<Option<Competition>, Error> {
+        sqlx::query_as::<_, Competition>(&format!(
+            "SELECT {} FROM competitions WHERE id = ?",
+            COMPETITION_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create(pool: &DbPool, competition: &CreateCompetition) -> Result<i32, Error> {
+        let result = sqlx::query(
+            "INSERT INTO competitions (name, english_name, organization, instrument, cycle_years, aliases)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(competition.name.trim())
+        .bind(&competition.english_name)
+        .bind(&competition.organization)
+        .bind(&competition.instrument)
+        .bind(competition.cycle_years)
+        .bind(&competition.aliases)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i32)
+    }
+
+    pub async fn update(pool: &DbPool, id: i32, competition: UpdateCompetition) -> Result<u64, Error> {
+        let current = match Self::find_by_id(pool, id).await? {
+            Some(current) => current,
+            None => return Ok(0),
+        };
+
+        let result = sqlx::query(
+            "UPDATE competitions SET name = ?, english_name = ?, organization = ?, instrument = ?,
+             cycle_years = ?, aliases = ?
+             WHERE id = ?",
+        )
+        .bind(competition.name.map(|name| name.trim().to_string()).unwrap_or(current.name))
+        .bind(competition.english_name.or(current.english_name))
+        .bind(competition.organization.or(current.organization))
+        .bind(competition.instrument.or(current.instrument))
+        .bind(competition.cycle_years.or(current.cycle_years))
+        .bind(competition.aliases.or(current.aliases))
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// 회차와 입상 결과도 함께 삭제 (ON DELETE CASCADE)
+    pub async fn delete(pool: &DbPool, id: i32) -> Result<u64, Error> {
+        let result = sqlx::query("DELETE FROM competitions WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // ============================================
+    // 회차 (competition_editions)
+    // ============================================
+
+    /// 회차 목록 (최근 연도 순, 확정된 입상자 수 포함)
+    pub async fn find_editions(pool: &DbPool, competition_id: i32) -> Result<Vec<CompetitionEdition>, Error> {
+        sqlx::query_as::<_, CompetitionEdition>(
+            "SELECT e.id, e.competition_id, e.year, e.edition_number,
+             COUNT(r.id) as laureate_count
+             FROM competition_editions e
+             LEFT JOIN competition_results r ON r.edition_id = e.id AND r.status = ?
+             WHERE e.competition_id = ?
+             GROUP BY e.id, e.competition_id, e.year, e.edition_number
+             ORDER BY e.year DESC",
+        )
+        .bind(RESULT_STATUS_CONFIRMED)
+        .bind(competition_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 회차 id (없으면 만듦, 회차 번호는 비어 있을 때만 채움)
+    async fn ensure_edition(
+        tx: &mut Transaction<'_, MySql>,
+        competition_id: i32,
+        year: i32,
+        edition_number: Option<i32>,
+    ) -> Result<i32, Error> {
+        let result = sqlx::query(
+            "INSERT INTO competition_editions (competition_id, year, edition_number) VALUES (?, ?, ?)
+             ON DUPLICATE KEY UPDATE id = LAST_INSERT_ID(id),
+             edition_number = COALESCE(edition_number, VALUES(edition_number))",
+        )
+        .bind(competition_id)
+        .bind(year)
+        .bind(edition_number)
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(result.last_insert_id() as i32)
+    }
+
+    // ============================================
+    // 입상 결과 (competition_results)
+    // ============================================
+
+    /// 회차 입상자 (확정된 결과만, 순위 순)
+    pub async fn find_laureates(pool: &DbPool, edition_id: i32) -> Result<Vec<Laureate>, Error> {
+        sqlx::query_as::<_, Laureate>(
+            "SELECT r.id as result_id, r.artist_id, a.name as artist_name,
+             a.english_name as artist_english_name, a.image_url as artist_image_url,
+             r.placement, r.placement_rank
+             FROM competition_results r
+             JOIN artists a ON r.artist_id = a.id
+             WHERE r.edition_id = ? AND r.status = ?
+             ORDER BY r.placement_rank IS NULL, r.placement_rank, a.name",
+        )
+        .bind(edition_id)
+        .bind(RESULT_STATUS_CONFIRMED)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 확정된 입상 결과 등록 (회차가 없으면 만듦), 같은 회차/아티스트/순위가 있으면 확정만 함
+    pub async fn create_result(
+        pool: &DbPool,
+        competition_id: i32,
+        year: i32,
+        edition_number: Option<i32>,
+        artist_id: i32,
+        placement: &str,
+        placement_rank: Option<i32>,
+    ) -> Result<i32, Error> {
+        let mut tx = pool.begin().await?;
+        let edition_id = Self::ensure_edition(&mut tx, competition_id, year, edition_number).await?;
+
+        let result = sqlx::query(
+            "INSERT INTO competition_results (edition_id, artist_id, placement, placement_rank, status)
+             VALUES (?, ?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE id = LAST_INSERT_ID(id), status = VALUES(status)",
+        )
+        .bind(edition_id)
+        .bind(artist_id)
+        .bind(placement.trim())
+        .bind(placement_rank)
+        .bind(RESULT_STATUS_CONFIRMED)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(result.last_insert_id() as i32)
+    }
+
+    /// 확정 대기 결과 (대회/연도 순)
+    pub async fn find_pending_results(pool: &DbPool) -> Result<Vec<PendingCompetitionResult>, Error> {
+        sqlx::query_as::<_, PendingCompetitionResult>(
+            "SELECT r.id, e.competition_id, c.name as competition_name, e.year,
+             r.artist_id, a.name as artist_name, r.placement, r.award_id,
+             aw.award_name, aw.organization as award_organization
+             FROM competition_results r
+             JOIN competition_editions e ON r.edition_id = e.id
+             JOIN competitions c ON e.competition_id = c.id
+             JOIN artists a ON r.artist_id = a.id
+             LEFT JOIN artist_awards aw ON r.award_id = aw.id
+             WHERE r.status = ?
+             ORDER BY c.name, e.year DESC, r.id",
+        )
+        .bind(RESULT_STATUS_PENDING)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 확정 대기 결과 확정 (competition_id가 있으면 그 대회의 같은 연도 회차로 옮김)
+    /// 확정 대기 결과가 아니면 0
+    pub async fn confirm_result(
+        pool: &DbPool,
+        id: i32,
+        competition_id: Option<i32>,
+        placement: Option<(&str, Option<i32>)>,
+    ) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        let year: Option<i32> = sqlx::query_scalar(
+            "SELECT e.year FROM competition_results r
+             JOIN competition_editions e ON r.edition_id = e.id
+             WHERE r.id = ? AND r.status = ?",
+        )
+        .bind(id)
+        .bind(RESULT_STATUS_PENDING)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(year) = year else {
+            return Ok(0);
+        };
+
+        if let Some(competition_id) = competition_id {
+            let edition_id = Self::ensure_edition(&mut tx, competition_id, year, None).await?;
+            sqlx::query("UPDATE competition_results SET edition_id = ? WHERE id = ?")
+                .bind(edition_id)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        if let Some((placement, placement_rank)) = placement {
+            sqlx::query("UPDATE competition_results SET placement = ?, placement_rank = ? WHERE id = ?")
+                .bind(placement.trim())
+                .bind(placement_rank)
+                .bind(id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let result = sqlx::query("UPDATE competition_results SET status = ? WHERE id = ?")
+            .bind(RESULT_STATUS_CONFIRMED)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 확정 대기 결과 거절 (원본 수상 내역은 다시 매칭하지 않음)
+    pub async fn reject_result(pool: &DbPool, id: i32) -> Result<u64, Error> {
+        let result = sqlx::query("UPDATE competition_results SET status = ? WHERE id = ? AND status = ?")
+            .bind(RESULT_STATUS_REJECTED)
+            .bind(id)
+            .bind(RESULT_STATUS_PENDING)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // ============================================
+    // 수상 내역 매칭 (artist_awards → competition_results)
+    // ============================================
+
+    /// 아직 입상 결과와 연결되지 않은 수상 내역
+    pub async fn find_unlinked_awards(pool: &DbPool) -> Result<Vec<UnlinkedAward>, Error> {
+        sqlx::query_as::<_, UnlinkedAward>(
+            "SELECT aw.id, aw.artist_id, aw.year, aw.award_name, aw.award_type, aw.organization, aw.ranking
+             FROM artist_awards aw
+             LEFT JOIN competition_results r ON r.award_id = aw.id
+             WHERE r.id IS NULL
+             ORDER BY aw.id",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 묶음 저장 (대회가 없으면 등록, 결과는 확정 대기로)
+    /// 반환: (등록한 대회 id, 새로 만든 결과 수)
+    pub async fn save_cluster(pool: &DbPool, cluster: &AwardCluster) -> Result<(Option<i32>, u64), Error> {
+        let mut tx = pool.begin().await?;
+
+        let (competition_id, created) = match cluster.competition_id {
+            Some(id) => (id, None),
+            None => {
+                // 같은 이름의 대회가 이미 있으면 새로 등록한 것이 아님
+                // (CLIENT_FOUND_ROWS 연결이라 ON DUPLICATE KEY의 rows_affected로는 구분 불가)
+                let existing = sqlx::query_scalar::<_, i32>(
+                    "SELECT id FROM competitions WHERE name = ? FOR UPDATE",
+                )
+                .bind(&cluster.name)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+                match existing {
+                    Some(id) => (id, None),
+                    None => {
+                        let id = sqlx::query("INSERT INTO competitions (name, organization) VALUES (?, ?)")
+                            .bind(&cluster.name)
+                            .bind(&cluster.organization)
+                            .execute(&mut *tx)
+                            .await?
+                            .last_insert_id() as i32;
+                        (id, Some(id))
+                    }
+                }
+            }
+        };
+
+        let mut inserted = 0;
+        for clustered in &cluster.results {
+            let edition_id = Self::ensure_edition(&mut tx, competition_id, clustered.year, None).await?;
+            let exists = sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM competition_results
+                 WHERE edition_id = ? AND artist_id = ? AND placement = ?",
+            )
+            .bind(edition_id)
+            .bind(clustered.artist_id)
+            .bind(&clustered.placement)
+            .fetch_one(&mut *tx)
+            .await?
+                > 0;
+
+            // 같은 입상 결과가 이미 있으면 수상 내역만 연결
+            sqlx::query(
+                "INSERT INTO competition_results
+                 (edition_id, artist_id, placement, placement_rank, award_id, status)
+                 VALUES (?, ?, ?, ?, ?, ?)
+                 ON DUPLICATE KEY UPDATE award_id = COALESCE(award_id, VALUES(award_id))",
+            )
+            .bind(edition_id)
+            .bind(clustered.artist_id)
+            .bind(&clustered.placement)
+            .bind(clustered.placement_rank)
+            .bind(clustered.award_id)
+            .bind(RESULT_STATUS_PENDING)
+            .execute(&mut *tx)
+            .await?;
+            if !exists {
+                inserted += 1;
+            }
+        }
+
+        tx.commit().await?;
+        Ok((created, inserted))
+    }
+}
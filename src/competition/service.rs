@@ -0,0 +1,176 @@
+use super::matching::{cluster_awards, placement_rank};
+use super::model::{
+    ClusterSummary, Competition, CompetitionWithEditions, ConfirmCompetitionResult,
+    CreateCompetition, CreateCompetitionResult, EditionLaureates, PendingCompetitionResult,
+    UpdateCompetition,
+};
+use super::repository::CompetitionRepository;
+use crate::db::DbPool;
+use crate::logger::Logger;
+
+pub struct CompetitionService;
+
+impl CompetitionService {
+    pub async fn get_competitions(pool: &DbPool, instrument: Option<String>) -> Result<Vec<Competition>, String> {
+        CompetitionRepository::find_all(pool, instrument.as_deref())
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 대회와 회차 목록
+    pub async fn get_competition(pool: &DbPool, id: i32) -> Result<Option<CompetitionWithEditions>, String> {
+        let Some(competition) = CompetitionRepository::find_by_id(pool, id)
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+
+        let editions = CompetitionRepository::find_editions(pool, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Some(CompetitionWithEditions { competition, editions }))
+    }
+
+    pub async fn create_competition(pool: &DbPool, competition: CreateCompetition) -> Result<i32, String> {
+        CompetitionRepository::create(pool, &competition)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn update_competition(pool: &DbPool, id: i32, competition: UpdateCompetition) -> Result<u64, String> {
+        CompetitionRepository::update(pool, id, competition)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_competition(pool: &DbPool, id: i32) -> Result<u64, String> {
+        CompetitionRepository::delete(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 회차별 입상자 (year가 있으면 그 회차만, 최근 회차부터)
+    /// 대회가 없으면 None, 입상자가 확정되지 않은 회차는 빈 목록
+    pub async fn get_laureates(
+        pool: &DbPool,
+        competition_id: i32,
+        year: Option<i32>,
+    ) -> Result<Option<Vec<EditionLaureates>>, String> {
+        if CompetitionRepository::find_by_id(pool, competition_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let editions = CompetitionRepository::find_editions(pool, competition_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut result = Vec::new();
+        for edition in editions.into_iter().filter(|e| year.is_none_or(|year| e.year == year)) {
+            let laureates = CompetitionRepository::find_laureates(pool, edition.id)
+                .await
+                .map_err(|e| e.to_string())?;
+            result.push(EditionLaureates {
+                competition_id,
+                year: edition.year,
+                edition_number: edition.edition_number,
+                laureates,
+            });
+        }
+
+        Ok(Some(result))
+    }
+
+    /// 입상 결과 직접 등록 (대회가 없으면 None)
+    pub async fn create_result(
+        pool: &DbPool,
+        competition_id: i32,
+        result: CreateCompetitionResult,
+    ) -> Result<Option<i32>, String> {
+        if CompetitionRepository::find_by_id(pool, competition_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        CompetitionRepository::create_result(
+            pool,
+            competition_id,
+            result.year,
+            result.edition_number,
+            result.artist_id,
+            &result.placement,
+            placement_rank(&result.placement),
+        )
+        .await
+        .map(Some)
+        .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_pending_results(pool: &DbPool) -> Result<Vec<PendingCompetitionResult>, String> {
+        CompetitionRepository::find_pending_results(pool)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn confirm_result(pool: &DbPool, id: i32, confirm: ConfirmCompetitionResult) -> Result<u64, String> {
+        let placement = confirm
+            .placement
+            .as_deref()
+            .map(|placement| (placement, placement_rank(placement)));
+
+        CompetitionRepository::confirm_result(pool, id, confirm.competition_id, placement)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn reject_result(pool: &DbPool, id: i32) -> Result<u64, String> {
+        CompetitionRepository::reject_result(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 기존 수상 내역(artist_awards)을 대회 카탈로그에 매칭해 확정 대기 결과로 등록
+    ///
+    /// 이미 결과와 연결된 수상 내역은 건너뛰므로 여러 번 실행해도 됨
+    pub async fn cluster_awards(pool: &DbPool) -> Result<ClusterSummary, String> {
+        let awards = CompetitionRepository::find_unlinked_awards(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let competitions = CompetitionRepository::find_all(pool, None)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut summary = ClusterSummary::default();
+        for cluster in cluster_awards(&awards, &competitions) {
+            if cluster.competition_id.is_some() {
+                summary.matched_awards += cluster.results.len();
+            }
+
+            let (created, inserted) = CompetitionRepository::save_cluster(pool, &cluster)
+                .await
+                .map_err(|e| e.to_string())?;
+            if created.is_some() {
+                summary.created_competitions += 1;
+            }
+            summary.pending_results += inserted;
+        }
+
+        Logger::info(
+            "COMPETITION",
+            &format!(
+                "Clustered awards: {} matched, {} new competitions, {} pending results",
+                summary.matched_awards, summary.created_competitions, summary.pending_results
+            ),
+        );
+
+        Ok(summary)
+    }
+}
@@ -2,8 +2,14 @@ use rocket::{State, serde::json::Json, http::Status};
 use crate::auth::ModeratorUser;
 use crate::db::DbPool;
 use crate::logger::Logger;
-use super::model::{Composer, CreateComposer, UpdateComposer, ComposerWithMajorPieces};
+use super::model::{
+    Composer, ComposerRelationWithNames, ComposerWithMajorPieces, CreateComposer, CreateComposerRelation,
+    ReorderComposerMajorPieces, SetComposerMajorPieces, Timeline, UpdateComposer, UpdateComposerRelation,
+};
+use crate::piece::model::Piece;
+use crate::piece::service::PieceService;
 use super::service::ComposerService;
+use super::timeline;
 
 #[get("/composers/search?<q>&<period>&<offset>&<limit>")]
 pub async fn search_composers(
@@ -95,3 +101,226 @@ pub async fn delete_composer(
         }
     }
 }
+
+/// 주요 곡 지정 (pieceIds 순서대로 교체, 작곡가의 곡이 아니면 400)
+#[put("/composers/<id>/major-pieces", data = "<major_pieces>")]
+pub async fn set_composer_major_pieces(
+    pool: &State<DbPool>,
+    id: i32,
+    major_pieces: Json<SetComposerMajorPieces>,
+    _moderator: ModeratorUser,
+) -> Result<Json<Vec<Piece>>, Status> {
+    match ComposerService::get_composer_by_id(pool, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get composer {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    }
+    let composer_pieces = match PieceService::get_pieces_by_composer(pool, id).await {
+        Ok(pieces) => pieces,
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get pieces for composer {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+    if let Err(e) = major_pieces.validate(&composer_pieces) {
+        Logger::warn("API", &format!("Invalid major pieces for composer {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    if let Err(e) = ComposerService::set_major_pieces(pool, id, &major_pieces.piece_ids).await {
+        Logger::error("API", &format!("Failed to set major pieces for composer {}: {}", id, e));
+        return Err(Status::InternalServerError);
+    }
+    match ComposerService::get_major_pieces(pool, id).await {
+        Ok(pieces) => Ok(Json(pieces)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get major pieces for composer {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 주요 곡 순서 변경 (pieceIds: 현재 주요 곡 id를 모두 표시 순서대로)
+#[put("/composers/<id>/major-pieces/order", data = "<order>")]
+pub async fn reorder_composer_major_pieces(
+    pool: &State<DbPool>,
+    id: i32,
+    order: Json<ReorderComposerMajorPieces>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let current = match ComposerService::get_major_pieces(pool, id).await {
+        Ok(current) => current,
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get major pieces for composer {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+    if let Err(e) = order.validate(&current) {
+        Logger::warn("API", &format!("Invalid major piece order for composer {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match ComposerService::reorder_major_pieces(pool, id, &order.piece_ids).await {
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to reorder major pieces for composer {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 주요 곡에서 제외 (곡 자체는 삭제하지 않음)
+#[delete("/composers/<id>/major-pieces/<piece_id>")]
+pub async fn delete_composer_major_piece(
+    pool: &State<DbPool>,
+    id: i32,
+    piece_id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match ComposerService::delete_major_piece(pool, id, piece_id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to remove major piece {} from composer {}: {}", piece_id, id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 연표: from~to 사이에 생존한 작곡가와 그 기간의 작품, 작곡가 간 관계
+/// (from/to 중 하나만 지정하면 열린 범위, period: 시대 필터)
+#[get("/timeline?<from>&<to>&<period>")]
+pub async fn get_timeline(
+    pool: &State<DbPool>,
+    from: Option<i32>,
+    to: Option<i32>,
+    period: Option<String>,
+) -> Result<Json<Timeline>, Status> {
+    if let Err(e) = timeline::validate_range(from, to) {
+        Logger::warn("API", &format!("Invalid timeline range: {}", e));
+        return Err(Status::BadRequest);
+    }
+
+    match ComposerService::get_timeline(pool, from, to, period).await {
+        Ok(timeline) => Ok(Json(timeline)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get timeline: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 작곡가의 스승/제자, 영향 관계 (양방향)
+#[get("/composers/<id>/relations")]
+pub async fn get_composer_relations(
+    pool: &State<DbPool>,
+    id: i32,
+) -> Result<Json<Vec<ComposerRelationWithNames>>, Status> {
+    match ComposerService::get_composer_relations(pool, id).await {
+        Ok(relations) => Ok(Json(relations)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get relations for composer {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 관계 추가 (id가 스승 / 영향을 준 쪽, 같은 관계가 있으면 기존 id 반환)
+#[post("/composers/<id>/relations", data = "<relation>")]
+pub async fn create_composer_relation(
+    pool: &State<DbPool>,
+    id: i32,
+    relation: Json<CreateComposerRelation>,
+    _moderator: ModeratorUser,
+) -> Result<Json<i32>, Status> {
+    let relation = relation.into_inner();
+    if let Err(e) = relation.validate() {
+        Logger::warn("API", &format!("Invalid relation for composer {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+    if relation.to_composer_id == id {
+        Logger::warn("API", &format!("Composer {} cannot be related to itself", id));
+        return Err(Status::BadRequest);
+    }
+
+    match ComposerService::get_composer_by_id(pool, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get composer {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    }
+    match ComposerService::get_composer_by_id(pool, relation.to_composer_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            Logger::warn("API", &format!("Related composer {} not found", relation.to_composer_id));
+            return Err(Status::BadRequest);
+        }
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get composer {}: {}", relation.to_composer_id, e));
+            return Err(Status::InternalServerError);
+        }
+    }
+
+    match ComposerService::create_composer_relation(pool, id, relation).await {
+        Ok(relation_id) => Ok(Json(relation_id)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to create relation for composer {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 이 작곡가가 포함되지 않은 관계면 404
+#[put("/composers/<id>/relations/<relation_id>", data = "<relation>")]
+pub async fn update_composer_relation(
+    pool: &State<DbPool>,
+    id: i32,
+    relation_id: i32,
+    relation: Json<UpdateComposerRelation>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let relation = relation.into_inner();
+    if let Err(e) = relation.validate() {
+        Logger::warn("API", &format!("Invalid relation {}: {}", relation_id, e));
+        return Err(Status::BadRequest);
+    }
+
+    let current = match ComposerService::get_composer_relation(pool, id, relation_id).await {
+        Ok(Some(current)) => current,
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get relation {}: {}", relation_id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+
+    match ComposerService::update_composer_relation(pool, current, relation).await {
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update relation {}: {}", relation_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+#[delete("/composers/<id>/relations/<relation_id>")]
+pub async fn delete_composer_relation(
+    pool: &State<DbPool>,
+    id: i32,
+    relation_id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match ComposerService::delete_composer_relation(pool, id, relation_id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to delete relation {}: {}", relation_id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
@@ -2,5 +2,6 @@ pub mod api;
 pub mod model;
 pub mod repository;
 pub mod service;
+pub mod timeline;
 
 pub use api::*;
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
+use crate::piece::model::Piece;
 use sqlx::FromRow;
+use std::collections::HashSet;
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 #[serde(rename_all = "camelCase")]
@@ -38,7 +40,9 @@ pub struct ComposerWithMajorPieces {
     pub bio: Option<String>,
     pub style: Option<String>,
     pub influence: Option<String>,
-    pub major_pieces: Option<String>,
+    /// 표시 순서대로 (composer_major_pieces)
+    #[sqlx(skip)]
+    pub major_pieces: Vec<Piece>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -75,4 +79,151 @@ pub struct UpdateComposer {
     pub bio: Option<String>,
     pub style: Option<String>,
     pub influence: Option<String>,
-}
\ No newline at end of file
+}
+/// 주요 곡 지정 (pieceIds: 작곡가의 곡 id를 표시 순서대로, 빈 배열이면 모두 해제)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetComposerMajorPieces {
+    pub piece_ids: Vec<i32>,
+}
+
+/// 주요 곡 순서 변경 (pieceIds: 현재 주요 곡 id를 모두 표시 순서대로)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderComposerMajorPieces {
+    pub piece_ids: Vec<i32>,
+}
+
+impl SetComposerMajorPieces {
+    /// 작곡가의 곡이어야 하고 중복 없어야 함
+    pub fn validate(&self, composer_pieces: &[Piece]) -> Result<(), String> {
+        let owned: HashSet<i32> = composer_pieces.iter().map(|p| p.id).collect();
+        let mut seen = HashSet::new();
+        for &piece_id in &self.piece_ids {
+            if !owned.contains(&piece_id) {
+                return Err(format!("Piece {} is not by this composer", piece_id));
+            }
+            if !seen.insert(piece_id) {
+                return Err(format!("Piece {} is listed more than once", piece_id));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ReorderComposerMajorPieces {
+    /// 현재 주요 곡 id를 빠짐없이, 중복 없이 포함해야 함
+    pub fn validate(&self, current: &[Piece]) -> Result<(), String> {
+        let mut requested = self.piece_ids.clone();
+        requested.sort_unstable();
+        let mut existing: Vec<i32> = current.iter().map(|p| p.id).collect();
+        existing.sort_unstable();
+
+        if requested != existing {
+            return Err("pieceIds must list every major piece of the composer exactly once".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub const RELATION_TEACHER: &str = "teacher";
+pub const RELATION_INFLUENCE: &str = "influence";
+pub const RELATION_TYPES: [&str; 2] = [RELATION_TEACHER, RELATION_INFLUENCE];
+
+/// 작곡가 관계 (from → to: 스승 → 제자, 영향을 준 → 받은)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerRelation {
+    pub id: i32,
+    pub from_composer_id: i32,
+    pub to_composer_id: i32,
+    pub relation_type: String,
+    pub notes: Option<String>,
+}
+
+/// 양쪽 작곡가 이름을 포함한 관계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposerRelationWithNames {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub relation: ComposerRelation,
+    pub from_composer_name: String,
+    pub to_composer_name: String,
+}
+
+/// /composers/<id>/relations로 등록 (id가 스승 / 영향을 준 쪽)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateComposerRelation {
+    pub to_composer_id: i32,
+    pub relation_type: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateComposerRelation {
+    pub relation_type: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl CreateComposerRelation {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_relation_type(Some(&self.relation_type))
+    }
+}
+
+impl UpdateComposerRelation {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_relation_type(self.relation_type.as_deref())
+    }
+}
+
+fn validate_relation_type(relation_type: Option<&str>) -> Result<(), String> {
+    match relation_type {
+        Some(t) if !RELATION_TYPES.contains(&t) => Err(format!(
+            "Unknown relation type: {} (expected one of {})",
+            t,
+            RELATION_TYPES.join(", ")
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 연표에 표시할 작곡가 (pieces: 기간 내 작곡한 곡)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineComposer {
+    pub id: i32,
+    pub name: String,
+    pub english_name: String,
+    pub period: String,
+    pub birth_year: i32,
+    pub death_year: Option<i32>,
+    pub nationality: String,
+    pub avatar_url: Option<String>,
+    #[sqlx(skip)]
+    pub pieces: Vec<TimelinePiece>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelinePiece {
+    pub id: i32,
+    pub composer_id: i32,
+    pub title: String,
+    pub title_en: Option<String>,
+    pub opus_number: Option<String>,
+    pub composition_year: i32,
+}
+
+/// /timeline 응답 (relations: 목록에 있는 작곡가끼리의 관계)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timeline {
+    pub from: Option<i32>,
+    pub to: Option<i32>,
+    pub composers: Vec<TimelineComposer>,
+    pub relations: Vec<ComposerRelationWithNames>,
+}
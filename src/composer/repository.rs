@@ -1,7 +1,22 @@
 use crate::db::DbPool;
-use super::model::{Composer, CreateComposer, UpdateComposer, ComposerWithMajorPieces};
+use super::model::{
+    Composer, ComposerRelation, ComposerRelationWithNames, ComposerWithMajorPieces, CreateComposer,
+    CreateComposerRelation, TimelineComposer, TimelinePiece, UpdateComposer, UpdateComposerRelation,
+};
+use crate::piece::model::Piece;
+use crate::search::SearchText;
 use sqlx::Error;
 
+const RELATION_WITH_NAMES_SELECT: &str =
+    "SELECT r.id, r.from_composer_id, r.to_composer_id, r.relation_type, r.notes,
+     f.name as from_composer_name, t.name as to_composer_name
+     FROM composer_relations r
+     JOIN composers f ON r.from_composer_id = f.id
+     JOIN composers t ON r.to_composer_id = t.id";
+
+/// FULLTEXT 인덱스 ft_composers_name 컬럼
+const COMPOSER_NAME_COLUMNS: [&str; 3] = ["c.name", "c.full_name", "c.english_name"];
+
 pub struct ComposerRepository;
 
 impl ComposerRepository {
@@ -21,12 +36,80 @@ impl ComposerRepository {
     }
 
     pub async fn find_by_id(pool: &DbPool, id: i32) -> Result<Option<ComposerWithMajorPieces>, Error> {
-        sqlx::query_as::<_, ComposerWithMajorPieces>("SELECT * FROM v_composers_full WHERE id = ?")
+        sqlx::query_as::<_, ComposerWithMajorPieces>("SELECT * FROM composers WHERE id = ?")
             .bind(id)
             .fetch_optional(pool)
             .await
     }
 
+    /// 주요 곡 (표시 순서대로)
+    pub async fn find_major_pieces(pool: &DbPool, composer_id: i32) -> Result<Vec<Piece>, Error> {
+        sqlx::query_as::<_, Piece>(
+            "SELECT p.*
+             FROM composer_major_pieces cmp
+             JOIN pieces p ON cmp.piece_id = p.id
+             WHERE cmp.composer_id = ?
+             ORDER BY cmp.display_order, cmp.id"
+        )
+        .bind(composer_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 주요 곡 목록을 통째로 교체 (piece_ids 순서가 표시 순서)
+    pub async fn set_major_pieces(pool: &DbPool, composer_id: i32, piece_ids: &[i32]) -> Result<(), Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM composer_major_pieces WHERE composer_id = ?")
+            .bind(composer_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for (order, piece_id) in piece_ids.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO composer_major_pieces (composer_id, piece_id, display_order) VALUES (?, ?, ?)"
+            )
+            .bind(composer_id)
+            .bind(piece_id)
+            .bind(order as i32 + 1)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn reorder_major_pieces(pool: &DbPool, composer_id: i32, piece_ids: &[i32]) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        let mut updated = 0;
+        for (order, piece_id) in piece_ids.iter().enumerate() {
+            let result = sqlx::query(
+                "UPDATE composer_major_pieces SET display_order = ? WHERE composer_id = ? AND piece_id = ?"
+            )
+            .bind(order as i32 + 1)
+            .bind(composer_id)
+            .bind(piece_id)
+            .execute(&mut *tx)
+            .await?;
+            updated += result.rows_affected();
+        }
+
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    pub async fn delete_major_piece(pool: &DbPool, composer_id: i32, piece_id: i32) -> Result<u64, Error> {
+        let result = sqlx::query("DELETE FROM composer_major_pieces WHERE composer_id = ? AND piece_id = ?")
+            .bind(composer_id)
+            .bind(piece_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn create(pool: &DbPool, composer: CreateComposer) -> Result<i32, Error> {
         let result = sqlx::query(
             "INSERT INTO composers (name, full_name, english_name, period, tier, birth_year, death_year, nationality, avatar_url, cover_image_url, bio, style, influence)
@@ -93,34 +176,39 @@ impl ComposerRepository {
         Ok(result.rows_affected())
     }
 
+    /// 검색어가 있으면 관련도 순, 없으면 출생 연도 순
     pub async fn search_composers(
         pool: &DbPool,
-        query: Option<String>,
+        search: Option<&SearchText>,
         period: Option<String>,
         offset: i64,
         limit: i64,
     ) -> Result<Vec<Composer>, Error> {
-        let mut sql = String::from(
-            "SELECT c.*, COUNT(p.id) as piece_count
-             FROM composers c
-             LEFT JOIN pieces p ON c.id = p.composer_id"
-        );
-
         let mut where_clauses = Vec::new();
         let mut bind_values: Vec<String> = Vec::new();
 
-        // Add search condition if query provided
-        if let Some(q) = query {
-            let trimmed = q.trim();
-            if !trimmed.is_empty() {
-                where_clauses.push(
-                    "(c.name LIKE ? OR c.full_name LIKE ? OR c.english_name LIKE ?)".to_string()
-                );
-                let search_pattern = format!("%{}%", trimmed);
-                bind_values.push(search_pattern.clone());
-                bind_values.push(search_pattern.clone());
-                bind_values.push(search_pattern);
+        // 관련도 점수 (SELECT 절이 WHERE보다 먼저 바인딩됨)
+        let relevance = match search {
+            Some(search) => {
+                let (score, values) = search.relevance(&COMPOSER_NAME_COLUMNS);
+                bind_values.extend(values);
+                score
             }
+            None => "0".to_string(),
+        };
+
+        let mut sql = format!(
+            "SELECT c.*, COUNT(p.id) as piece_count, {} as relevance
+             FROM composers c
+             LEFT JOIN pieces p ON c.id = p.composer_id",
+            relevance
+        );
+
+        // Add search condition if query provided (FULLTEXT ngram)
+        if let Some(search) = search {
+            let (condition, values) = search.condition(&COMPOSER_NAME_COLUMNS);
+            where_clauses.push(condition);
+            bind_values.extend(values);
         }
 
         // Add period filter if provided and not 'all'
@@ -136,7 +224,7 @@ impl ComposerRepository {
             sql.push_str(&format!(" WHERE {}", where_clauses.join(" AND ")));
         }
 
-        sql.push_str(" GROUP BY c.id ORDER BY c.birth_year ASC LIMIT ? OFFSET ?");
+        sql.push_str(" GROUP BY c.id ORDER BY relevance DESC, c.birth_year ASC LIMIT ? OFFSET ?");
 
         // Build query with dynamic bindings
         let mut query = sqlx::query_as::<_, Composer>(&sql);
@@ -151,4 +239,183 @@ impl ComposerRepository {
 
         query.fetch_all(pool).await
     }
+
+    /// 기간 중 생존한 작곡가 (출생 연도 순)
+    pub async fn find_alive_between(
+        pool: &DbPool,
+        from: Option<i32>,
+        to: Option<i32>,
+        period: Option<String>,
+    ) -> Result<Vec<TimelineComposer>, Error> {
+        let mut sql = "SELECT id, name, english_name, period, birth_year, death_year, nationality, avatar_url
+             FROM composers WHERE 1 = 1"
+            .to_string();
+        if to.is_some() {
+            sql.push_str(" AND birth_year <= ?");
+        }
+        if from.is_some() {
+            sql.push_str(" AND (death_year IS NULL OR death_year >= ?)");
+        }
+        let period = period.filter(|p| p != "all");
+        if period.is_some() {
+            sql.push_str(" AND period = ?");
+        }
+        sql.push_str(" ORDER BY birth_year, name");
+
+        let mut query = sqlx::query_as::<_, TimelineComposer>(&sql);
+        if let Some(to) = to {
+            query = query.bind(to);
+        }
+        if let Some(from) = from {
+            query = query.bind(from);
+        }
+        if let Some(period) = period {
+            query = query.bind(period);
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// 작곡가들이 기간 중 작곡한 곡 (작곡 연도를 모르는 곡 제외)
+    pub async fn find_pieces_composed_between(
+        pool: &DbPool,
+        composer_ids: &[i32],
+        from: Option<i32>,
+        to: Option<i32>,
+    ) -> Result<Vec<TimelinePiece>, Error> {
+        if composer_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; composer_ids.len()].join(", ");
+        let mut sql = format!(
+            "SELECT id, composer_id, title, title_en, opus_number, composition_year
+             FROM pieces
+             WHERE composer_id IN ({}) AND composition_year IS NOT NULL",
+            placeholders
+        );
+        if from.is_some() {
+            sql.push_str(" AND composition_year >= ?");
+        }
+        if to.is_some() {
+            sql.push_str(" AND composition_year <= ?");
+        }
+        sql.push_str(" ORDER BY composition_year, id");
+
+        let mut query = sqlx::query_as::<_, TimelinePiece>(&sql);
+        for id in composer_ids {
+            query = query.bind(id);
+        }
+        if let Some(from) = from {
+            query = query.bind(from);
+        }
+        if let Some(to) = to {
+            query = query.bind(to);
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// 양쪽 모두 목록에 있는 작곡가인 관계
+    pub async fn find_relations_among(
+        pool: &DbPool,
+        composer_ids: &[i32],
+    ) -> Result<Vec<ComposerRelationWithNames>, Error> {
+        if composer_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; composer_ids.len()].join(", ");
+        let sql = format!(
+            "{} WHERE r.from_composer_id IN ({}) AND r.to_composer_id IN ({})
+             ORDER BY f.birth_year, t.birth_year, r.relation_type",
+            RELATION_WITH_NAMES_SELECT, placeholders, placeholders
+        );
+
+        let mut query = sqlx::query_as::<_, ComposerRelationWithNames>(&sql);
+        for _ in 0..2 {
+            for id in composer_ids {
+                query = query.bind(id);
+            }
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// 작곡가가 어느 쪽이든 포함된 관계
+    pub async fn find_relations(pool: &DbPool, composer_id: i32) -> Result<Vec<ComposerRelationWithNames>, Error> {
+        let sql = format!(
+            "{} WHERE r.from_composer_id = ? OR r.to_composer_id = ?
+             ORDER BY r.relation_type, f.birth_year, t.birth_year",
+            RELATION_WITH_NAMES_SELECT
+        );
+        sqlx::query_as::<_, ComposerRelationWithNames>(&sql)
+            .bind(composer_id)
+            .bind(composer_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn find_relation(
+        pool: &DbPool,
+        composer_id: i32,
+        relation_id: i32,
+    ) -> Result<Option<ComposerRelation>, Error> {
+        sqlx::query_as::<_, ComposerRelation>(
+            "SELECT id, from_composer_id, to_composer_id, relation_type, notes
+             FROM composer_relations
+             WHERE id = ? AND (from_composer_id = ? OR to_composer_id = ?)",
+        )
+        .bind(relation_id)
+        .bind(composer_id)
+        .bind(composer_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 같은 관계가 이미 있으면 설명만 갱신하고 기존 id 반환
+    pub async fn create_relation(
+        pool: &DbPool,
+        from_composer_id: i32,
+        relation: CreateComposerRelation,
+    ) -> Result<i32, Error> {
+        let result = sqlx::query(
+            "INSERT INTO composer_relations (from_composer_id, to_composer_id, relation_type, notes)
+             VALUES (?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE id = LAST_INSERT_ID(id), notes = COALESCE(VALUES(notes), notes)",
+        )
+        .bind(from_composer_id)
+        .bind(relation.to_composer_id)
+        .bind(&relation.relation_type)
+        .bind(&relation.notes)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i32)
+    }
+
+    pub async fn update_relation(
+        pool: &DbPool,
+        current: ComposerRelation,
+        relation: UpdateComposerRelation,
+    ) -> Result<u64, Error> {
+        let result = sqlx::query("UPDATE composer_relations SET relation_type = ?, notes = ? WHERE id = ?")
+            .bind(relation.relation_type.unwrap_or(current.relation_type))
+            .bind(relation.notes.or(current.notes))
+            .bind(current.id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_relation(pool: &DbPool, composer_id: i32, relation_id: i32) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "DELETE FROM composer_relations WHERE id = ? AND (from_composer_id = ? OR to_composer_id = ?)",
+        )
+        .bind(relation_id)
+        .bind(composer_id)
+        .bind(composer_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
 }
@@ -1,6 +1,12 @@
 use crate::db::DbPool;
-use super::model::{Composer, CreateComposer, UpdateComposer, ComposerWithMajorPieces};
+use super::model::{
+    Composer, ComposerRelation, ComposerRelationWithNames, ComposerWithMajorPieces, CreateComposer,
+    CreateComposerRelation, Timeline, UpdateComposer, UpdateComposerRelation,
+};
 use super::repository::ComposerRepository;
+use super::timeline;
+use crate::piece::model::Piece;
+use crate::search::SearchText;
 
 pub struct ComposerService;
 
@@ -11,8 +17,39 @@ impl ComposerService {
             .map_err(|e| e.to_string())
     }
 
+    /// 주요 곡 포함 (표시 순서대로)
     pub async fn get_composer_by_id(pool: &DbPool, id: i32) -> Result<Option<ComposerWithMajorPieces>, String> {
-        ComposerRepository::find_by_id(pool, id)
+        let Some(mut composer) = ComposerRepository::find_by_id(pool, id)
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+
+        composer.major_pieces = Self::get_major_pieces(pool, id).await?;
+        Ok(Some(composer))
+    }
+
+    pub async fn get_major_pieces(pool: &DbPool, id: i32) -> Result<Vec<Piece>, String> {
+        ComposerRepository::find_major_pieces(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn set_major_pieces(pool: &DbPool, id: i32, piece_ids: &[i32]) -> Result<(), String> {
+        ComposerRepository::set_major_pieces(pool, id, piece_ids)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn reorder_major_pieces(pool: &DbPool, id: i32, piece_ids: &[i32]) -> Result<u64, String> {
+        ComposerRepository::reorder_major_pieces(pool, id, piece_ids)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_major_piece(pool: &DbPool, id: i32, piece_id: i32) -> Result<u64, String> {
+        ComposerRepository::delete_major_piece(pool, id, piece_id)
             .await
             .map_err(|e| e.to_string())
     }
@@ -45,7 +82,77 @@ impl ComposerService {
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(20);
 
-        ComposerRepository::search_composers(pool, query, period, offset, limit)
+        let search = SearchText::build(pool, query.as_deref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        ComposerRepository::search_composers(pool, search.as_ref(), period, offset, limit)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 기간 중 생존한 작곡가, 그 기간에 작곡한 곡, 작곡가 사이의 관계
+    pub async fn get_timeline(
+        pool: &DbPool,
+        from: Option<i32>,
+        to: Option<i32>,
+        period: Option<String>,
+    ) -> Result<Timeline, String> {
+        let mut composers = ComposerRepository::find_alive_between(pool, from, to, period)
+            .await
+            .map_err(|e| e.to_string())?;
+        let ids: Vec<i32> = composers.iter().map(|c| c.id).collect();
+
+        let pieces = ComposerRepository::find_pieces_composed_between(pool, &ids, from, to)
+            .await
+            .map_err(|e| e.to_string())?;
+        timeline::attach_pieces(&mut composers, pieces);
+
+        let relations = ComposerRepository::find_relations_among(pool, &ids)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Timeline { from, to, composers, relations })
+    }
+
+    pub async fn get_composer_relations(pool: &DbPool, id: i32) -> Result<Vec<ComposerRelationWithNames>, String> {
+        ComposerRepository::find_relations(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_composer_relation(
+        pool: &DbPool,
+        id: i32,
+        relation_id: i32,
+    ) -> Result<Option<ComposerRelation>, String> {
+        ComposerRepository::find_relation(pool, id, relation_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn create_composer_relation(
+        pool: &DbPool,
+        id: i32,
+        relation: CreateComposerRelation,
+    ) -> Result<i32, String> {
+        ComposerRepository::create_relation(pool, id, relation)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn update_composer_relation(
+        pool: &DbPool,
+        current: ComposerRelation,
+        relation: UpdateComposerRelation,
+    ) -> Result<u64, String> {
+        ComposerRepository::update_relation(pool, current, relation)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_composer_relation(pool: &DbPool, id: i32, relation_id: i32) -> Result<u64, String> {
+        ComposerRepository::delete_relation(pool, id, relation_id)
             .await
             .map_err(|e| e.to_string())
     }
@@ -0,0 +1,28 @@
+use super::model::{TimelineComposer, TimelinePiece};
+use std::collections::HashMap;
+
+/// 연표 범위 검증 (한쪽만 지정하면 그쪽으로 열린 범위)
+pub fn validate_range(from: Option<i32>, to: Option<i32>) -> Result<(), String> {
+    match (from, to) {
+        (Some(from), Some(to)) if from > to => Err(format!("from ({}) must not be after to ({})", from, to)),
+        _ => Ok(()),
+    }
+}
+
+/// 곡을 작곡가별로 나눠 붙임 (작곡 연도 순, 목록에 없는 작곡가의 곡은 버림)
+pub fn attach_pieces(composers: &mut [TimelineComposer], pieces: Vec<TimelinePiece>) {
+    let index: HashMap<i32, usize> = composers
+        .iter()
+        .enumerate()
+        .map(|(i, composer)| (composer.id, i))
+        .collect();
+
+    for piece in pieces {
+        if let Some(&i) = index.get(&piece.composer_id) {
+            composers[i].pieces.push(piece);
+        }
+    }
+    for composer in composers.iter_mut() {
+        composer.pieces.sort_by_key(|piece| piece.composition_year);
+    }
+}
@@ -1,6 +1,8 @@
+use super::lineup::validate_lineup;
 use super::model::{
-    ConcertListItem, ConcertTicketVendor, ConcertWithArtists, ConcertWithDetails, CreateConcert,
-    SubmitRating, UpdateConcert,
+    ConcertArtist, ConcertFacets, ConcertLineupEntry, ConcertListItem, ConcertSearchFilter,
+    ConcertTicketVendor, ConcertWithArtists, ConcertWithDetails, CreateConcert, SubmitRating,
+    UpdateConcert, CONCERT_SORTS,
 };
 use super::service::ConcertService;
 use crate::auth::{AuthenticatedUser, ModeratorUser};
@@ -70,6 +72,38 @@ pub async fn update_concert(
     }
 }
 
+/// 출연진 교체 (ensembleId로 지휘자/협연자를 함께 출연한 앙상블과 연결)
+/// Moderator 권한 필요
+#[put("/concerts/<id>/artists", data = "<lineup>")]
+pub async fn set_concert_artists(
+    pool: &State<DbPool>,
+    id: i32,
+    lineup: Json<Vec<ConcertLineupEntry>>,
+    _moderator: ModeratorUser,
+) -> Result<Json<Vec<ConcertArtist>>, Status> {
+    let lineup = lineup.into_inner();
+    let artist_types = match ConcertService::get_lineup_artist_types(pool, &lineup).await {
+        Ok(artist_types) => artist_types,
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get lineup artists for concert {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+    if let Err(e) = validate_lineup(&lineup, &artist_types) {
+        Logger::warn("API", &format!("Invalid lineup for concert {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match ConcertService::set_concert_artists(pool, id, lineup).await {
+        Ok(Some(artists)) => Ok(Json(artists)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to set artists for concert {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
 #[delete("/concerts/<id>")]
 pub async fn delete_concert(
     pool: &State<DbPool>,
@@ -126,21 +160,6 @@ pub async fn get_user_rating(
 // New Enhanced Endpoints
 // ============================================
 
-#[get("/concerts/featured?<area_code>&<limit>")]
-pub async fn get_featured_concerts(
-    pool: &State<DbPool>,
-    area_code: Option<String>,
-    limit: Option<i32>,
-) -> Result<Json<Vec<ConcertWithDetails>>, Status> {
-    match ConcertService::get_featured_concerts(pool, area_code, limit).await {
-        Ok(concerts) => Ok(Json(concerts)),
-        Err(e) => {
-            Logger::error("API", &format!("Failed to get featured concerts: {}", e));
-            Err(Status::InternalServerError)
-        }
-    }
-}
-
 #[get("/concerts/upcoming?<sort>&<limit>")]
 pub async fn get_upcoming_concerts(
     pool: &State<DbPool>,
@@ -156,20 +175,33 @@ pub async fn get_upcoming_concerts(
     }
 }
 
-#[get("/concerts/search?<q>&<genre>&<area>&<status>&<offset>&<limit>")]
+/// 공연 검색 (검색어 + 필터 + 정렬)
+/// 필터: genre, area, status, from, to, venue_id, artist_id, composer_id,
+/// is_visit, is_festival, is_child, is_open_run, age, min_price, max_price
+/// sort: date, date_desc, rating, popularity, price, price_desc
+#[get("/concerts/search?<q>&<sort>&<offset>&<limit>&<filter..>")]
 pub async fn search_concerts(
     pool: &State<DbPool>,
     q: Option<String>,
-    genre: Option<String>,
-    area: Option<String>,
-    status: Option<String>,
+    sort: Option<String>,
     offset: Option<i64>,
     limit: Option<i64>,
+    filter: ConcertSearchFilter,
 ) -> Result<Json<Vec<ConcertListItem>>, Status> {
+    if let Err(e) = filter.validate() {
+        Logger::warn("API", &format!("Invalid concert search filter: {}", e));
+        return Err(Status::BadRequest);
+    }
+    if let Some(s) = &sort {
+        if !CONCERT_SORTS.contains(&s.as_str()) {
+            Logger::warn("API", &format!("Unknown concert sort: {}", s));
+            return Err(Status::BadRequest);
+        }
+    }
+
     // Always use search_concerts_by_text for pagination support
     // It handles both text search and filter-only search
-    match ConcertService::search_concerts_by_text(pool, q, genre, area, status, offset, limit).await
-    {
+    match ConcertService::search_concerts_by_text(pool, q, filter, sort, offset, limit).await {
         Ok(concerts) => Ok(Json(concerts)),
         Err(e) => {
             Logger::error("API", &format!("Failed to search concerts: {}", e));
@@ -178,6 +210,28 @@ pub async fn search_concerts(
     }
 }
 
+/// 검색 필터 칩용 항목별 값과 공연 수 (area, genre, status, month, venue, isVisit, isFestival)
+/// 검색과 같은 q/필터를 받으며, 각 항목은 자기 필터를 빼고 집계
+#[get("/concerts/facets?<q>&<filter..>")]
+pub async fn get_concert_facets(
+    pool: &State<DbPool>,
+    q: Option<String>,
+    filter: ConcertSearchFilter,
+) -> Result<Json<ConcertFacets>, Status> {
+    if let Err(e) = filter.validate() {
+        Logger::warn("API", &format!("Invalid concert facet filter: {}", e));
+        return Err(Status::BadRequest);
+    }
+
+    match ConcertService::get_concert_facets(pool, q, filter).await {
+        Ok(facets) => Ok(Json(facets)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get concert facets: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
 #[get("/concerts/<id>/ticket-vendors")]
 pub async fn get_ticket_vendors(
     pool: &State<DbPool>,
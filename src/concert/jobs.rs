@@ -0,0 +1,26 @@
+use super::service::ConcertService;
+use crate::scheduler::{JobDefinition, JobFuture};
+use sqlx::MySqlPool;
+
+pub const CONCERT_STATUS_JOB: &str = "concert_status";
+
+/// 공연 기간 기준 상태 갱신 (매일 자정 직후)
+pub fn status_update() -> JobDefinition {
+    JobDefinition {
+        name: CONCERT_STATUS_JOB,
+        description: "공연 상태 갱신 (공연중/공연완료)",
+        cron: "0 5 0 * * *",
+        depends_on: &[],
+        run: run_status_update,
+    }
+}
+
+fn run_status_update(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        ConcertService::update_statuses_by_date(&pool)
+            .await
+            .map(|(ongoing, completed)| {
+                format!("{} ongoing, {} completed", ongoing, completed).into()
+            })
+    })
+}
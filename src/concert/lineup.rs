@@ -0,0 +1,77 @@
+use super::model::ConcertLineupEntry;
+use crate::artist::model::{is_ensemble_type, ARTIST_TYPE_CONDUCTOR, ARTIST_TYPE_SOLOIST};
+use std::collections::{HashMap, HashSet};
+
+/// KOPIS 출연진 항목에서 이름과 역할 분리
+///
+/// "정명훈(지휘)" → ("정명훈", Some("지휘")), "손열음" → ("손열음", None)
+pub fn split_cast_role(entry: &str) -> (&str, Option<&str>) {
+    let entry = entry.trim();
+    if let Some(inner) = entry.strip_suffix(')') {
+        if let Some((name, role)) = inner.split_once('(') {
+            let (name, role) = (name.trim(), role.trim());
+            if !name.is_empty() {
+                return (name, Some(role).filter(|r| !r.is_empty()));
+            }
+        }
+    }
+    (entry, None)
+}
+
+/// 출연진에 앙상블이 하나뿐이면 지휘자/독주자를 그 앙상블과 함께 출연한 것으로 연결
+/// (앙상블이 여럿이면 누구와 함께인지 알 수 없으므로 그대로 둠)
+pub fn link_single_ensemble(lineup: &mut [ConcertLineupEntry], artist_types: &HashMap<i32, String>) {
+    let artist_type = |artist_id: i32| artist_types.get(&artist_id).map(String::as_str);
+
+    let ensembles: Vec<i32> = lineup
+        .iter()
+        .map(|entry| entry.artist_id)
+        .filter(|&id| artist_type(id).is_some_and(is_ensemble_type))
+        .collect();
+    let [ensemble_id] = ensembles[..] else {
+        return;
+    };
+
+    for entry in lineup.iter_mut() {
+        let performs_with_ensemble = matches!(
+            artist_type(entry.artist_id),
+            Some(ARTIST_TYPE_CONDUCTOR | ARTIST_TYPE_SOLOIST)
+        );
+        if performs_with_ensemble && entry.ensemble_id.is_none() {
+            entry.ensemble_id = Some(ensemble_id);
+        }
+    }
+}
+
+/// 직접 지정한 출연진 검증
+///
+/// artist_types: 출연진 아티스트의 유형 (없는 아티스트는 빠져 있음)
+/// ensembleId는 같은 출연진에 있는 앙상블이어야 함
+pub fn validate_lineup(lineup: &[ConcertLineupEntry], artist_types: &HashMap<i32, String>) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for entry in lineup {
+        if !artist_types.contains_key(&entry.artist_id) {
+            return Err(format!("Artist {} not found", entry.artist_id));
+        }
+        if !seen.insert(entry.artist_id) {
+            return Err(format!("Artist {} is listed more than once", entry.artist_id));
+        }
+    }
+
+    for entry in lineup {
+        let Some(ensemble_id) = entry.ensemble_id else {
+            continue;
+        };
+        if ensemble_id == entry.artist_id || !seen.contains(&ensemble_id) {
+            return Err(format!(
+                "Ensemble {} of artist {} must be another artist in the lineup",
+                ensemble_id, entry.artist_id
+            ));
+        }
+        if !artist_types.get(&ensemble_id).is_some_and(|t| is_ensemble_type(t)) {
+            return Err(format!("Artist {} is not an ensemble", ensemble_id));
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 pub mod api;
+pub mod jobs;
+pub mod lineup;
 pub mod model;
+pub mod price;
 pub mod repository;
 pub mod service;
 
@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 #[serde(rename_all = "camelCase")]
@@ -18,6 +18,10 @@ pub struct Concert {
 
     // Basic info
     pub price_info: Option<String>,
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
+    pub is_free: Option<bool>,
+    pub is_price_undecided: Option<bool>,
     pub poster_url: Option<String>,
     pub program: Option<String>,
     pub status: String,
@@ -67,7 +71,22 @@ pub struct ConcertArtist {
     pub concert_id: i32,
     pub artist_id: i32,
     pub artist_name: String,
+    /// soloist, conductor, orchestra, chamber_ensemble, choir
+    pub artist_type: String,
     pub role: Option<String>,
+    /// 함께 출연한 앙상블 (지휘자/협연자가 어느 오케스트라와 함께인지)
+    pub ensemble_id: Option<i32>,
+}
+
+/// 출연진 항목 (공연 출연진 지정, KOPIS 동기화)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcertLineupEntry {
+    pub artist_id: i32,
+    /// 예: 지휘, 피아노, 협연
+    pub role: Option<String>,
+    /// 같은 출연진에 있는 앙상블 id
+    pub ensemble_id: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -123,6 +142,120 @@ pub struct UpdateConcert {
     pub status: Option<String>,
 }
 
+/// 공연 검색 정렬 (지정하지 않으면 관련도 → 오늘과 가까운 순)
+/// date: 시작일 순, date_desc: 최근 시작 순, rating: 평점 순,
+/// popularity: 예매 순위 → 평점 수 순, price / price_desc: 최저가 낮은 순 / 최고가 높은 순
+pub const CONCERT_SORTS: [&str; 6] = ["date", "date_desc", "rating", "popularity", "price", "price_desc"];
+
+/// 공연 검색 필터 (쿼리 파라미터 이름 그대로)
+#[derive(Debug, Clone, Default, FromForm)]
+pub struct ConcertSearchFilter {
+    pub genre: Option<String>,
+    pub area: Option<String>,
+    pub status: Option<String>,
+    /// 이 날짜 이후에도 진행 중인 공연 (YYYY-MM-DD)
+    pub from: Option<String>,
+    /// 이 날짜까지 시작하는 공연 (YYYY-MM-DD)
+    pub to: Option<String>,
+    pub venue_id: Option<i32>,
+    pub artist_id: Option<i32>,
+    pub composer_id: Option<i32>,
+    pub is_visit: Option<bool>,
+    pub is_festival: Option<bool>,
+    pub is_child: Option<bool>,
+    pub is_open_run: Option<bool>,
+    /// 관람자 나이 (관람 연령 제한이 이 나이 이하인 공연)
+    pub age: Option<i32>,
+    /// 가격대 (원, 공연의 최저~최고 가격과 겹치면 포함)
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
+}
+
+impl ConcertSearchFilter {
+    pub fn validate(&self) -> Result<(), String> {
+        let from = parse_filter_date("from", self.from.as_deref())?;
+        let to = parse_filter_date("to", self.to.as_deref())?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err("from must not be after to".to_string());
+            }
+        }
+
+        if self.age.is_some_and(|age| age < 0) {
+            return Err("age must not be negative".to_string());
+        }
+        if self.min_price.is_some_and(|p| p < 0) || self.max_price.is_some_and(|p| p < 0) {
+            return Err("price must not be negative".to_string());
+        }
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price) {
+            if min > max {
+                return Err("min_price must not be greater than max_price".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_filter_date(name: &str, value: Option<&str>) -> Result<Option<NaiveDate>, String> {
+    value
+        .map(|v| {
+            NaiveDate::parse_from_str(v, "%Y-%m-%d")
+                .map_err(|_| format!("{} must be YYYY-MM-DD: {}", name, v))
+        })
+        .transpose()
+}
+
+/// 집계 대상 항목 (ConcertFacets 필드)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcertFacet {
+    Area,
+    Genre,
+    Status,
+    /// 시작 월 (YYYY-MM)
+    Month,
+    Venue,
+    IsVisit,
+    IsFestival,
+}
+
+impl ConcertFacet {
+    pub const ALL: [ConcertFacet; 7] = [
+        ConcertFacet::Area,
+        ConcertFacet::Genre,
+        ConcertFacet::Status,
+        ConcertFacet::Month,
+        ConcertFacet::Venue,
+        ConcertFacet::IsVisit,
+        ConcertFacet::IsFestival,
+    ];
+}
+
+/// 항목 값별 공연 수
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct FacetCount {
+    /// 필터에 그대로 넘길 값 (venue는 id, 플래그는 true/false)
+    pub value: String,
+    /// 표시 이름 (venue만)
+    pub label: Option<String>,
+    pub count: i64,
+}
+
+/// 검색 필터 항목별 선택 가능한 값과 공연 수
+/// 각 항목은 자기 자신의 필터를 빼고 집계 (선택 중인 칩 외 다른 값의 수도 보여주기 위함)
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcertFacets {
+    pub area: Vec<FacetCount>,
+    pub genre: Vec<FacetCount>,
+    pub status: Vec<FacetCount>,
+    pub month: Vec<FacetCount>,
+    pub venue: Vec<FacetCount>,
+    pub is_visit: Vec<FacetCount>,
+    pub is_festival: Vec<FacetCount>,
+}
+
 // ============================================
 // Related Models
 // ============================================
@@ -147,6 +280,16 @@ pub struct ConcertImage {
     pub display_order: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcertPrice {
+    pub id: i32,
+    pub concert_id: i32,
+    pub seat_class: Option<String>,
+    pub amount: i32,
+    pub display_order: i32,
+}
+
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 #[serde(rename_all = "camelCase")]
 pub struct ConcertBoxofficeRanking {
@@ -156,6 +299,7 @@ pub struct ConcertBoxofficeRanking {
     pub genre_name: Option<String>,
     pub kopis_area_code: Option<String>,
     pub area_name: Option<String>,
+    pub period: String,
     pub ranking: i32,
     pub seat_scale: Option<String>,
     pub performance_count: Option<i32>,
@@ -191,6 +335,8 @@ pub struct ConcertListItem {
     pub is_open_run: Option<bool>,
     pub is_visit: Option<bool>,
     pub is_festival: Option<bool>,
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
 }
 
 // Full detail response with all related data
@@ -202,5 +348,6 @@ pub struct ConcertWithDetails {
     pub artists: Vec<ConcertArtist>,
     pub ticket_vendors: Vec<ConcertTicketVendor>,
     pub images: Vec<ConcertImage>,
+    pub prices: Vec<ConcertPrice>,
     pub boxoffice_ranking: Option<ConcertBoxofficeRanking>,
 }
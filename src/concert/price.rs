@@ -0,0 +1,112 @@
+/// 좌석 등급명 최대 길이 (concert_prices.seat_class)
+const MAX_SEAT_CLASS_LENGTH: usize = 100;
+
+/// KOPIS 가격 안내(pcseguidance) 분석 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedPrices {
+    /// 좌석 등급별 가격 (안내 순서, 등급명이 없으면 None)
+    pub seats: Vec<SeatPrice>,
+    /// 모든 좌석이 무료
+    pub is_free: bool,
+    /// 금액을 찾을 수 없음 (추후 공지, 가격 미정 등)
+    pub is_undecided: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeatPrice {
+    pub seat_class: Option<String>,
+    /// 원 (무료=0)
+    pub amount: i32,
+}
+
+impl ParsedPrices {
+    /// (최저, 최고) 가격
+    pub fn range(&self) -> Option<(i32, i32)> {
+        let min = self.seats.iter().map(|s| s.amount).min()?;
+        let max = self.seats.iter().map(|s| s.amount).max()?;
+        Some((min, max))
+    }
+}
+
+/// 가격 안내를 좌석 등급별 가격으로 분석
+///
+/// 예: "R석 150,000원, S석 120,000원" → [R석 150000, S석 120000]
+///     "전석 무료" → [전석 0] (무료), "추후 공지" / 없음 → [] (가격 미정)
+pub fn parse_prices(price_info: Option<&str>) -> ParsedPrices {
+    let text = price_info.unwrap_or_default();
+    let chars: Vec<char> = text.chars().collect();
+    let mut seats: Vec<SeatPrice> = Vec::new();
+
+    // 금액("원", "만원") 또는 "무료" 앞의 글자를 등급명으로 사용
+    let mut name_start = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        let token = if chars[i].is_ascii_digit() {
+            parse_amount(&chars, i)
+        } else if chars[i..].starts_with(&['무', '료']) {
+            Some((0, i + 2))
+        } else {
+            None
+        };
+
+        match token {
+            Some((amount, end)) => {
+                let seat = SeatPrice {
+                    seat_class: clean_seat_class(&chars[name_start..i]),
+                    amount,
+                };
+                if !seats.contains(&seat) {
+                    seats.push(seat);
+                }
+                name_start = end;
+                i = end;
+            }
+            None => {
+                // 금액이 아닌 숫자(예: "2층")는 등급명의 일부
+                i += 1;
+            }
+        }
+    }
+
+    ParsedPrices {
+        is_free: !seats.is_empty() && seats.iter().all(|s| s.amount == 0),
+        is_undecided: seats.is_empty(),
+        seats,
+    }
+}
+
+/// start 위치의 "150,000원" / "3만원" → (금액, 끝 위치)
+fn parse_amount(chars: &[char], start: usize) -> Option<(i32, usize)> {
+    let mut value: i64 = 0;
+    let mut i = start;
+    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == ',') {
+        if let Some(digit) = chars[i].to_digit(10) {
+            value = value.saturating_mul(10).saturating_add(digit as i64);
+        }
+        i += 1;
+    }
+
+    while i < chars.len() && chars[i] == ' ' {
+        i += 1;
+    }
+    let (multiplier, end) = match (chars.get(i), chars.get(i + 1)) {
+        (Some('원'), _) => (1, i + 1),
+        (Some('만'), Some('원')) => (10_000, i + 2),
+        _ => return None,
+    };
+
+    i32::try_from(value.saturating_mul(multiplier))
+        .ok()
+        .map(|amount| (amount, end))
+}
+
+/// 구분자/괄호/콜론을 걷어낸 등급명
+fn clean_seat_class(chars: &[char]) -> Option<String> {
+    let is_separator = |c: char| c.is_whitespace() || ",/|:;()[]-·".contains(c);
+    let name: String = chars.iter().collect();
+    let name = name.trim_matches(is_separator);
+    if name.is_empty() {
+        return None;
+    }
+    Some(name.chars().take(MAX_SEAT_CLASS_LENGTH).collect())
+}
@@ -1,11 +1,32 @@
 use super::model::{
-    Concert, ConcertArtist, ConcertBoxofficeRanking, ConcertImage, ConcertListItem,
-    ConcertTicketVendor, ConcertWithArtists, ConcertWithDetails, CreateConcert, UpdateConcert,
+    Concert, ConcertArtist, ConcertBoxofficeRanking, ConcertFacet, ConcertImage, ConcertLineupEntry,
+    ConcertListItem, ConcertPrice, ConcertSearchFilter, ConcertTicketVendor, ConcertWithArtists,
+    ConcertWithDetails, CreateConcert, FacetCount, UpdateConcert,
 };
+use super::price::parse_prices;
 use crate::db::DbPool;
+use crate::search::SearchText;
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use sqlx::Error;
 
+/// FULLTEXT 인덱스 ft_concerts_search 컬럼
+const CONCERT_SEARCH_COLUMNS: [&str; 4] = ["c.title", "c.composer_info", "c.cast", "c.facility_name"];
+
+/// 주목 공연 순위 (장르 × 지역별로 여러 건일 수 있어 공연당 최고 순위 하나만)
+const FEATURED_RANKING_JOIN: &str = "LEFT JOIN (
+    SELECT concert_id, MIN(ranking) AS ranking
+    FROM concert_boxoffice_rankings
+    WHERE is_featured = TRUE
+    GROUP BY concert_id
+  ) cbr ON c.id = cbr.concert_id";
+
+/// 관람 연령 (나이): "만 7세 이상" → 7, "36개월 이상" → 3, 숫자가 없으면 (전체 관람가) 0
+const MIN_AGE_SQL: &str = "CASE
+    WHEN c.age_restriction LIKE '%개월%' THEN CEIL(CAST(REGEXP_SUBSTR(c.age_restriction, '[0-9]+') AS UNSIGNED) / 12)
+    ELSE COALESCE(CAST(REGEXP_SUBSTR(c.age_restriction, '[0-9]+') AS UNSIGNED), 0)
+  END";
+
 pub struct ConcertRepository;
 
 impl ConcertRepository {
@@ -15,7 +36,8 @@ impl ConcertRepository {
              DATE_FORMAT(start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(end_date, '%Y-%m-%d') as end_date,
              concert_time,
-             price_info, poster_url, program, status, rating, rating_count,
+             price_info, min_price, max_price, is_free, is_price_undecided,
+             poster_url, program, status, rating, rating_count,
              kopis_id, DATE_FORMAT(kopis_updated_at, '%Y-%m-%d %H:%i:%s') as kopis_updated_at, data_source, venue_kopis_id,
              genre, area, facility_name, is_open_run,
              cast, crew, runtime, age_restriction, synopsis, performance_schedule,
@@ -34,25 +56,30 @@ impl ConcertRepository {
         offset: i64,
         limit: i64,
     ) -> Result<Vec<ConcertListItem>, Error> {
-        sqlx::query_as::<_, ConcertListItem>(
+        let query = format!(
             "SELECT c.id, c.title, c.venue_id,
              DATE_FORMAT(c.start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(c.end_date, '%Y-%m-%d') as end_date,
              c.concert_time,
              c.poster_url, c.status, c.rating, c.rating_count,
              c.genre, c.area, c.facility_name, c.is_open_run, c.is_visit, c.is_festival,
+             c.min_price, c.max_price,
              cbr.ranking as boxoffice_ranking
              FROM concerts c
-             LEFT JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
+             {}
+             WHERE c.status <> 'stale'
              ORDER BY
                CASE WHEN c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')) THEN 0 ELSE 1 END,
                ABS(DATEDIFF(c.start_date, DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')))) ASC
              LIMIT ? OFFSET ?",
-        )
-        .bind(limit)
-        .bind(offset)
-        .fetch_all(pool)
-        .await
+            FEATURED_RANKING_JOIN
+        );
+
+        sqlx::query_as::<_, ConcertListItem>(&query)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
     }
 
     pub async fn find_all_with_artists(pool: &DbPool) -> Result<Vec<ConcertWithArtists>, Error> {
@@ -72,7 +99,8 @@ impl ConcertRepository {
         concert_id: i32,
     ) -> Result<Vec<ConcertArtist>, Error> {
         sqlx::query_as::<_, ConcertArtist>(
-            "SELECT ca.id, ca.concert_id, ca.artist_id, a.name as artist_name, ca.role
+            "SELECT ca.id, ca.concert_id, ca.artist_id, a.name as artist_name, a.artist_type,
+             ca.role, ca.ensemble_id
              FROM concert_artists ca
              INNER JOIN artists a ON ca.artist_id = a.id
              WHERE ca.concert_id = ?
@@ -83,13 +111,73 @@ impl ConcertRepository {
         .await
     }
 
+    /// 아티스트 출연 공연 (upcoming: 종료일이 오늘 이후면 예정, 아니면 지난 공연)
+    /// 예정 공연은 가까운 순, 지난 공연은 최근 순, 취소/사라진 공연 제외
+    pub async fn find_list_by_artist(
+        pool: &DbPool,
+        artist_id: i32,
+        upcoming: bool,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<ConcertListItem>, Error> {
+        let query = format!(
+            "SELECT c.id, c.title, c.venue_id,
+             DATE_FORMAT(c.start_date, '%Y-%m-%d') as start_date,
+             DATE_FORMAT(c.end_date, '%Y-%m-%d') as end_date,
+             c.concert_time,
+             c.poster_url, c.status, c.rating, c.rating_count,
+             c.genre, c.area, c.facility_name, c.is_open_run, c.is_visit, c.is_festival,
+             c.min_price, c.max_price,
+             cbr.ranking as boxoffice_ranking
+             FROM concerts c
+             INNER JOIN concert_artists ca ON c.id = ca.concert_id
+             {}
+             WHERE ca.artist_id = ? AND {}
+             ORDER BY c.start_date {}, c.id
+             LIMIT ? OFFSET ?",
+            FEATURED_RANKING_JOIN,
+            Self::artist_period_condition(upcoming),
+            if upcoming { "ASC" } else { "DESC" }
+        );
+
+        sqlx::query_as::<_, ConcertListItem>(&query)
+            .bind(artist_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn count_by_artist(pool: &DbPool, artist_id: i32, upcoming: bool) -> Result<i64, Error> {
+        let query = format!(
+            "SELECT COUNT(*) FROM concerts c
+             INNER JOIN concert_artists ca ON c.id = ca.concert_id
+             WHERE ca.artist_id = ? AND {}",
+            Self::artist_period_condition(upcoming)
+        );
+
+        sqlx::query_scalar::<_, i64>(&query)
+            .bind(artist_id)
+            .fetch_one(pool)
+            .await
+    }
+
+    fn artist_period_condition(upcoming: bool) -> String {
+        format!(
+            "c.status NOT IN ('cancelled', 'stale')
+             AND COALESCE(c.end_date, c.start_date) {} DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00'))",
+            if upcoming { ">=" } else { "<" }
+        )
+    }
+
     pub async fn find_by_id(pool: &DbPool, id: i32) -> Result<Option<Concert>, Error> {
         sqlx::query_as::<_, Concert>(
             "SELECT id, title, composer_info, venue_id,
              DATE_FORMAT(start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(end_date, '%Y-%m-%d') as end_date,
              concert_time,
-             price_info, poster_url, program, status, rating, rating_count,
+             price_info, min_price, max_price, is_free, is_price_undecided,
+             poster_url, program, status, rating, rating_count,
              kopis_id, DATE_FORMAT(kopis_updated_at, '%Y-%m-%d %H:%i:%s') as kopis_updated_at, data_source, venue_kopis_id,
              genre, area, facility_name, is_open_run,
              cast, crew, runtime, age_restriction, synopsis, performance_schedule,
@@ -123,7 +211,8 @@ impl ConcertRepository {
              DATE_FORMAT(c.start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(c.end_date, '%Y-%m-%d') as end_date,
              c.concert_time,
-             c.price_info, c.poster_url, c.program, c.status, c.rating, c.rating_count,
+             c.price_info, c.min_price, c.max_price, c.is_free, c.is_price_undecided,
+             c.poster_url, c.program, c.status, c.rating, c.rating_count,
              c.kopis_id, DATE_FORMAT(c.kopis_updated_at, '%Y-%m-%d %H:%i:%s') as kopis_updated_at, c.data_source, c.venue_kopis_id,
              c.genre, c.area, c.facility_name, c.is_open_run,
              c.cast, c.crew, c.runtime, c.age_restriction, c.synopsis, c.performance_schedule,
@@ -159,7 +248,10 @@ impl ConcertRepository {
         .execute(pool)
         .await?;
 
-        Ok(result.last_insert_id() as i32)
+        let id = result.last_insert_id() as i32;
+        Self::save_prices(pool, id, concert.price_info.as_deref()).await?;
+
+        Ok(id)
     }
 
     pub async fn update(pool: &DbPool, id: i32, concert: UpdateConcert) -> Result<u64, Error> {
@@ -168,6 +260,7 @@ impl ConcertRepository {
             return Ok(0);
         }
         let current = current.unwrap();
+        let price_info = concert.price_info.or(current.price_info);
 
         let result = sqlx::query(
             "UPDATE concerts SET title = ?, composer_info = ?, venue_id = ?,
@@ -181,7 +274,7 @@ impl ConcertRepository {
         .bind(concert.start_date.unwrap_or(current.start_date))
         .bind(concert.end_date.or(current.end_date))
         .bind(concert.concert_time.or(current.concert_time))
-        .bind(concert.price_info.or(current.price_info))
+        .bind(&price_info)
         .bind(concert.poster_url.or(current.poster_url))
         .bind(concert.program.or(current.program))
         .bind(concert.status.unwrap_or(current.status))
@@ -189,6 +282,8 @@ impl ConcertRepository {
         .execute(pool)
         .await?;
 
+        Self::save_prices(pool, id, price_info.as_deref()).await?;
+
         Ok(result.rows_affected())
     }
 
@@ -268,7 +363,8 @@ impl ConcertRepository {
              DATE_FORMAT(start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(end_date, '%Y-%m-%d') as end_date,
              concert_time,
-             price_info, poster_url, program, status, rating, rating_count,
+             price_info, min_price, max_price, is_free, is_price_undecided,
+             poster_url, program, status, rating, rating_count,
              kopis_id, DATE_FORMAT(kopis_updated_at, '%Y-%m-%d %H:%i:%s') as kopis_updated_at, data_source, venue_kopis_id,
              genre, area, facility_name, is_open_run,
              cast, crew, runtime, age_restriction, synopsis, performance_schedule,
@@ -388,6 +484,8 @@ impl ConcertRepository {
             .execute(pool)
             .await?;
 
+            Self::save_prices(pool, concert.id, price_info).await?;
+
             Ok(concert.id)
         } else {
             // 삽입
@@ -455,10 +553,76 @@ impl ConcertRepository {
             .execute(pool)
             .await?;
 
-            Ok(result.last_insert_id() as i32)
+            let id = result.last_insert_id() as i32;
+            Self::save_prices(pool, id, price_info).await?;
+
+            Ok(id)
         }
     }
 
+    // ============================================
+    // 좌석 등급별 가격
+    // ============================================
+
+    pub async fn find_prices_by_concert(
+        pool: &DbPool,
+        concert_id: i32,
+    ) -> Result<Vec<ConcertPrice>, Error> {
+        sqlx::query_as::<_, ConcertPrice>(
+            "SELECT id, concert_id, seat_class, amount, display_order
+             FROM concert_prices
+             WHERE concert_id = ?
+             ORDER BY display_order",
+        )
+        .bind(concert_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// price_info를 분석해 concert_prices와 가격 요약 컬럼(min/max, 무료, 미정)을 한 트랜잭션으로 다시 저장
+    pub async fn save_prices(
+        pool: &DbPool,
+        concert_id: i32,
+        price_info: Option<&str>,
+    ) -> Result<(), Error> {
+        let prices = parse_prices(price_info);
+        let range = prices.range();
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            "UPDATE concerts SET min_price = ?, max_price = ?, is_free = ?, is_price_undecided = ?
+             WHERE id = ?",
+        )
+        .bind(range.map(|(min, _)| min))
+        .bind(range.map(|(_, max)| max))
+        .bind(prices.is_free)
+        .bind(prices.is_undecided)
+        .bind(concert_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM concert_prices WHERE concert_id = ?")
+            .bind(concert_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for (idx, seat) in prices.seats.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO concert_prices (concert_id, seat_class, amount, display_order)
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(concert_id)
+            .bind(&seat.seat_class)
+            .bind(seat.amount)
+            .bind(idx as i32)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
     // ============================================
     // Ticket Vendors 관련 메소드
     // ============================================
@@ -507,12 +671,12 @@ impl ConcertRepository {
     ) -> Result<Option<ConcertBoxofficeRanking>, Error> {
         sqlx::query_as::<_, ConcertBoxofficeRanking>(
             "SELECT id, concert_id, kopis_genre_code, genre_name, kopis_area_code, area_name,
-             ranking, seat_scale, performance_count, venue_name, seat_count,
+             period, ranking, seat_scale, performance_count, venue_name, seat_count,
              DATE_FORMAT(sync_start_date, '%Y-%m-%d') as sync_start_date,
              DATE_FORMAT(sync_end_date, '%Y-%m-%d') as sync_end_date,
              synced_at, is_featured
              FROM concert_boxoffice_rankings
-             WHERE concert_id = ?
+             WHERE concert_id = ? AND is_featured = TRUE
              ORDER BY synced_at DESC
              LIMIT 1",
         )
@@ -535,6 +699,7 @@ impl ConcertRepository {
             let artists = Self::find_artists_by_concert(pool, id).await?;
             let ticket_vendors = Self::find_ticket_vendors_by_concert(pool, id).await?;
             let images = Self::find_images_by_concert(pool, id).await?;
+            let prices = Self::find_prices_by_concert(pool, id).await?;
             let boxoffice_ranking = Self::find_boxoffice_ranking_by_concert(pool, id).await?;
 
             Ok(Some(ConcertWithDetails {
@@ -542,6 +707,7 @@ impl ConcertRepository {
                 artists,
                 ticket_vendors,
                 images,
+                prices,
                 boxoffice_ranking,
             }))
         } else {
@@ -549,58 +715,6 @@ impl ConcertRepository {
         }
     }
 
-    // ============================================
-    // Featured Concerts (예매 순위 TOP)
-    // ============================================
-
-    pub async fn find_featured_concerts(
-        pool: &DbPool,
-        area_code: Option<&str>,
-        limit: i32,
-    ) -> Result<Vec<ConcertWithDetails>, Error> {
-        let query = if let Some(code) = area_code {
-            format!(
-                "SELECT DISTINCT c.id
-                 FROM concerts c
-                 INNER JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
-                 WHERE cbr.is_featured = true
-                 AND cbr.kopis_area_code = ?
-                 AND c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00'))
-                 ORDER BY cbr.ranking ASC
-                 LIMIT ?"
-            )
-        } else {
-            format!(
-                "SELECT DISTINCT c.id
-                 FROM concerts c
-                 INNER JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
-                 WHERE cbr.is_featured = true
-                 AND c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00'))
-                 ORDER BY cbr.ranking ASC
-                 LIMIT ?"
-            )
-        };
-
-        let concert_ids: Vec<(i32,)> = if let Some(code) = area_code {
-            sqlx::query_as(&query)
-                .bind(code)
-                .bind(limit)
-                .fetch_all(pool)
-                .await?
-        } else {
-            sqlx::query_as(&query).bind(limit).fetch_all(pool).await?
-        };
-
-        let mut result = Vec::new();
-        for (concert_id,) in concert_ids {
-            if let Some(concert_details) = Self::find_by_id_with_details(pool, concert_id).await? {
-                result.push(concert_details);
-            }
-        }
-
-        Ok(result)
-    }
-
     // ============================================
     // Upcoming Concerts (다가오는 공연)
     // ============================================
@@ -622,13 +736,15 @@ impl ConcertRepository {
              c.concert_time,
              c.poster_url, c.status, c.rating, c.rating_count,
              c.genre, c.area, c.facility_name, c.is_open_run, c.is_visit, c.is_festival,
+             c.min_price, c.max_price,
              cbr.ranking as boxoffice_ranking
              FROM concerts c
-             LEFT JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
+             {}
              WHERE c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00'))
-             AND c.status IN ('upcoming', 'ongoing', '공연예정', '공연중')
+             AND c.status IN ('upcoming', 'ongoing')
              {}
              LIMIT ?",
+            FEATURED_RANKING_JOIN,
             order_clause
         );
 
@@ -642,139 +758,331 @@ impl ConcertRepository {
     // Search/Filter Concerts
     // ============================================
 
-    pub async fn search_concerts(
+    /// Full-text search across concerts with filters and pagination
+    /// 정렬을 지정하지 않으면 관련도 순 (제목 일치 가중), 같으면 오늘과 가까운 순
+    pub async fn search_concerts_by_text(
         pool: &DbPool,
-        genre: Option<&str>,
-        area: Option<&str>,
-        is_visit: Option<bool>,
-        is_festival: Option<bool>,
+        search: Option<&SearchText>,
+        filter: &ConcertSearchFilter,
+        sort: Option<&str>,
+        offset: i64,
+        limit: i64,
     ) -> Result<Vec<ConcertListItem>, Error> {
-        let mut query = String::from(
+        let mut bindings: Vec<String> = Vec::new();
+
+        // 관련도 = 제목 점수 * 2 + 전체 점수
+        let relevance = match search {
+            Some(search) => {
+                let (title_score, title_values) = search.relevance(&["c.title"]);
+                let (text_score, text_values) = search.relevance(&CONCERT_SEARCH_COLUMNS);
+                bindings.extend(title_values);
+                bindings.extend(text_values);
+                format!("({}) * 2 + ({})", title_score, text_score)
+            }
+            None => "0".to_string(),
+        };
+
+        let mut query = format!(
             "SELECT c.id, c.title, c.venue_id,
              DATE_FORMAT(c.start_date, '%Y-%m-%d') as start_date,
              DATE_FORMAT(c.end_date, '%Y-%m-%d') as end_date,
              c.concert_time,
              c.poster_url, c.status, c.rating, c.rating_count,
              c.genre, c.area, c.facility_name, c.is_open_run, c.is_visit, c.is_festival,
-             cbr.ranking as boxoffice_ranking
+             c.min_price, c.max_price,
+             cbr.ranking as boxoffice_ranking,
+             {} as relevance
              FROM concerts c
-             LEFT JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
+             {}
              WHERE 1=1",
+            relevance,
+            FEATURED_RANKING_JOIN
         );
 
-        if genre.is_some() {
-            query.push_str(" AND c.genre = ?");
+        // Text search across multiple fields (FULLTEXT ngram)
+        if let Some(search) = search {
+            let (condition, values) = search.condition(&CONCERT_SEARCH_COLUMNS);
+            query.push_str(&format!(" AND {}", condition));
+            bindings.extend(values);
+        }
+
+        let (conditions, values) = Self::filter_conditions(filter);
+        query.push_str(&conditions);
+        bindings.extend(values);
+
+        // 지정한 정렬 → 관련도 → 오늘과 가까운 순 (예정 공연 먼저)
+        query.push_str(&format!(
+            " ORDER BY {}
+               relevance DESC,
+               CASE WHEN c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')) THEN 0 ELSE 1 END,
+               ABS(DATEDIFF(c.start_date, DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')))) ASC
+             LIMIT ? OFFSET ?",
+            Self::sort_clause(sort)
+        ));
+
+        let mut sql_query = sqlx::query_as::<_, ConcertListItem>(&query);
+        for value in bindings {
+            sql_query = sql_query.bind(value);
         }
-        if area.is_some() {
-            query.push_str(" AND c.area = ?");
+
+        // Bind pagination
+        sql_query = sql_query.bind(limit).bind(offset);
+
+        sql_query.fetch_all(pool).await
+    }
+
+    /// 검색 필터 조건 (" AND ..." 형태)과 바인딩 값
+    /// 값은 모두 문자열로 바인딩 (BOOLEAN은 1/0으로 넘겨 MySQL이 숫자로 비교하도록 함)
+    pub fn filter_conditions(filter: &ConcertSearchFilter) -> (String, Vec<String>) {
+        let mut conditions = String::new();
+        let mut bindings: Vec<String> = Vec::new();
+
+        if let Some(g) = &filter.genre {
+            conditions.push_str(" AND c.genre = ?");
+            bindings.push(g.clone());
         }
-        if is_visit.is_some() {
-            query.push_str(" AND c.is_visit = ?");
+        if let Some(a) = &filter.area {
+            conditions.push_str(" AND c.area = ?");
+            bindings.push(a.clone());
         }
-        if is_festival.is_some() {
-            query.push_str(" AND c.is_festival = ?");
+        if let Some(s) = &filter.status {
+            conditions.push_str(" AND c.status = ?");
+            bindings.push(s.clone());
+        } else {
+            // KOPIS에서 사라진 공연은 명시적으로 요청할 때만 포함
+            conditions.push_str(" AND c.status <> 'stale'");
         }
 
-        query.push_str(" ORDER BY c.start_date DESC");
+        // 기간: 종료일(없으면 시작일)이 from 이후, 시작일이 to 이전
+        if let Some(from) = &filter.from {
+            conditions.push_str(" AND COALESCE(c.end_date, c.start_date) >= ?");
+            bindings.push(from.clone());
+        }
+        if let Some(to) = &filter.to {
+            conditions.push_str(" AND c.start_date <= ?");
+            bindings.push(to.clone());
+        }
 
-        let mut sql_query = sqlx::query_as::<_, ConcertListItem>(&query);
+        if let Some(venue_id) = filter.venue_id {
+            conditions.push_str(" AND c.venue_id = ?");
+            bindings.push(venue_id.to_string());
+        }
+        if let Some(artist_id) = filter.artist_id {
+            conditions.push_str(
+                " AND EXISTS (SELECT 1 FROM concert_artists ca WHERE ca.concert_id = c.id AND ca.artist_id = ?)",
+            );
+            bindings.push(artist_id.to_string());
+        }
+        // 공연과 작곡가는 연결 테이블이 없으므로 작곡가 정보/제목에 이름이 있는지로 판단
+        // 한글 이름은 다른 단어의 일부가 아닌 경우만 (예: "리스트"가 "플레이리스트"에 걸리지 않도록),
+        // 영문 이름은 성(english_name 마지막 단어)을 단어 단위로 비교 (예: "Chopin")
+        if let Some(composer_id) = filter.composer_id {
+            conditions.push_str(
+                r" AND EXISTS (SELECT 1 FROM composers cp WHERE cp.id = ?
+                   AND (CONCAT_WS(' ', c.title, c.composer_info) REGEXP CONCAT('(^|[^가-힣])', cp.name)
+                     OR CONCAT_WS(' ', c.title, c.composer_info) LIKE CONCAT('%', cp.full_name, '%')
+                     OR CONCAT_WS(' ', c.title, c.composer_info)
+                        REGEXP CONCAT('\\b', SUBSTRING_INDEX(cp.english_name, ' ', -1), '\\b')))",
+            );
+            bindings.push(composer_id.to_string());
+        }
 
-        if let Some(g) = genre {
-            sql_query = sql_query.bind(g);
+        for (column, value) in [
+            ("c.is_visit", filter.is_visit),
+            ("c.is_festival", filter.is_festival),
+            ("c.is_child", filter.is_child),
+            ("c.is_open_run", filter.is_open_run),
+        ] {
+            if let Some(value) = value {
+                conditions.push_str(&format!(" AND {} = ?", column));
+                bindings.push((value as i32).to_string());
+            }
         }
-        if let Some(a) = area {
-            sql_query = sql_query.bind(a);
+
+        if let Some(age) = filter.age {
+            conditions.push_str(&format!(" AND {} <= ?", MIN_AGE_SQL));
+            bindings.push(age.to_string());
         }
-        if let Some(v) = is_visit {
-            sql_query = sql_query.bind(v);
+
+        // 가격대가 겹치는 공연 (가격 정보가 없는 공연 제외)
+        if let Some(min_price) = filter.min_price {
+            conditions.push_str(" AND c.max_price >= ?");
+            bindings.push(min_price.to_string());
         }
-        if let Some(f) = is_festival {
-            sql_query = sql_query.bind(f);
+        if let Some(max_price) = filter.max_price {
+            conditions.push_str(" AND c.min_price <= ?");
+            bindings.push(max_price.to_string());
         }
 
-        sql_query.fetch_all(pool).await
+        (conditions, bindings)
     }
 
-    /// Full-text search across concerts with pagination
-    pub async fn search_concerts_by_text(
+    /// 검색 조건에 맞는 공연을 항목 값별로 집계 (값이 없는 공연 제외)
+    /// 항목 자신의 필터는 적용하지 않음 (예: area 집계는 area 필터를 무시)
+    pub async fn count_facet(
         pool: &DbPool,
-        search_query: Option<&str>,
-        genre: Option<&str>,
-        area: Option<&str>,
-        status: Option<&str>,
-        offset: i64,
-        limit: i64,
-    ) -> Result<Vec<ConcertListItem>, Error> {
-        // Prepare search pattern early to avoid lifetime issues
-        let search_pattern = search_query
-            .filter(|q| !q.trim().is_empty())
-            .map(|q| format!("%{}%", q));
+        search: Option<&SearchText>,
+        filter: &ConcertSearchFilter,
+        facet: ConcertFacet,
+    ) -> Result<Vec<FacetCount>, Error> {
+        let mut filter = filter.clone();
+        let (value, label, join, order) = match facet {
+            ConcertFacet::Area => {
+                filter.area = None;
+                ("c.area", "NULL", "", "count DESC, value")
+            }
+            ConcertFacet::Genre => {
+                filter.genre = None;
+                ("c.genre", "NULL", "", "count DESC, value")
+            }
+            ConcertFacet::Status => {
+                filter.status = None;
+                ("c.status", "NULL", "", "count DESC, value")
+            }
+            ConcertFacet::Month => ("DATE_FORMAT(c.start_date, '%Y-%m')", "NULL", "", "value"),
+            ConcertFacet::Venue => {
+                filter.venue_id = None;
+                (
+                    "CAST(c.venue_id AS CHAR)",
+                    "MAX(v.name)",
+                    "JOIN venues v ON c.venue_id = v.id",
+                    "count DESC, label",
+                )
+            }
+            ConcertFacet::IsVisit => {
+                filter.is_visit = None;
+                (
+                    "IF(c.is_visit, 'true', 'false')",
+                    "NULL",
+                    "",
+                    "count DESC, value DESC",
+                )
+            }
+            ConcertFacet::IsFestival => {
+                filter.is_festival = None;
+                (
+                    "IF(c.is_festival, 'true', 'false')",
+                    "NULL",
+                    "",
+                    "count DESC, value DESC",
+                )
+            }
+        };
 
-        let mut query = String::from(
-            "SELECT c.id, c.title, c.venue_id,
-             DATE_FORMAT(c.start_date, '%Y-%m-%d') as start_date,
-             DATE_FORMAT(c.end_date, '%Y-%m-%d') as end_date,
-             c.concert_time,
-             c.poster_url, c.status, c.rating, c.rating_count,
-             c.genre, c.area, c.facility_name, c.is_open_run, c.is_visit, c.is_festival,
-             cbr.ranking as boxoffice_ranking
+        let mut query = format!(
+            "SELECT {value} as value, {label} as label, COUNT(*) as count
              FROM concerts c
-             LEFT JOIN concert_boxoffice_rankings cbr ON c.id = cbr.concert_id
-             WHERE 1=1",
+             {join}
+             WHERE {value} IS NOT NULL AND {value} != ''"
         );
+        let mut bindings: Vec<String> = Vec::new();
 
-        // Text search across multiple fields
-        if search_pattern.is_some() {
-            query.push_str(
-                " AND (c.title LIKE ? OR c.composer_info LIKE ? OR c.cast LIKE ? OR c.facility_name LIKE ?)"
-            );
+        if let Some(search) = search {
+            let (condition, values) = search.condition(&CONCERT_SEARCH_COLUMNS);
+            query.push_str(&format!(" AND {}", condition));
+            bindings.extend(values);
         }
 
-        // Additional filters
-        if genre.is_some() {
-            query.push_str(" AND c.genre = ?");
+        let (conditions, values) = Self::filter_conditions(&filter);
+        query.push_str(&conditions);
+        bindings.extend(values);
+
+        query.push_str(&format!(" GROUP BY value ORDER BY {}", order));
+
+        let mut sql_query = sqlx::query_as::<_, FacetCount>(&query);
+        for value in bindings {
+            sql_query = sql_query.bind(value);
         }
-        if area.is_some() {
-            query.push_str(" AND c.area = ?");
+
+        sql_query.fetch_all(pool).await
+    }
+
+    /// CONCERT_SORTS 정렬 (ORDER BY 앞부분, 쉼표 포함)
+    fn sort_clause(sort: Option<&str>) -> &'static str {
+        match sort {
+            Some("date") => "c.start_date ASC,",
+            Some("date_desc") => "c.start_date DESC,",
+            Some("rating") => "c.rating DESC, c.rating_count DESC,",
+            Some("popularity") => "cbr.ranking IS NULL, cbr.ranking ASC, c.rating_count DESC,",
+            Some("price") => "c.min_price IS NULL, c.min_price ASC,",
+            Some("price_desc") => "c.max_price IS NULL, c.max_price DESC,",
+            _ => "",
         }
-        if status.is_some() {
-            query.push_str(" AND c.status = ?");
+    }
+
+    // ============================================
+    // 공연 상태 갱신 (KOPIS 대조, 날짜 기준)
+    // ============================================
+
+    /// 진행 중/예정인 KOPIS 공연 (id, kopis_id, title)
+    /// 기간이 [from, to]와 겹치고 장르가 genres 중 하나인 공연만
+    pub async fn find_active_kopis_concerts(
+        pool: &DbPool,
+        genres: &[&str],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<(i32, String, String)>, Error> {
+        if genres.is_empty() {
+            return Ok(Vec::new());
         }
 
-        // Sort by proximity to today (upcoming first, then past)
-        query.push_str(
-            " ORDER BY
-               CASE WHEN c.start_date >= DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')) THEN 0 ELSE 1 END,
-               ABS(DATEDIFF(c.start_date, DATE(CONVERT_TZ(NOW(), '+00:00', '+09:00')))) ASC
-             LIMIT ? OFFSET ?",
+        let placeholders = vec!["?"; genres.len()].join(", ");
+        let query = format!(
+            "SELECT id, kopis_id, title FROM concerts
+             WHERE data_source = 'KOPIS' AND kopis_id IS NOT NULL
+             AND status IN ('upcoming', 'ongoing')
+             AND COALESCE(end_date, start_date) >= ?
+             AND start_date <= ?
+             AND genre IN ({})",
+            placeholders
         );
 
-        let mut sql_query = sqlx::query_as::<_, ConcertListItem>(&query);
-
-        // Bind search query with wildcards
-        if let Some(ref pattern) = search_pattern {
-            sql_query = sql_query
-                .bind(pattern) // title
-                .bind(pattern) // composer_info
-                .bind(pattern) // cast
-                .bind(pattern); // facility_name
+        let mut sql_query = sqlx::query_as::<_, (i32, String, String)>(&query)
+            .bind(from)
+            .bind(to);
+        for genre in genres {
+            sql_query = sql_query.bind(*genre);
         }
 
-        // Bind filter parameters
-        if let Some(g) = genre {
-            sql_query = sql_query.bind(g);
-        }
-        if let Some(a) = area {
-            sql_query = sql_query.bind(a);
-        }
-        if let Some(s) = status {
-            sql_query = sql_query.bind(s);
-        }
+        sql_query.fetch_all(pool).await
+    }
 
-        // Bind pagination
-        sql_query = sql_query.bind(limit).bind(offset);
+    pub async fn update_status(pool: &DbPool, id: i32, status: &str) -> Result<u64, Error> {
+        let result = sqlx::query("UPDATE concerts SET status = ? WHERE id = ?")
+            .bind(status)
+            .bind(id)
+            .execute(pool)
+            .await?;
 
-        sql_query.fetch_all(pool).await
+        Ok(result.rows_affected())
+    }
+
+    /// 공연 기간 기준 상태 갱신 → (공연중 전환 수, 완료 전환 수)
+    pub async fn update_statuses_by_date(
+        pool: &DbPool,
+        today: NaiveDate,
+    ) -> Result<(u64, u64), Error> {
+        let completed = sqlx::query(
+            "UPDATE concerts SET status = 'completed'
+             WHERE status IN ('upcoming', 'ongoing')
+             AND COALESCE(end_date, start_date) < ?",
+        )
+        .bind(today)
+        .execute(pool)
+        .await?;
+
+        let ongoing = sqlx::query(
+            "UPDATE concerts SET status = 'ongoing'
+             WHERE status = 'upcoming'
+             AND start_date <= ?
+             AND COALESCE(end_date, start_date) >= ?",
+        )
+        .bind(today)
+        .bind(today)
+        .execute(pool)
+        .await?;
+
+        Ok((ongoing.rows_affected(), completed.rows_affected()))
     }
 
     // ============================================
@@ -831,31 +1139,36 @@ impl ConcertRepository {
     // Concert Artists 저장 로직
     // ============================================
 
-    /// concert_artists 테이블에 아티스트-공연 관계 일괄 저장
+    /// concert_artists 테이블에 출연진 일괄 저장
     /// 기존 데이터는 삭제하고 새로 삽입
     pub async fn upsert_concert_artists(
         pool: &DbPool,
         concert_id: i32,
-        artist_ids: Vec<i32>,
+        lineup: &[ConcertLineupEntry],
     ) -> Result<(), Error> {
+        let mut tx = pool.begin().await?;
+
         // 1. 기존 데이터 삭제
         sqlx::query("DELETE FROM concert_artists WHERE concert_id = ?")
             .bind(concert_id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
 
         // 2. 새 데이터 삽입
-        for artist_id in artist_ids {
+        for entry in lineup {
             sqlx::query(
-                "INSERT INTO concert_artists (concert_id, artist_id, role)
-                 VALUES (?, ?, NULL)"
+                "INSERT IGNORE INTO concert_artists (concert_id, artist_id, role, ensemble_id)
+                 VALUES (?, ?, ?, ?)"
             )
             .bind(concert_id)
-            .bind(artist_id)
-            .execute(pool)
+            .bind(entry.artist_id)
+            .bind(&entry.role)
+            .bind(entry.ensemble_id)
+            .execute(&mut *tx)
             .await?;
         }
 
+        tx.commit().await?;
         Ok(())
     }
 
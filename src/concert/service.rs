@@ -1,7 +1,11 @@
 use crate::db::DbPool;
-use super::model::{Concert, CreateConcert, UpdateConcert, ConcertWithArtists, ConcertWithDetails, ConcertListItem, ConcertTicketVendor};
+use super::model::{Concert, ConcertArtist, ConcertLineupEntry, CreateConcert, UpdateConcert, ConcertWithArtists, ConcertWithDetails, ConcertListItem, ConcertSearchFilter, ConcertTicketVendor, ConcertFacet, ConcertFacets};
 use super::repository::ConcertRepository;
+use crate::artist::repository::ArtistRepository;
+use crate::clock;
+use crate::search::SearchText;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 pub struct ConcertService;
 
@@ -54,6 +58,46 @@ impl ConcertService {
             .map_err(|e| e.to_string())
     }
 
+    /// 출연진 아티스트의 유형 (없는 아티스트는 빠짐, 출연진 검증용)
+    pub async fn get_lineup_artist_types(
+        pool: &DbPool,
+        lineup: &[ConcertLineupEntry],
+    ) -> Result<HashMap<i32, String>, String> {
+        let mut artist_types = HashMap::new();
+        for entry in lineup {
+            if let Some(artist) = ArtistRepository::find_by_id(pool, entry.artist_id)
+                .await
+                .map_err(|e| e.to_string())?
+            {
+                artist_types.insert(artist.id, artist.artist_type);
+            }
+        }
+        Ok(artist_types)
+    }
+
+    /// 출연진 교체 (공연이 없으면 None)
+    pub async fn set_concert_artists(
+        pool: &DbPool,
+        concert_id: i32,
+        lineup: Vec<ConcertLineupEntry>,
+    ) -> Result<Option<Vec<ConcertArtist>>, String> {
+        if ConcertRepository::find_by_id(pool, concert_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        ConcertRepository::upsert_concert_artists(pool, concert_id, &lineup)
+            .await
+            .map_err(|e| e.to_string())?;
+        ConcertRepository::find_artists_by_concert(pool, concert_id)
+            .await
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
     pub async fn submit_rating(pool: &DbPool, user_id: i32, concert_id: i32, rating: f32) -> Result<(), String> {
         if rating < 0.0 || rating > 5.0 {
             return Err("Rating must be between 0.0 and 5.0".to_string());
@@ -87,13 +131,6 @@ impl ConcertService {
             .map_err(|e| e.to_string())
     }
 
-    pub async fn get_featured_concerts(pool: &DbPool, area_code: Option<String>, limit: Option<i32>) -> Result<Vec<ConcertWithDetails>, String> {
-        let limit_val = limit.unwrap_or(3);
-        ConcertRepository::find_featured_concerts(pool, area_code.as_deref(), limit_val)
-            .await
-            .map_err(|e| e.to_string())
-    }
-
     pub async fn get_upcoming_concerts(pool: &DbPool, sort_by: Option<String>, limit: Option<i32>) -> Result<Vec<ConcertListItem>, String> {
         let sort = sort_by.as_deref().unwrap_or("date");
         let limit_val = limit.unwrap_or(20);
@@ -102,42 +139,25 @@ impl ConcertService {
             .map_err(|e| e.to_string())
     }
 
-    pub async fn search_concerts(
-        pool: &DbPool,
-        genre: Option<String>,
-        area: Option<String>,
-        is_visit: Option<bool>,
-        is_festival: Option<bool>,
-    ) -> Result<Vec<ConcertListItem>, String> {
-        ConcertRepository::search_concerts(
-            pool,
-            genre.as_deref(),
-            area.as_deref(),
-            is_visit,
-            is_festival,
-        )
-        .await
-        .map_err(|e| e.to_string())
-    }
-
     pub async fn search_concerts_by_text(
         pool: &DbPool,
         search_query: Option<String>,
-        genre: Option<String>,
-        area: Option<String>,
-        status: Option<String>,
+        filter: ConcertSearchFilter,
+        sort: Option<String>,
         offset: Option<i64>,
         limit: Option<i64>,
     ) -> Result<Vec<ConcertListItem>, String> {
         let offset_val = offset.unwrap_or(0);
         let limit_val = limit.unwrap_or(20);
+        let search = SearchText::build(pool, search_query.as_deref())
+            .await
+            .map_err(|e| e.to_string())?;
 
         ConcertRepository::search_concerts_by_text(
             pool,
-            search_query.as_deref(),
-            genre.as_deref(),
-            area.as_deref(),
-            status.as_deref(),
+            search.as_ref(),
+            &filter,
+            sort.as_deref(),
             offset_val,
             limit_val,
         )
@@ -145,6 +165,35 @@ impl ConcertService {
         .map_err(|e| e.to_string())
     }
 
+    /// 검색 조건에 맞는 공연의 항목별 값과 공연 수 (항목당 쿼리 1번)
+    pub async fn get_concert_facets(
+        pool: &DbPool,
+        search_query: Option<String>,
+        filter: ConcertSearchFilter,
+    ) -> Result<ConcertFacets, String> {
+        let search = SearchText::build(pool, search_query.as_deref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut facets = ConcertFacets::default();
+        for facet in ConcertFacet::ALL {
+            let counts = ConcertRepository::count_facet(pool, search.as_ref(), &filter, facet)
+                .await
+                .map_err(|e| e.to_string())?;
+            match facet {
+                ConcertFacet::Area => facets.area = counts,
+                ConcertFacet::Genre => facets.genre = counts,
+                ConcertFacet::Status => facets.status = counts,
+                ConcertFacet::Month => facets.month = counts,
+                ConcertFacet::Venue => facets.venue = counts,
+                ConcertFacet::IsVisit => facets.is_visit = counts,
+                ConcertFacet::IsFestival => facets.is_festival = counts,
+            }
+        }
+
+        Ok(facets)
+    }
+
     pub async fn get_ticket_vendors(pool: &DbPool, concert_id: i32) -> Result<Vec<ConcertTicketVendor>, String> {
         ConcertRepository::find_ticket_vendors_by_concert(pool, concert_id)
             .await
@@ -156,4 +205,12 @@ impl ConcertService {
             .await
             .map_err(|e| e.to_string())
     }
+
+    /// 오늘(KST) 기준으로 공연 상태 갱신 → (공연중 전환 수, 완료 전환 수)
+    pub async fn update_statuses_by_date(pool: &DbPool) -> Result<(u64, u64), String> {
+        let today = clock::today();
+        ConcertRepository::update_statuses_by_date(pool, today)
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
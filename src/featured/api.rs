@@ -0,0 +1,152 @@
+use super::model::{CreateFeaturedSlot, FeaturedConcert, FeaturedSlot, UpdateFeaturedSlot};
+use super::service::FeaturedService;
+use super::settings::{FeaturedPolicy, FeaturedPolicyResponse, UpdateFeaturedPolicy};
+use crate::auth::{AdminUser, ModeratorUser};
+use crate::db::DbPool;
+use crate::kopis::codes;
+use crate::logger::Logger;
+use rocket::{http::Status, serde::json::Json, State};
+
+/// 홈 화면 주목 공연 (고정 + 예매 순위 + 높은 평점, 각 항목에 reason 포함)
+#[get("/concerts/featured?<area_code>&<limit>")]
+pub async fn get_featured_concerts(
+    pool: &State<DbPool>,
+    area_code: Option<String>,
+    limit: Option<i32>,
+) -> Result<Json<Vec<FeaturedConcert>>, Status> {
+    if let Some(code) = &area_code {
+        if codes::area_name(code).is_none() {
+            Logger::warn("API", &format!("Unknown area code: {}", code));
+            return Err(Status::BadRequest);
+        }
+    }
+
+    match FeaturedService::get_featured_concerts(pool, area_code, limit).await {
+        Ok(concerts) => Ok(Json(concerts)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get featured concerts: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 고정 슬롯 목록 (active=true면 오늘 노출 중인 슬롯만)
+/// Moderator 권한 필요
+#[get("/featured/slots?<area_code>&<active>")]
+pub async fn get_featured_slots(
+    pool: &State<DbPool>,
+    area_code: Option<String>,
+    active: Option<bool>,
+    _moderator: ModeratorUser,
+) -> Result<Json<Vec<FeaturedSlot>>, Status> {
+    match FeaturedService::get_slots(pool, area_code, active).await {
+        Ok(slots) => Ok(Json(slots)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get featured slots: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 공연을 지역 홈 화면에 기간 지정으로 고정
+/// Moderator 권한 필요
+#[post("/featured/slots", data = "<slot>")]
+pub async fn create_featured_slot(
+    pool: &State<DbPool>,
+    slot: Json<CreateFeaturedSlot>,
+    moderator: ModeratorUser,
+) -> Result<Json<i32>, Status> {
+    let slot = slot.into_inner();
+    if let Err(e) = slot.validate() {
+        Logger::warn("API", &format!("Invalid featured slot: {}", e));
+        return Err(Status::BadRequest);
+    }
+
+    match FeaturedService::create_slot(pool, slot, moderator.user.id).await {
+        Ok(Some(id)) => Ok(Json(id)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to create featured slot: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// Moderator 권한 필요
+#[put("/featured/slots/<id>", data = "<slot>")]
+pub async fn update_featured_slot(
+    pool: &State<DbPool>,
+    id: i32,
+    slot: Json<UpdateFeaturedSlot>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    let current = match FeaturedService::get_slot(pool, id).await {
+        Ok(Some(current)) => current,
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get featured slot {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+
+    let slot = slot.into_inner();
+    if let Err(e) = slot.validate(&current) {
+        Logger::warn("API", &format!("Invalid featured slot {}: {}", id, e));
+        return Err(Status::BadRequest);
+    }
+
+    match FeaturedService::update_slot(pool, current, slot).await {
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update featured slot {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// Moderator 권한 필요
+#[delete("/featured/slots/<id>")]
+pub async fn delete_featured_slot(
+    pool: &State<DbPool>,
+    id: i32,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match FeaturedService::delete_slot(pool, id).await {
+        Ok(0) => Err(Status::NotFound),
+        Ok(rows) => Ok(Json(rows)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to delete featured slot {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 주목 공연 구성 정책 조회 (출처 순서, 개수, 평점 기준)
+/// Admin 권한 필요
+#[get("/featured/policy")]
+pub async fn get_featured_policy(
+    pool: &State<DbPool>,
+    _admin: AdminUser,
+) -> Json<FeaturedPolicyResponse> {
+    Json(FeaturedPolicy::load(pool).await.response())
+}
+
+/// 주목 공연 구성 정책 변경 (다음 요청부터 적용)
+/// Admin 권한 필요
+#[put("/featured/policy", data = "<policy>")]
+pub async fn update_featured_policy(
+    pool: &State<DbPool>,
+    policy: Json<UpdateFeaturedPolicy>,
+    _admin: AdminUser,
+) -> Result<Json<FeaturedPolicyResponse>, Status> {
+    match FeaturedPolicy::save(pool, policy.into_inner()).await {
+        Ok(updated) => {
+            Logger::info("API", "Featured policy updated by admin");
+            Ok(Json(updated.response()))
+        }
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update featured policy: {}", e));
+            Err(Status::BadRequest)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod api;
+pub mod model;
+pub mod repository;
+pub mod service;
+pub mod settings;
+
+pub use api::*;
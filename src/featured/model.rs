@@ -0,0 +1,82 @@
+use crate::concert::model::ConcertWithDetails;
+use crate::kopis::codes;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedSlot {
+    pub id: i32,
+    pub concert_id: i32,
+    pub concert_title: String,
+    pub area_code: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub display_order: i32,
+    pub note: Option<String>,
+    pub created_by: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFeaturedSlot {
+    pub concert_id: i32,
+    /// 지정하지 않으면 "00"(전국)
+    pub area_code: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub display_order: Option<i32>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFeaturedSlot {
+    pub area_code: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub display_order: Option<i32>,
+    pub note: Option<String>,
+}
+
+/// 홈 화면 주목 공연 (선정 이유 포함)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedConcert {
+    #[serde(flatten)]
+    pub concert: ConcertWithDetails,
+    /// 선정 이유 (pinned, boxoffice, rating)
+    pub reason: String,
+}
+
+impl CreateFeaturedSlot {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_slot(self.area_code.as_deref(), self.start_date, self.end_date)
+    }
+}
+
+impl UpdateFeaturedSlot {
+    /// 기존 슬롯에 변경값을 합친 결과 검증
+    pub fn validate(&self, current: &FeaturedSlot) -> Result<(), String> {
+        validate_slot(
+            self.area_code.as_deref(),
+            self.start_date.unwrap_or(current.start_date),
+            self.end_date.unwrap_or(current.end_date),
+        )
+    }
+}
+
+fn validate_slot(area_code: Option<&str>, start: NaiveDate, end: NaiveDate) -> Result<(), String> {
+    if let Some(code) = area_code {
+        if codes::area_name(code).is_none() {
+            return Err(format!("Unknown area code: {}", code));
+        }
+    }
+
+    if start > end {
+        return Err(format!("Start date {} is after end date {}", start, end));
+    }
+
+    Ok(())
+}
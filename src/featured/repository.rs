@@ -0,0 +1,174 @@
+use super::model::{CreateFeaturedSlot, FeaturedSlot, UpdateFeaturedSlot};
+use crate::db::DbPool;
+use crate::kopis::codes::NATIONWIDE_AREA_CODE;
+use chrono::NaiveDate;
+use sqlx::Error;
+
+const SLOT_COLUMNS: &str = "fs.id, fs.concert_id, c.title as concert_title, fs.area_code,
+     fs.start_date, fs.end_date, fs.display_order, fs.note, fs.created_by";
+
+pub struct FeaturedRepository;
+
+impl FeaturedRepository {
+    // ============================================
+    // 고정 슬롯 (featured_slots)
+    // ============================================
+
+    /// 고정 슬롯 목록 (지역/노출일 필터, 최근 시작일 순)
+    pub async fn find_slots(
+        pool: &DbPool,
+        area_code: Option<&str>,
+        active_on: Option<NaiveDate>,
+    ) -> Result<Vec<FeaturedSlot>, Error> {
+        let mut query = format!(
+            "SELECT {}
+             FROM featured_slots fs
+             JOIN concerts c ON fs.concert_id = c.id
+             WHERE 1=1",
+            SLOT_COLUMNS
+        );
+
+        if area_code.is_some() {
+            query.push_str(" AND fs.area_code = ?");
+        }
+        if active_on.is_some() {
+            query.push_str(" AND fs.start_date <= ? AND fs.end_date >= ?");
+        }
+        query.push_str(" ORDER BY fs.start_date DESC, fs.display_order ASC, fs.id ASC");
+
+        let mut q = sqlx::query_as::<_, FeaturedSlot>(&query);
+        if let Some(code) = area_code {
+            q = q.bind(code);
+        }
+        if let Some(date) = active_on {
+            q = q.bind(date).bind(date);
+        }
+
+        q.fetch_all(pool).await
+    }
+
+    pub async fn find_slot_by_id(pool: &DbPool, id: i32) -> Result<Option<FeaturedSlot>, Error> {
+        sqlx::query_as::<_, FeaturedSlot>(&format!(
+            "SELECT {}
+             FROM featured_slots fs
+             JOIN concerts c ON fs.concert_id = c.id
+             WHERE fs.id = ?",
+            SLOT_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create_slot(
+        pool: &DbPool,
+        slot: &CreateFeaturedSlot,
+        created_by: i32,
+    ) -> Result<i32, Error> {
+        let result = sqlx::query(
+            "INSERT INTO featured_slots (concert_id, area_code, start_date, end_date, display_order, note, created_by)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(slot.concert_id)
+        .bind(slot.area_code.as_deref().unwrap_or(NATIONWIDE_AREA_CODE))
+        .bind(slot.start_date)
+        .bind(slot.end_date)
+        .bind(slot.display_order.unwrap_or(0))
+        .bind(&slot.note)
+        .bind(created_by)
+        .execute(pool)
+        .await?;
+
+        Ok(result.last_insert_id() as i32)
+    }
+
+    pub async fn update_slot(
+        pool: &DbPool,
+        current: FeaturedSlot,
+        slot: UpdateFeaturedSlot,
+    ) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "UPDATE featured_slots SET area_code = ?, start_date = ?, end_date = ?, display_order = ?, note = ?
+             WHERE id = ?",
+        )
+        .bind(slot.area_code.unwrap_or(current.area_code))
+        .bind(slot.start_date.unwrap_or(current.start_date))
+        .bind(slot.end_date.unwrap_or(current.end_date))
+        .bind(slot.display_order.unwrap_or(current.display_order))
+        .bind(slot.note.or(current.note))
+        .bind(current.id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_slot(pool: &DbPool, id: i32) -> Result<u64, Error> {
+        let result = sqlx::query("DELETE FROM featured_slots WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    // ============================================
+    // 주목 공연 후보
+    // ============================================
+
+    /// 오늘 노출 중인 고정 공연 (해당 지역 슬롯 먼저, 전국 슬롯은 모든 지역에 포함)
+    pub async fn find_pinned_concert_ids(
+        pool: &DbPool,
+        area_code: &str,
+        today: NaiveDate,
+    ) -> Result<Vec<i32>, Error> {
+        sqlx::query_scalar::<_, i32>(
+            "SELECT fs.concert_id
+             FROM featured_slots fs
+             JOIN concerts c ON fs.concert_id = c.id
+             WHERE fs.area_code IN (?, ?)
+               AND fs.start_date <= ? AND fs.end_date >= ?
+               AND c.status IN ('upcoming', 'ongoing')
+             ORDER BY fs.area_code = ? DESC, fs.display_order ASC, fs.id ASC",
+        )
+        .bind(area_code)
+        .bind(NATIONWIDE_AREA_CODE)
+        .bind(today)
+        .bind(today)
+        .bind(area_code)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 평점이 높은 예정 공연 (provinces가 비어 있으면 전국)
+    pub async fn find_top_rated_concert_ids(
+        pool: &DbPool,
+        provinces: &[&str],
+        min_rating: f64,
+        min_rating_count: i32,
+        limit: i32,
+    ) -> Result<Vec<i32>, Error> {
+        let mut query = String::from(
+            "SELECT c.id
+             FROM concerts c
+             WHERE c.status = 'upcoming'
+               AND c.rating >= ?
+               AND c.rating_count >= ?",
+        );
+
+        if !provinces.is_empty() {
+            let placeholders = vec!["?"; provinces.len()].join(", ");
+            query.push_str(&format!(" AND c.area IN ({})", placeholders));
+        }
+        query.push_str(" ORDER BY c.rating DESC, c.rating_count DESC, c.start_date ASC LIMIT ?");
+
+        let mut q = sqlx::query_scalar::<_, i32>(&query)
+            .bind(min_rating)
+            .bind(min_rating_count);
+        for province in provinces {
+            q = q.bind(*province);
+        }
+
+        q.bind(limit).fetch_all(pool).await
+    }
+}
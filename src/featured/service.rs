@@ -0,0 +1,137 @@
+use super::model::{CreateFeaturedSlot, FeaturedConcert, FeaturedSlot, UpdateFeaturedSlot};
+use super::repository::FeaturedRepository;
+use super::settings::{FeaturedPolicy, MAX_LIMIT, SOURCE_BOXOFFICE, SOURCE_PINNED, SOURCE_RATING};
+use crate::boxoffice::BoxofficeRepository;
+use crate::clock::today;
+use crate::concert::repository::ConcertRepository;
+use crate::db::DbPool;
+use crate::kopis::codes::{province_names, NATIONWIDE_AREA_CODE};
+use crate::logger::Logger;
+use std::collections::HashSet;
+
+pub struct FeaturedService;
+
+impl FeaturedService {
+    /// 홈 화면 주목 공연
+    /// 정책(FeaturedPolicy)의 출처 순서대로 고정 → 예매 순위 → 평점 후보를 채우고 중복 공연은 제외
+    pub async fn get_featured_concerts(
+        pool: &DbPool,
+        area_code: Option<String>,
+        limit: Option<i32>,
+    ) -> Result<Vec<FeaturedConcert>, String> {
+        let policy = FeaturedPolicy::load(pool).await;
+        let area_code = area_code.as_deref().unwrap_or(NATIONWIDE_AREA_CODE);
+        let limit = limit.unwrap_or(policy.limit).clamp(1, MAX_LIMIT) as usize;
+
+        let mut seen = HashSet::new();
+        let mut result = Vec::new();
+
+        for source in &policy.sources {
+            if result.len() >= limit {
+                break;
+            }
+
+            let candidates = match source.as_str() {
+                SOURCE_PINNED => {
+                    FeaturedRepository::find_pinned_concert_ids(pool, area_code, today()).await
+                }
+                SOURCE_BOXOFFICE => {
+                    BoxofficeRepository::find_top_concert_ids(pool, area_code, policy.boxoffice_top)
+                        .await
+                }
+                SOURCE_RATING => {
+                    FeaturedRepository::find_top_rated_concert_ids(
+                        pool,
+                        &province_names(area_code),
+                        policy.min_rating,
+                        policy.min_rating_count,
+                        policy.rating_limit,
+                    )
+                    .await
+                }
+                _ => continue,
+            }
+            .map_err(|e| e.to_string())?;
+
+            for concert_id in candidates {
+                if result.len() >= limit {
+                    break;
+                }
+                if !seen.insert(concert_id) {
+                    continue;
+                }
+
+                if let Some(concert) = ConcertRepository::find_by_id_with_details(pool, concert_id)
+                    .await
+                    .map_err(|e| e.to_string())?
+                {
+                    result.push(FeaturedConcert {
+                        concert,
+                        reason: source.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn get_slots(
+        pool: &DbPool,
+        area_code: Option<String>,
+        active: Option<bool>,
+    ) -> Result<Vec<FeaturedSlot>, String> {
+        let active_on = active.unwrap_or(false).then(today);
+        FeaturedRepository::find_slots(pool, area_code.as_deref(), active_on)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn get_slot(pool: &DbPool, id: i32) -> Result<Option<FeaturedSlot>, String> {
+        FeaturedRepository::find_slot_by_id(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 고정 슬롯 등록 (공연이 없으면 None)
+    pub async fn create_slot(
+        pool: &DbPool,
+        slot: CreateFeaturedSlot,
+        created_by: i32,
+    ) -> Result<Option<i32>, String> {
+        let concert = ConcertRepository::find_by_id(pool, slot.concert_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        if concert.is_none() {
+            return Ok(None);
+        }
+
+        let id = FeaturedRepository::create_slot(pool, &slot, created_by)
+            .await
+            .map_err(|e| e.to_string())?;
+        Logger::info(
+            "FEATURED",
+            &format!(
+                "Pinned concert {} ({} ~ {}) as slot {}",
+                slot.concert_id, slot.start_date, slot.end_date, id
+            ),
+        );
+        Ok(Some(id))
+    }
+
+    pub async fn update_slot(
+        pool: &DbPool,
+        current: FeaturedSlot,
+        slot: UpdateFeaturedSlot,
+    ) -> Result<u64, String> {
+        FeaturedRepository::update_slot(pool, current, slot)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    pub async fn delete_slot(pool: &DbPool, id: i32) -> Result<u64, String> {
+        FeaturedRepository::delete_slot(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
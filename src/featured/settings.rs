@@ -0,0 +1,184 @@
+use crate::settings::{self, parse_in_range, split_list, validate_range, KeyValueSettings};
+use serde::{Deserialize, Serialize};
+use sqlx::MySqlPool;
+
+// 설정 키 (featured_settings.setting_key)
+const KEY_SOURCES: &str = "sources";
+const KEY_LIMIT: &str = "limit";
+const KEY_BOXOFFICE_TOP: &str = "boxoffice_top";
+const KEY_RATING_LIMIT: &str = "rating_limit";
+const KEY_MIN_RATING: &str = "min_rating";
+const KEY_MIN_RATING_COUNT: &str = "min_rating_count";
+
+pub const SOURCE_PINNED: &str = "pinned";
+pub const SOURCE_BOXOFFICE: &str = "boxoffice";
+pub const SOURCE_RATING: &str = "rating";
+
+/// 주목 공연 후보 출처 (응답의 `reason` 값과 동일)
+pub const FEATURED_SOURCES: &[(&str, &str)] = &[
+    (SOURCE_PINNED, "모더레이터 고정"),
+    (SOURCE_BOXOFFICE, "예매 순위"),
+    (SOURCE_RATING, "높은 평점"),
+];
+
+pub const MAX_LIMIT: i32 = 20;
+const MAX_BOXOFFICE_TOP: i32 = 10;
+const MAX_RATING: f64 = 5.0;
+
+/// 주목 공연 구성 정책 (featured_settings, FEATURED_* 환경변수)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedPolicy {
+    /// 후보 출처 순서 (앞에 있는 출처가 먼저 채워짐, 빠진 출처는 사용 안 함)
+    pub sources: Vec<String>,
+    /// 기본 노출 개수
+    pub limit: i32,
+    /// 예매 순위 후보 범위 (월별 TOP N)
+    pub boxoffice_top: i32,
+    /// 평점 후보 최대 개수
+    pub rating_limit: i32,
+    /// 평점 후보 최소 평점
+    pub min_rating: f64,
+    /// 평점 후보 최소 평가 수
+    pub min_rating_count: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateFeaturedPolicy {
+    pub sources: Option<Vec<String>>,
+    pub limit: Option<i32>,
+    pub boxoffice_top: Option<i32>,
+    pub rating_limit: Option<i32>,
+    pub min_rating: Option<f64>,
+    pub min_rating_count: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedSource {
+    pub code: String,
+    pub name: String,
+}
+
+/// 정책 조회/변경 응답 (source_codes: 정렬할 수 있는 출처)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedPolicyResponse {
+    pub policy: FeaturedPolicy,
+    pub source_codes: Vec<FeaturedSource>,
+}
+
+impl Default for FeaturedPolicy {
+    fn default() -> Self {
+        Self {
+            sources: FEATURED_SOURCES
+                .iter()
+                .map(|(code, _)| code.to_string())
+                .collect(),
+            limit: 6,
+            boxoffice_top: 3,
+            rating_limit: 3,
+            min_rating: 4.0,
+            min_rating_count: 3,
+        }
+    }
+}
+
+impl KeyValueSettings for FeaturedPolicy {
+    const TABLE: &'static str = "featured_settings";
+    const LOG_MODULE: &'static str = "FEATURED";
+    const ENV_KEYS: &'static [(&'static str, &'static str)] = &[
+        ("FEATURED_SOURCES", KEY_SOURCES),
+        ("FEATURED_LIMIT", KEY_LIMIT),
+        ("FEATURED_BOXOFFICE_TOP", KEY_BOXOFFICE_TOP),
+        ("FEATURED_RATING_LIMIT", KEY_RATING_LIMIT),
+        ("FEATURED_MIN_RATING", KEY_MIN_RATING),
+        ("FEATURED_MIN_RATING_COUNT", KEY_MIN_RATING_COUNT),
+    ];
+
+    fn apply(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            KEY_SOURCES => self.sources = parse_sources(value)?,
+            KEY_LIMIT => self.limit = parse_in_range(value, 1, MAX_LIMIT)?,
+            KEY_BOXOFFICE_TOP => self.boxoffice_top = parse_in_range(value, 1, MAX_BOXOFFICE_TOP)?,
+            KEY_RATING_LIMIT => self.rating_limit = parse_in_range(value, 1, MAX_LIMIT)?,
+            KEY_MIN_RATING => self.min_rating = parse_in_range(value, 0.0, MAX_RATING)?,
+            KEY_MIN_RATING_COUNT => self.min_rating_count = parse_in_range(value, 0, i32::MAX)?,
+            _ => return Err(format!("Unknown setting key: {}", key)),
+        }
+        Ok(())
+    }
+}
+
+impl FeaturedPolicy {
+    /// 현재 유효한 정책 조회 (요청마다 호출)
+    pub async fn load(pool: &MySqlPool) -> Self {
+        settings::load(pool).await
+    }
+
+    /// 관리자 설정 저장 후 갱신된 정책 반환
+    pub async fn save(pool: &MySqlPool, update: UpdateFeaturedPolicy) -> Result<Self, String> {
+        let mut values: Vec<(&str, String)> = Vec::new();
+
+        if let Some(sources) = update.sources {
+            values.push((KEY_SOURCES, validate_sources(&sources)?));
+        }
+        if let Some(limit) = update.limit {
+            values.push((KEY_LIMIT, validate_range(limit, 1, MAX_LIMIT)?.to_string()));
+        }
+        if let Some(top) = update.boxoffice_top {
+            values.push((KEY_BOXOFFICE_TOP, validate_range(top, 1, MAX_BOXOFFICE_TOP)?.to_string()));
+        }
+        if let Some(limit) = update.rating_limit {
+            values.push((KEY_RATING_LIMIT, validate_range(limit, 1, MAX_LIMIT)?.to_string()));
+        }
+        if let Some(rating) = update.min_rating {
+            values.push((KEY_MIN_RATING, validate_range(rating, 0.0, MAX_RATING)?.to_string()));
+        }
+        if let Some(count) = update.min_rating_count {
+            values.push((KEY_MIN_RATING_COUNT, validate_range(count, 0, i32::MAX)?.to_string()));
+        }
+
+        settings::save::<Self>(pool, values).await?;
+        Ok(Self::load(pool).await)
+    }
+
+    pub fn response(self) -> FeaturedPolicyResponse {
+        FeaturedPolicyResponse {
+            policy: self,
+            source_codes: FEATURED_SOURCES
+                .iter()
+                .map(|(code, name)| FeaturedSource {
+                    code: code.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 쉼표로 구분된 출처 목록 파싱 (예: "pinned, boxoffice")
+fn parse_sources(value: &str) -> Result<Vec<String>, String> {
+    let sources = split_list(value);
+    validate_sources(&sources)?;
+    Ok(sources)
+}
+
+/// 출처 목록 검증 후 저장용 문자열로 변환 (중복 불가)
+fn validate_sources(sources: &[String]) -> Result<String, String> {
+    if sources.is_empty() {
+        return Err("At least one source is required".to_string());
+    }
+
+    for (i, source) in sources.iter().enumerate() {
+        if !FEATURED_SOURCES.iter().any(|(code, _)| *code == source.as_str()) {
+            return Err(format!("Unknown featured source: {}", source));
+        }
+        if sources[..i].contains(source) {
+            return Err(format!("Duplicate featured source: {}", source));
+        }
+    }
+
+    Ok(sources.join(","))
+}
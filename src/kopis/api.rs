@@ -1,9 +1,12 @@
-use super::scheduler::VenueSyncScheduler;
+use super::jobs::VENUE_SYNC_JOB;
+use super::settings::{KopisSyncSettings, KopisSyncSettingsResponse, UpdateKopisSyncSettings};
 use crate::auth::AdminUser;
 use crate::db::DbPool;
 use crate::logger::Logger;
+use crate::scheduler::JobScheduler;
 use rocket::{http::Status, serde::json::Json, State};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SyncResponse {
@@ -15,46 +18,67 @@ pub struct SyncResponse {
 }
 
 /// 수동으로 KOPIS 공연장 동기화 트리거
+/// 스케줄러의 venue_sync 작업으로 실행 (실행 중이면 거절, 실행 상태 기록)
 /// Admin 권한 필요
 #[post("/kopis/sync")]
 pub async fn trigger_venue_sync(
-    pool: &State<DbPool>,
+    scheduler: &State<Arc<JobScheduler>>,
     _admin: AdminUser,
-) -> Result<Json<SyncResponse>, Status> {
+) -> Json<SyncResponse> {
     Logger::info("API", "Manual KOPIS venue sync triggered by admin");
 
-    match VenueSyncScheduler::trigger_sync(pool).await {
-        Ok(result) => {
-            let response = SyncResponse {
+    match scheduler.run_job(VENUE_SYNC_JOB).await {
+        Ok(output) => {
+            Logger::success("API", &format!("Manual sync completed: {}", output.message));
+
+            let counts = output.counts.unwrap_or_default();
+            Json(SyncResponse {
                 success: true,
                 message: "Sync completed successfully".to_string(),
-                added: result.added,
-                updated: result.updated,
-                errors: result.errors,
-            };
-
-            Logger::success(
-                "API",
-                &format!(
-                    "Manual sync completed: {} added, {} updated, {} errors",
-                    result.added, result.updated, result.errors
-                ),
-            );
-
-            Ok(Json(response))
+                added: counts.added,
+                updated: counts.updated,
+                errors: counts.errors,
+            })
         }
         Err(e) => {
             Logger::error("API", &format!("Manual sync failed: {}", e));
-
-            let response = SyncResponse {
+            Json(SyncResponse {
                 success: false,
                 message: format!("Sync failed: {}", e),
                 added: 0,
                 updated: 0,
                 errors: 0,
-            };
+            })
+        }
+    }
+}
+
+/// KOPIS 동기화 범위 설정 조회 (장르/지역/조회 기간)
+/// Admin 권한 필요
+#[get("/kopis/settings")]
+pub async fn get_sync_settings(
+    pool: &State<DbPool>,
+    _admin: AdminUser,
+) -> Json<KopisSyncSettingsResponse> {
+    Json(KopisSyncSettings::load(pool).await.response())
+}
 
-            Ok(Json(response))
+/// KOPIS 동기화 범위 설정 변경 (다음 동기화부터 적용)
+/// Admin 권한 필요
+#[put("/kopis/settings", data = "<settings>")]
+pub async fn update_sync_settings(
+    pool: &State<DbPool>,
+    settings: Json<UpdateKopisSyncSettings>,
+    _admin: AdminUser,
+) -> Result<Json<KopisSyncSettingsResponse>, Status> {
+    match KopisSyncSettings::save(pool, settings.into_inner()).await {
+        Ok(updated) => {
+            Logger::info("API", "KOPIS sync settings updated by admin");
+            Ok(Json(updated.response()))
+        }
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update KOPIS sync settings: {}", e));
+            Err(Status::BadRequest)
         }
     }
 }
@@ -305,12 +305,16 @@ impl KopisClient {
     /// * `end_date` - 종료일자 (YYYYMMDD, 최대 31일)
     /// * `genre_code` - 장르 구분 코드 (Optional, 예: "CCCA" = 클래식)
     /// * `area_code` - 지역 코드 (Optional, 예: "11" = 서울)
+    /// * `period` - 집계 기간 (Optional, day/week/month, 기준일은 end_date)
+    /// * `seat_scale` - 좌석규모 코드 (Optional, 예: "1000" = 500~999석)
     pub async fn fetch_boxoffice_rankings(
         &self,
         start_date: &str,
         end_date: &str,
         genre_code: Option<&str>,
         area_code: Option<&str>,
+        period: Option<&str>,
+        seat_scale: Option<&str>,
     ) -> Result<BoxofficeResponse, String> {
         let mut url = format!(
             "{}/boxoffice?service={}&stdate={}&eddate={}",
@@ -325,6 +329,14 @@ impl KopisClient {
             url.push_str(&format!("&area={}", area));
         }
 
+        if let Some(ststype) = period {
+            url.push_str(&format!("&ststype={}&date={}", ststype, end_date));
+        }
+
+        if let Some(scale) = seat_scale {
+            url.push_str(&format!("&srchseatscale={}", scale));
+        }
+
         let response = self.client
             .get(&url)
             .send()
@@ -0,0 +1,120 @@
+// ============================================
+// KOPIS 코드 ↔ 이름 조회 테이블
+// ============================================
+
+/// KOPIS 장르 코드 (pblprfr `shcate`, boxoffice `catecode`)
+pub const GENRE_CODES: &[(&str, &str)] = &[
+    ("AAAA", "연극"),
+    ("BBBC", "무용(서양/한국무용)"),
+    ("BBBE", "대중무용"),
+    ("CCCA", "서양음악(클래식)"),
+    ("CCCC", "한국음악(국악)"),
+    ("CCCD", "대중음악"),
+    ("EEEA", "복합"),
+    ("EEEB", "서커스/마술"),
+    ("GGGA", "뮤지컬"),
+];
+
+/// KOPIS 시도 코드 (boxoffice `area`)
+/// "00"은 지역 필터 없이 조회하는 전국 순위를 의미 (DB 저장값과 동일)
+pub const AREA_CODES: &[(&str, &str)] = &[
+    ("11", "서울"),
+    ("26", "부산"),
+    ("27", "대구"),
+    ("28", "인천"),
+    ("29", "광주"),
+    ("30", "대전"),
+    ("31", "울산"),
+    ("36", "세종"),
+    ("41", "경기"),
+    ("42", "강원"),
+    ("43", "충북"),
+    ("44", "충남"),
+    ("45", "전북"),
+    ("46", "전남"),
+    ("47", "경북"),
+    ("48", "경남"),
+    ("50", "제주"),
+    ("00", "전체"),
+];
+
+/// 전국 순위를 나타내는 지역 코드
+pub const NATIONWIDE_AREA_CODE: &str = "00";
+
+/// KOPIS 예매상황판 집계 기간 (boxoffice `ststype`)
+pub const BOXOFFICE_PERIODS: &[(&str, &str)] = &[
+    ("day", "일별"),
+    ("week", "주별"),
+    ("month", "월별"),
+];
+
+/// KOPIS 좌석규모 코드 (boxoffice `srchseatscale`)
+/// "0"은 좌석규모 필터 없이 조회하는 전체 순위를 의미 (DB 저장값과 동일)
+pub const SEAT_SCALES: &[(&str, &str)] = &[
+    ("100", "100석 미만"),
+    ("300", "100~299석"),
+    ("500", "300~499석"),
+    ("1000", "500~999석"),
+    ("5000", "1,000~4,999석"),
+    ("10000", "5,000석 이상"),
+    ("0", "전체"),
+];
+
+/// 전체 좌석규모 순위를 나타내는 코드
+pub const ALL_SEAT_SCALE: &str = "0";
+
+/// 지역 코드별 시도 정식 명칭 (공연 상세 `area`, concerts.area 저장값)
+/// 특별자치도로 바뀐 지역은 이전 명칭도 포함
+pub const AREA_PROVINCES: &[(&str, &str)] = &[
+    ("11", "서울특별시"),
+    ("26", "부산광역시"),
+    ("27", "대구광역시"),
+    ("28", "인천광역시"),
+    ("29", "광주광역시"),
+    ("30", "대전광역시"),
+    ("31", "울산광역시"),
+    ("36", "세종특별자치시"),
+    ("41", "경기도"),
+    ("42", "강원특별자치도"),
+    ("42", "강원도"),
+    ("43", "충청북도"),
+    ("44", "충청남도"),
+    ("45", "전북특별자치도"),
+    ("45", "전라북도"),
+    ("46", "전라남도"),
+    ("47", "경상북도"),
+    ("48", "경상남도"),
+    ("50", "제주특별자치도"),
+];
+
+pub fn genre_name(code: &str) -> Option<&'static str> {
+    lookup(GENRE_CODES, code)
+}
+
+pub fn area_name(code: &str) -> Option<&'static str> {
+    lookup(AREA_CODES, code)
+}
+
+pub fn period_name(code: &str) -> Option<&'static str> {
+    lookup(BOXOFFICE_PERIODS, code)
+}
+
+pub fn seat_scale_name(code: &str) -> Option<&'static str> {
+    lookup(SEAT_SCALES, code)
+}
+
+/// 지역 코드에 해당하는 concerts.area 값 목록 ("00"은 빈 목록)
+pub fn province_names(code: &str) -> Vec<&'static str> {
+    AREA_PROVINCES
+        .iter()
+        .filter(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+fn lookup(table: &'static [(&'static str, &'static str)], code: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+}
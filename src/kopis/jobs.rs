@@ -0,0 +1,97 @@
+use super::service::{KopisService, SyncResult};
+use crate::scheduler::{JobCounts, JobDefinition, JobFuture, JobOutput};
+use sqlx::MySqlPool;
+
+pub const VENUE_SYNC_JOB: &str = "venue_sync";
+pub const CONCERT_SYNC_JOB: &str = "concert_sync";
+pub const CONCERT_RECONCILE_JOB: &str = "concert_reconcile";
+
+/// KOPIS 공연장 동기화 (매일 새벽 2시)
+pub fn venue_sync() -> JobDefinition {
+    JobDefinition {
+        name: VENUE_SYNC_JOB,
+        description: "KOPIS 공연장 동기화",
+        cron: "0 0 2 * * *",
+        depends_on: &[],
+        run: run_venue_sync,
+    }
+}
+
+/// KOPIS 공연 + 예매상황판 순위 동기화 (매일 새벽 3시, 공연장 동기화 이후)
+pub fn concert_sync() -> JobDefinition {
+    JobDefinition {
+        name: CONCERT_SYNC_JOB,
+        description: "KOPIS 공연 및 예매상황판 순위 동기화",
+        cron: "0 0 3 * * *",
+        depends_on: &[VENUE_SYNC_JOB],
+        run: run_concert_sync,
+    }
+}
+
+/// KOPIS에서 사라지거나 취소된 공연 표시 (매일 새벽 4시, 공연 동기화 이후)
+pub fn concert_reconcile() -> JobDefinition {
+    JobDefinition {
+        name: CONCERT_RECONCILE_JOB,
+        description: "KOPIS 공연 대조 (취소/삭제 공연 표시)",
+        cron: "0 0 4 * * *",
+        depends_on: &[CONCERT_SYNC_JOB],
+        run: run_concert_reconcile,
+    }
+}
+
+fn run_venue_sync(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        KopisService::sync_venues(&pool).await.map(|result| JobOutput {
+            message: summarize(&result),
+            counts: Some(JobCounts {
+                added: result.added,
+                updated: result.updated,
+                errors: result.errors,
+            }),
+        })
+    })
+}
+
+fn run_concert_sync(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        // 공연 동기화 실패해도 순위 동기화는 시도
+        let concerts = KopisService::sync_concerts(&pool).await;
+        let boxoffice = KopisService::sync_boxoffice_rankings(&pool).await;
+
+        match (concerts, boxoffice) {
+            (Ok(concerts), Ok(boxoffice)) => Ok(format!(
+                "concerts: {} / boxoffice: {}",
+                summarize(&concerts),
+                summarize(&boxoffice)
+            )
+            .into()),
+            (Err(concert_error), Err(boxoffice_error)) => Err(format!(
+                "Concert sync failed: {} / Boxoffice sync failed: {}",
+                concert_error, boxoffice_error
+            )),
+            (Err(e), _) => Err(format!("Concert sync failed: {}", e)),
+            (_, Err(e)) => Err(format!("Boxoffice sync failed: {}", e)),
+        }
+    })
+}
+
+fn run_concert_reconcile(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        KopisService::reconcile_concerts(&pool)
+            .await
+            .map(|result| {
+                format!(
+                    "{} checked, {} refreshed, {} cancelled, {} stale, {} errors",
+                    result.checked, result.refreshed, result.cancelled, result.stale, result.errors
+                )
+                .into()
+            })
+    })
+}
+
+fn summarize(result: &SyncResult) -> String {
+    format!(
+        "{} added, {} updated, {} errors",
+        result.added, result.updated, result.errors
+    )
+}
@@ -1,13 +1,13 @@
 mod api;
 pub mod client;
-pub mod concert_scheduler;
+pub mod codes;
+pub mod jobs;
 pub mod models;
-pub mod scheduler;
+pub mod repository;
 pub mod service;
+pub mod settings;
 
-pub use api::trigger_venue_sync;
+pub use api::{get_sync_settings, trigger_venue_sync, update_sync_settings};
 pub use client::*;
-pub use concert_scheduler::*;
 pub use models::*;
-pub use scheduler::*;
 pub use service::*;
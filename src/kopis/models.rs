@@ -177,8 +177,9 @@ pub struct ConcertListItem {
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename = "dbs")]
 pub struct ConcertDetailResponse {
-    #[serde(rename = "db")]
-    pub db: ConcertDetail,
+    /// 삭제된 공연 ID로 조회하면 빈 <dbs/>가 와서 None
+    #[serde(rename = "db", default)]
+    pub db: Option<ConcertDetail>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -341,6 +342,7 @@ pub struct BoxofficeItem {
 // ============================================
 // Helper 함수들
 // ============================================
+use crate::clock;
 use chrono::NaiveDate;
 
 impl ConcertListItem {
@@ -388,11 +390,24 @@ impl ConcertDetail {
     }
 
     /// KOPIS 공연상태를 DB ENUM으로 변환
+    /// 알 수 없는 상태는 공연 기간으로 판단
     pub fn parse_status(&self) -> String {
         match self.performance_state.as_str() {
             "공연예정" => "upcoming".to_string(),
             "공연중" => "ongoing".to_string(),
             "공연완료" => "completed".to_string(),
+            state if state.contains("취소") => "cancelled".to_string(),
+            _ => self.status_by_dates(clock::today()),
+        }
+    }
+
+    fn status_by_dates(&self, today: NaiveDate) -> String {
+        let start = self.parse_start_date();
+        let end = self.parse_end_date().or(start);
+
+        match (start, end) {
+            (_, Some(end)) if end < today => "completed".to_string(),
+            (Some(start), _) if start <= today => "ongoing".to_string(),
             _ => "upcoming".to_string(),
         }
     }
@@ -410,3 +425,14 @@ fn parse_kopis_date(date_str: &str) -> Option<NaiveDate> {
         None
     }
 }
+
+// ============================================
+// 공연장 미확인 공연 대기열 (kopis_pending_concerts)
+// ============================================
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PendingConcert {
+    pub kopis_id: String,        // KOPIS 공연ID
+    pub title: String,           // 공연명
+    pub venue_kopis_id: String,  // KOPIS 공연시설ID
+    pub attempts: i32,           // 재시도 횟수
+}
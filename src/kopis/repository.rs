@@ -0,0 +1,61 @@
+use super::models::PendingConcert;
+use sqlx::MySqlPool;
+
+pub struct KopisRepository;
+
+impl KopisRepository {
+    // ============================================
+    // 공연장 미확인 공연 대기열
+    // ============================================
+
+    pub async fn find_pending_concerts(pool: &MySqlPool) -> Result<Vec<PendingConcert>, sqlx::Error> {
+        sqlx::query_as::<_, PendingConcert>(
+            "SELECT kopis_id, title, venue_kopis_id, attempts
+             FROM kopis_pending_concerts
+             ORDER BY created_at",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 대기열에 추가 (이미 있으면 공연명/공연시설ID만 갱신)
+    pub async fn upsert_pending_concert(
+        pool: &MySqlPool,
+        kopis_id: &str,
+        title: &str,
+        venue_kopis_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO kopis_pending_concerts (kopis_id, title, venue_kopis_id)
+             VALUES (?, ?, ?)
+             ON DUPLICATE KEY UPDATE title = VALUES(title), venue_kopis_id = VALUES(venue_kopis_id)",
+        )
+        .bind(kopis_id)
+        .bind(title)
+        .bind(venue_kopis_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn increment_pending_attempts(pool: &MySqlPool, kopis_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE kopis_pending_concerts SET attempts = attempts + 1 WHERE kopis_id = ?",
+        )
+        .bind(kopis_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn delete_pending_concert(pool: &MySqlPool, kopis_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM kopis_pending_concerts WHERE kopis_id = ?")
+            .bind(kopis_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,12 +1,25 @@
 use super::client::KopisClient;
+use super::codes::{genre_name, ALL_SEAT_SCALE, NATIONWIDE_AREA_CODE};
+use super::models::{BoxofficeItem, ConcertDetail};
+use super::repository::KopisRepository;
+use super::settings::{describe_area, describe_genre, describe_seat_scale, KopisSyncSettings};
 use crate::artist::repository::ArtistRepository;
+use crate::boxoffice::model::NewBoxofficeSnapshot;
+use crate::boxoffice::repository::RankingScope;
 use crate::boxoffice::BoxofficeRepository;
+use crate::clock;
+use crate::concert::lineup::{link_single_ensemble, split_cast_role};
+use crate::concert::model::ConcertLineupEntry;
 use crate::concert::repository::ConcertRepository;
 use crate::hall::{CreateHall, HallRepository};
 use crate::logger::Logger;
 use crate::venue::{CreateVenue, VenueRepository};
-use chrono::{Duration, NaiveDate, Utc};
+use chrono::{Duration, NaiveDate};
 use sqlx::MySqlPool;
+use std::collections::{HashMap, HashSet};
+
+/// 공연장 미확인 공연 재시도 최대 횟수 (일 1회 동기화 기준 약 2주)
+const MAX_PENDING_ATTEMPTS: i32 = 14;
 
 pub struct KopisService;
 
@@ -45,108 +58,25 @@ impl KopisService {
         let mut error_count = 0;
 
         for venue_item in venues {
-            // 공연장 상세 정보 조회
-            match client.fetch_venue_detail(&venue_item.facility_id).await {
-                Ok(detail_response) => {
-                    let detail = detail_response.db;
-
-                    // Venue 저장/업데이트
-                    let venue_data = CreateVenue {
-                        kopis_id: Some(detail.facility_id.clone()),
-                        name: detail.facility_name.clone(),
-                        address: detail.address.clone(),
-                        city: venue_item.city.clone(),
-                        province: venue_item.province.clone(),
-                        country: Some("대한민국".to_string()),
-                        seats: detail.parse_seats(),
-                        hall_count: detail.hall_count,
-                        opening_year: detail.parse_opening_year(),
-                        is_active: Some(true),
-                        data_source: Some("KOPIS".to_string()),
-                    };
-
-                    match VenueRepository::get_by_kopis_id(pool, &detail.facility_id).await {
-                        Ok(existing) => {
-                            match VenueRepository::upsert(pool, venue_data).await {
-                                Ok(venue_id) => {
-                                    if existing.is_some() {
-                                        updated_count += 1;
-                                        Logger::debug(
-                                            "KOPIS",
-                                            &format!(
-                                                "Updated venue: {} (ID: {})",
-                                                detail.facility_name, venue_id
-                                            ),
-                                        );
-                                    } else {
-                                        added_count += 1;
-                                        Logger::success(
-                                            "KOPIS",
-                                            &format!(
-                                                "Added new venue: {} (ID: {})",
-                                                detail.facility_name, venue_id
-                                            ),
-                                        );
-                                    }
-
-                                    // Halls 저장/업데이트
-                                    if let Some(halls_wrapper) = detail.halls {
-                                        for hall_detail in halls_wrapper.halls {
-                                            let hall_data = CreateHall {
-                                                venue_id,
-                                                kopis_id: Some(hall_detail.hall_id.clone()),
-                                                name: hall_detail.hall_name.clone(),
-                                                seats: hall_detail.parse_seats(),
-                                                is_active: Some(true),
-                                            };
-
-                                            if let Err(e) =
-                                                HallRepository::upsert(pool, hall_data).await
-                                            {
-                                                Logger::warn(
-                                                    "KOPIS",
-                                                    &format!(
-                                                        "Failed to upsert hall {}: {}",
-                                                        hall_detail.hall_name, e
-                                                    ),
-                                                );
-                                                error_count += 1;
-                                            }
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    Logger::error(
-                                        "KOPIS",
-                                        &format!(
-                                            "Failed to upsert venue {}: {}",
-                                            detail.facility_name, e
-                                        ),
-                                    );
-                                    error_count += 1;
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            Logger::error(
-                                "KOPIS",
-                                &format!(
-                                    "Failed to check existing venue {}: {}",
-                                    detail.facility_id, e
-                                ),
-                            );
-                            error_count += 1;
-                        }
+            match Self::import_venue(
+                pool,
+                &client,
+                &venue_item.facility_id,
+                venue_item.city.clone(),
+                venue_item.province.clone(),
+            )
+            .await
+            {
+                Ok(imported) => {
+                    if imported.existed {
+                        updated_count += 1;
+                    } else {
+                        added_count += 1;
                     }
+                    error_count += imported.hall_errors;
                 }
                 Err(e) => {
-                    Logger::warn(
-                        "KOPIS",
-                        &format!(
-                            "Failed to fetch detail for venue {}: {}",
-                            venue_item.facility_id, e
-                        ),
-                    );
+                    Logger::warn("KOPIS", &e);
                     error_count += 1;
                 }
             }
@@ -159,7 +89,7 @@ impl KopisService {
         };
 
         // 동기화 완료 기록
-        let today = Utc::now().date_naive();
+        let today = clock::today();
         Self::update_sync_status(
             pool,
             "venues",
@@ -181,6 +111,91 @@ impl KopisService {
         Ok(result)
     }
 
+    /// KOPIS 공연장 상세 조회 후 공연장 및 공연홀 저장
+    /// 정기 공연장 동기화와 공연 동기화 중 누락된 공연장 보충에 공통 사용
+    async fn import_venue(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        facility_id: &str,
+        city: Option<String>,
+        province: Option<String>,
+    ) -> Result<ImportedVenue, String> {
+        let detail = client
+            .fetch_venue_detail(facility_id)
+            .await
+            .map_err(|e| format!("Failed to fetch detail for venue {}: {}", facility_id, e))?
+            .db;
+
+        let existed = VenueRepository::get_by_kopis_id(pool, &detail.facility_id)
+            .await
+            .map_err(|e| {
+                format!(
+                    "Failed to check existing venue {}: {}",
+                    detail.facility_id, e
+                )
+            })?
+            .is_some();
+
+        // Venue 저장/업데이트
+        let venue_data = CreateVenue {
+            kopis_id: Some(detail.facility_id.clone()),
+            name: detail.facility_name.clone(),
+            address: detail.address.clone(),
+            city,
+            province,
+            country: Some("대한민국".to_string()),
+            seats: detail.parse_seats(),
+            hall_count: detail.hall_count,
+            opening_year: detail.parse_opening_year(),
+            is_active: Some(true),
+            data_source: Some("KOPIS".to_string()),
+        };
+
+        let venue_id = VenueRepository::upsert(pool, venue_data)
+            .await
+            .map_err(|e| format!("Failed to upsert venue {}: {}", detail.facility_name, e))?;
+
+        if existed {
+            Logger::debug(
+                "KOPIS",
+                &format!("Updated venue: {} (ID: {})", detail.facility_name, venue_id),
+            );
+        } else {
+            Logger::success(
+                "KOPIS",
+                &format!("Added new venue: {} (ID: {})", detail.facility_name, venue_id),
+            );
+        }
+
+        // Halls 저장/업데이트
+        let mut hall_errors = 0;
+        if let Some(halls_wrapper) = detail.halls {
+            for hall_detail in halls_wrapper.halls {
+                let hall_data = CreateHall {
+                    venue_id,
+                    kopis_id: Some(hall_detail.hall_id.clone()),
+                    name: hall_detail.hall_name.clone(),
+                    seats: hall_detail.parse_seats(),
+                    is_active: Some(true),
+                };
+
+                if let Err(e) = HallRepository::upsert(pool, hall_data).await {
+                    Logger::warn(
+                        "KOPIS",
+                        &format!("Failed to upsert hall {}: {}", hall_detail.hall_name, e),
+                    );
+                    hall_errors += 1;
+                }
+            }
+        }
+
+        Ok(ImportedVenue {
+            id: venue_id,
+            existed,
+            hall_errors,
+        })
+    }
+
     /// 마지막 동기화 날짜 조회
     async fn get_last_sync_date(pool: &MySqlPool, sync_type: &str) -> Result<NaiveDate, String> {
         let row: Option<(NaiveDate,)> =
@@ -267,10 +282,12 @@ impl KopisService {
     // 아티스트 매칭 헬퍼 함수
     // ============================================
 
-    /// cast 문자열을 파싱하여 아티스트 ID 목록 반환
-    /// 예: "손열음, 홍혜란, 김효나" -> [189, 234, ...]
-    async fn parse_and_match_artists(pool: &MySqlPool, cast: Option<&str>) -> Vec<i32> {
-        let mut artist_ids = Vec::new();
+    /// cast 문자열을 파싱하여 출연진 목록 반환
+    /// 예: "서울시립교향악단, 정명훈(지휘), 손열음(피아노)" -> [{189}, {201, 지휘}, {234, 피아노}]
+    /// 앙상블이 하나뿐이면 지휘자/독주자를 그 앙상블과 연결
+    async fn parse_and_match_artists(pool: &MySqlPool, cast: Option<&str>) -> Vec<ConcertLineupEntry> {
+        let mut lineup: Vec<ConcertLineupEntry> = Vec::new();
+        let mut artist_types = HashMap::new();
 
         if let Some(cast_str) = cast {
             // 쉼표나 띄어쓰기 등으로 분리
@@ -281,17 +298,26 @@ impl KopisService {
                 .collect();
 
             for name in names {
-                // " 등" 제거
+                // " 등" 제거 후 괄호 안 역할 분리
                 let clean_name = name.trim_end_matches(" 등").trim_end_matches("등").trim();
+                let (clean_name, role) = split_cast_role(clean_name);
 
                 // DB에서 아티스트 검색
                 match ArtistRepository::find_by_name(pool, clean_name).await {
                     Ok(Some(artist)) => {
-                        artist_ids.push(artist.id);
                         Logger::debug(
                             "KOPIS",
                             &format!("Matched artist: {} (ID: {})", clean_name, artist.id),
                         );
+                        if lineup.iter().any(|entry| entry.artist_id == artist.id) {
+                            continue;
+                        }
+                        lineup.push(ConcertLineupEntry {
+                            artist_id: artist.id,
+                            role: role.map(str::to_string),
+                            ensemble_id: None,
+                        });
+                        artist_types.insert(artist.id, artist.artist_type);
                     }
                     Ok(None) => {
                         Logger::debug("KOPIS", &format!("Artist not found in DB: {}", clean_name));
@@ -306,7 +332,8 @@ impl KopisService {
             }
         }
 
-        artist_ids
+        link_single_ensemble(&mut lineup, &artist_types);
+        lineup
     }
 
     // ============================================
@@ -314,7 +341,7 @@ impl KopisService {
     // ============================================
 
     /// KOPIS API에서 공연 데이터를 동기화
-    /// 클래식/뮤지컬/오페라 장르만 필터링
+    /// 설정된 장르(KopisSyncSettings::concert_genres)만 필터링
     pub async fn sync_concerts(pool: &MySqlPool) -> Result<SyncResult, String> {
         Logger::info("KOPIS", "Starting concert synchronization");
 
@@ -335,34 +362,32 @@ impl KopisService {
         // KOPIS 클라이언트 생성
         let client = KopisClient::from_env()?;
 
-        // 클래식 관련 장르 코드
-        let genre_codes = vec![
-            "CCCA", // 클래식
-            "GGGA", // 뮤지컬
-            "CCCC", // 오페라
-        ];
+        // 동기화 범위 설정 (장르, 조회 기간)
+        let settings = KopisSyncSettings::load(pool).await;
 
-        let mut added_count = 0;
-        let mut updated_count = 0;
-        let mut error_count = 0;
+        // 이번 실행에서 이미 조회한 공연 (대기열 재시도 후 목록에 다시 나와도 재조회하지 않음)
+        let mut tried = HashSet::new();
 
-        // 조회 기간 설정 (오늘부터 1년 후까지)
-        let today = Utc::now().date_naive();
-        let start_date = today.format("%Y%m%d").to_string();
-        let end_date = (today + Duration::days(365)).format("%Y%m%d").to_string();
+        // 이전 실행에서 공연장을 찾지 못한 공연 먼저 재시도
+        let retried = Self::retry_pending_concerts(pool, &client, &mut tried).await;
 
-        for genre_code in &genre_codes {
-            Logger::info("KOPIS", &format!("Syncing genre: {}", genre_code));
+        let mut added_count = retried.added;
+        let mut updated_count = retried.updated;
+        let mut error_count = retried.errors;
 
-            // 31일 단위로 분할하여 조회 (KOPIS API 제한)
-            let mut current_start = today;
-            let end_limit = today + Duration::days(365);
+        // 조회 기간 설정 (오늘부터 lookahead_days 후까지)
+        let today = clock::today();
 
-            while current_start < end_limit {
-                let current_end = (current_start + Duration::days(30)).min(end_limit);
+        for genre_code in &settings.concert_genres {
+            Logger::info(
+                "KOPIS",
+                &format!("Syncing genre: {}", describe_genre(genre_code)),
+            );
 
-                let batch_start = current_start.format("%Y%m%d").to_string();
-                let batch_end = current_end.format("%Y%m%d").to_string();
+            // batch_days 단위로 분할하여 조회 (KOPIS API 제한: 최대 31일)
+            for (window_start, window_end) in settings.listing_windows(today) {
+                let batch_start = window_start.format("%Y%m%d").to_string();
+                let batch_end = window_end.format("%Y%m%d").to_string();
 
                 match client
                     .fetch_all_concerts(
@@ -386,230 +411,27 @@ impl KopisService {
                         );
 
                         for concert_item in concerts {
-                            // 공연 상세 정보 조회
-                            match client
-                                .fetch_concert_detail(&concert_item.performance_id)
+                            if !tried.insert(concert_item.performance_id.clone()) {
+                                continue;
+                            }
+
+                            match Self::sync_concert(pool, &client, &concert_item.performance_id)
                                 .await
                             {
-                                Ok(detail_response) => {
-                                    let detail = detail_response.db;
-
-                                    // venue_kopis_id로 venue_id 매칭
-                                    match ConcertRepository::get_venue_id_by_kopis_id(
+                                Ok(ConcertSyncOutcome::Added) => added_count += 1,
+                                Ok(ConcertSyncOutcome::Updated) => updated_count += 1,
+                                Ok(ConcertSyncOutcome::VenueMissing(facility_id)) => {
+                                    Self::queue_pending_concert(
                                         pool,
-                                        &detail.facility_id,
+                                        &concert_item.performance_id,
+                                        &concert_item.performance_name,
+                                        &facility_id,
                                     )
-                                    .await
-                                    {
-                                        Ok(Some(venue_id)) => {
-                                            // 공연 데이터 upsert
-                                            let start_date_str = detail
-                                                .parse_start_date()
-                                                .map(|d| d.format("%Y-%m-%d").to_string())
-                                                .unwrap_or(detail.start_date.clone());
-
-                                            let end_date_str = detail
-                                                .parse_end_date()
-                                                .map(|d| d.format("%Y-%m-%d").to_string());
-
-                                            match ConcertRepository::upsert_kopis_concert(
-                                                pool,
-                                                &detail.performance_id,
-                                                &detail.performance_name,
-                                                detail.cast.as_deref(),
-                                                venue_id,
-                                                &start_date_str,
-                                                end_date_str.as_deref(),
-                                                detail.performance_schedule.as_deref(), // concert_time (KOPIS dtguidance)
-                                                detail.poster.as_deref(),
-                                                detail.synopsis.as_deref(),
-                                                detail.price_info.as_deref(),
-                                                &detail.parse_status(),
-                                                // KOPIS 추가 필드들
-                                                &detail.facility_id,
-                                                detail.update_date.as_deref(),
-                                                Some(&detail.genre_name),
-                                                detail.area.as_deref(),
-                                                Some(&detail.facility_name),
-                                                detail.is_open_run(),
-                                                detail.cast.as_deref(),
-                                                detail.crew.as_deref(),
-                                                detail.runtime.as_deref(),
-                                                detail.age_restriction.as_deref(),
-                                                detail.synopsis.as_deref(),
-                                                detail.performance_schedule.as_deref(),
-                                                detail.production_company.as_deref(),
-                                                detail.production_company_plan.as_deref(),
-                                                detail.production_company_agency.as_deref(),
-                                                detail.production_company_host.as_deref(),
-                                                detail.production_company_sponsor.as_deref(),
-                                                detail.is_visit(),
-                                                detail.is_child(),
-                                                detail.is_daehakro(),
-                                                detail.is_festival(),
-                                            )
-                                            .await
-                                            {
-                                                Ok(concert_id) => {
-                                                    // Ticket Vendors 저장
-                                                    if let Some(vendor_list) =
-                                                        &detail.ticket_vendors
-                                                    {
-                                                        let vendors: Vec<(Option<String>, String)> =
-                                                            vendor_list
-                                                                .vendors
-                                                                .iter()
-                                                                .map(|v| {
-                                                                    (
-                                                                        v.vendor_name.clone(),
-                                                                        v.vendor_url.clone(),
-                                                                    )
-                                                                })
-                                                                .collect();
-
-                                                        if !vendors.is_empty() {
-                                                            if let Err(e) = ConcertRepository::upsert_ticket_vendors(
-                                                                pool,
-                                                                concert_id,
-                                                                vendors,
-                                                            )
-                                                            .await
-                                                            {
-                                                                Logger::warn(
-                                                                    "KOPIS",
-                                                                    &format!(
-                                                                        "Failed to save ticket vendors for concert {}: {}",
-                                                                        detail.performance_name, e
-                                                                    ),
-                                                                );
-                                                            }
-                                                        }
-                                                    }
-
-                                                    // Concert Images 저장 (소개 이미지)
-                                                    if let Some(intro_images) = &detail.intro_images
-                                                    {
-                                                        if !intro_images.images.is_empty() {
-                                                            if let Err(e) = ConcertRepository::upsert_concert_images(
-                                                                pool,
-                                                                concert_id,
-                                                                intro_images.images.clone(),
-                                                                "introduction",
-                                                            )
-                                                            .await
-                                                            {
-                                                                Logger::warn(
-                                                                    "KOPIS",
-                                                                    &format!(
-                                                                        "Failed to save intro images for concert {}: {}",
-                                                                        detail.performance_name, e
-                                                                    ),
-                                                                );
-                                                            }
-                                                        }
-                                                    }
-
-                                                    // 아티스트 매칭 및 저장
-                                                    let artist_ids = Self::parse_and_match_artists(
-                                                        pool,
-                                                        detail.cast.as_deref(),
-                                                    )
-                                                    .await;
-                                                    if !artist_ids.is_empty() {
-                                                        if let Err(e) = ConcertRepository::upsert_concert_artists(
-                                                            pool,
-                                                            concert_id,
-                                                            artist_ids.clone(),
-                                                        )
-                                                        .await
-                                                        {
-                                                            Logger::warn(
-                                                                "KOPIS",
-                                                                &format!(
-                                                                    "Failed to save concert artists for {}: {}",
-                                                                    detail.performance_name, e
-                                                                ),
-                                                            );
-                                                        } else {
-                                                            Logger::success(
-                                                                "KOPIS",
-                                                                &format!(
-                                                                    "Matched {} artists for concert: {}",
-                                                                    artist_ids.len(),
-                                                                    detail.performance_name
-                                                                ),
-                                                            );
-                                                        }
-                                                    }
-
-                                                    // 기존 공연 여부 확인
-                                                    if ConcertRepository::get_by_kopis_id(
-                                                        pool,
-                                                        &detail.performance_id,
-                                                    )
-                                                    .await
-                                                    .ok()
-                                                    .flatten()
-                                                    .is_some()
-                                                    {
-                                                        updated_count += 1;
-                                                        Logger::debug(
-                                                            "KOPIS",
-                                                            &format!(
-                                                                "Updated concert: {} (ID: {})",
-                                                                detail.performance_name, concert_id
-                                                            ),
-                                                        );
-                                                    } else {
-                                                        added_count += 1;
-                                                        Logger::success(
-                                                            "KOPIS",
-                                                            &format!(
-                                                                "Added new concert: {} (ID: {})",
-                                                                detail.performance_name, concert_id
-                                                            ),
-                                                        );
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    Logger::error(
-                                                        "KOPIS",
-                                                        &format!(
-                                                            "Failed to upsert concert {}: {}",
-                                                            detail.performance_name, e
-                                                        ),
-                                                    );
-                                                    error_count += 1;
-                                                }
-                                            }
-                                        }
-                                        Ok(None) => {
-                                            Logger::warn("KOPIS", &format!(
-                                                "Venue not found for concert {} (facility_id: {}), skipping",
-                                                detail.performance_name, detail.facility_id
-                                            ));
-                                            error_count += 1;
-                                        }
-                                        Err(e) => {
-                                            Logger::error(
-                                                "KOPIS",
-                                                &format!(
-                                                    "Failed to get venue_id for {}: {}",
-                                                    detail.facility_id, e
-                                                ),
-                                            );
-                                            error_count += 1;
-                                        }
-                                    }
+                                    .await;
+                                    error_count += 1;
                                 }
                                 Err(e) => {
-                                    Logger::warn(
-                                        "KOPIS",
-                                        &format!(
-                                            "Failed to fetch detail for concert {}: {}",
-                                            concert_item.performance_id, e
-                                        ),
-                                    );
+                                    Logger::warn("KOPIS", &e);
                                     error_count += 1;
                                 }
                             }
@@ -626,8 +448,6 @@ impl KopisService {
                         error_count += 1;
                     }
                 }
-
-                current_start = current_end + Duration::days(1);
             }
         }
 
@@ -638,7 +458,7 @@ impl KopisService {
         };
 
         // 동기화 완료 기록
-        let today = Utc::now().date_naive();
+        let today = clock::today();
         Self::update_sync_status(
             pool,
             "concerts",
@@ -660,166 +480,518 @@ impl KopisService {
         Ok(result)
     }
 
-    /// KOPIS API에서 예매상황판 순위 동기화
-    /// 클래식 장르의 TOP 3만 저장
-    pub async fn sync_boxoffice_rankings(pool: &MySqlPool) -> Result<SyncResult, String> {
-        Logger::info("KOPIS", "Starting boxoffice rankings synchronization");
+    /// 공연 1건 상세 조회 후 저장 (예매처, 소개 이미지, 출연 아티스트 포함)
+    /// 공연장이 DB에 없으면 KOPIS에서 즉시 조회해 저장한 뒤 진행
+    async fn sync_concert(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        performance_id: &str,
+    ) -> Result<ConcertSyncOutcome, String> {
+        // 공연 상세 정보 조회
+        let detail = client
+            .fetch_concert_detail(performance_id)
+            .await
+            .map_err(|e| format!("Failed to fetch detail for concert {}: {}", performance_id, e))?
+            .db
+            .ok_or_else(|| format!("Concert {} not found in KOPIS", performance_id))?;
 
-        // 동기화 시작 기록
-        Self::update_sync_status(pool, "boxoffice", "in_progress", None, None, None)
+        Self::save_concert_detail(pool, client, detail).await
+    }
+
+    /// 조회한 공연 상세 저장
+    async fn save_concert_detail(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        detail: ConcertDetail,
+    ) -> Result<ConcertSyncOutcome, String> {
+        // venue_kopis_id로 venue_id 매칭
+        let venue_id = match Self::resolve_venue_id(pool, client, &detail).await? {
+            Some(venue_id) => venue_id,
+            None => return Ok(ConcertSyncOutcome::VenueMissing(detail.facility_id)),
+        };
+
+        // 기존 공연 여부 확인 (upsert 전에 확인해야 신규/업데이트 구분 가능)
+        let exists = ConcertRepository::get_by_kopis_id(pool, &detail.performance_id)
             .await
-            .map_err(|e| format!("Failed to update sync status: {}", e))?;
+            .ok()
+            .flatten()
+            .is_some();
 
-        // KOPIS 클라이언트 생성
-        let client = KopisClient::from_env()?;
+        // 공연 데이터 upsert
+        let start_date_str = detail
+            .parse_start_date()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or(detail.start_date.clone());
 
-        // 조회 기간: 최근 30일
-        let today = Utc::now().date_naive();
-        let start_date = (today - Duration::days(30)).format("%Y%m%d").to_string();
-        let end_date = today.format("%Y%m%d").to_string();
+        let end_date_str = detail
+            .parse_end_date()
+            .map(|d| d.format("%Y-%m-%d").to_string());
 
-        let mut added_count = 0;
-        let mut error_count = 0;
+        let concert_id = ConcertRepository::upsert_kopis_concert(
+            pool,
+            &detail.performance_id,
+            &detail.performance_name,
+            detail.cast.as_deref(),
+            venue_id,
+            &start_date_str,
+            end_date_str.as_deref(),
+            detail.performance_schedule.as_deref(), // concert_time (KOPIS dtguidance)
+            detail.poster.as_deref(),
+            detail.synopsis.as_deref(),
+            detail.price_info.as_deref(),
+            &detail.parse_status(),
+            // KOPIS 추가 필드들
+            &detail.facility_id,
+            detail.update_date.as_deref(),
+            Some(&detail.genre_name),
+            detail.area.as_deref(),
+            Some(&detail.facility_name),
+            detail.is_open_run(),
+            detail.cast.as_deref(),
+            detail.crew.as_deref(),
+            detail.runtime.as_deref(),
+            detail.age_restriction.as_deref(),
+            detail.synopsis.as_deref(),
+            detail.performance_schedule.as_deref(),
+            detail.production_company.as_deref(),
+            detail.production_company_plan.as_deref(),
+            detail.production_company_agency.as_deref(),
+            detail.production_company_host.as_deref(),
+            detail.production_company_sponsor.as_deref(),
+            detail.is_visit(),
+            detail.is_child(),
+            detail.is_daehakro(),
+            detail.is_festival(),
+        )
+        .await
+        .map_err(|e| format!("Failed to upsert concert {}: {}", detail.performance_name, e))?;
+
+        // Ticket Vendors 저장
+        if let Some(vendor_list) = &detail.ticket_vendors {
+            let vendors: Vec<(Option<String>, String)> = vendor_list
+                .vendors
+                .iter()
+                .map(|v| (v.vendor_name.clone(), v.vendor_url.clone()))
+                .collect();
+
+            if !vendors.is_empty() {
+                if let Err(e) =
+                    ConcertRepository::upsert_ticket_vendors(pool, concert_id, vendors).await
+                {
+                    Logger::warn(
+                        "KOPIS",
+                        &format!(
+                            "Failed to save ticket vendors for concert {}: {}",
+                            detail.performance_name, e
+                        ),
+                    );
+                }
+            }
+        }
 
-        // 클래식 장르만
-        let genre_codes = vec!["CCCA"]; // 클래식
-
-        // 전국 17개 시도 + 전체
-        let area_codes = vec![
-            Some("11"), // 서울
-            Some("26"), // 부산
-            Some("27"), // 대구
-            Some("28"), // 인천
-            Some("29"), // 광주
-            Some("30"), // 대전
-            Some("31"), // 울산
-            Some("36"), // 세종
-            Some("41"), // 경기
-            Some("42"), // 강원
-            Some("43"), // 충북
-            Some("44"), // 충남
-            Some("45"), // 전북
-            Some("46"), // 전남
-            Some("47"), // 경북
-            Some("48"), // 경남
-            Some("50"), // 제주
-            None,       // 전체
-        ];
-
-        for genre_code in &genre_codes {
-            for area_code in &area_codes {
-                let area_display = area_code.unwrap_or("전체");
-
-                Logger::info(
+        // Concert Images 저장 (소개 이미지)
+        if let Some(intro_images) = &detail.intro_images {
+            if !intro_images.images.is_empty() {
+                if let Err(e) = ConcertRepository::upsert_concert_images(
+                    pool,
+                    concert_id,
+                    intro_images.images.clone(),
+                    "introduction",
+                )
+                .await
+                {
+                    Logger::warn(
+                        "KOPIS",
+                        &format!(
+                            "Failed to save intro images for concert {}: {}",
+                            detail.performance_name, e
+                        ),
+                    );
+                }
+            }
+        }
+
+        // 아티스트 매칭 및 저장
+        let lineup = Self::parse_and_match_artists(pool, detail.cast.as_deref()).await;
+        if !lineup.is_empty() {
+            if let Err(e) =
+                ConcertRepository::upsert_concert_artists(pool, concert_id, &lineup).await
+            {
+                Logger::warn(
+                    "KOPIS",
+                    &format!(
+                        "Failed to save concert artists for {}: {}",
+                        detail.performance_name, e
+                    ),
+                );
+            } else {
+                Logger::success(
                     "KOPIS",
                     &format!(
-                        "Fetching boxoffice rankings for genre: {}, area: {}",
-                        genre_code, area_display
+                        "Matched {} artists for concert: {}",
+                        lineup.len(),
+                        detail.performance_name
                     ),
                 );
+            }
+        }
 
-                match client
-                    .fetch_boxoffice_rankings(&start_date, &end_date, Some(genre_code), *area_code)
+        if exists {
+            Logger::debug(
+                "KOPIS",
+                &format!(
+                    "Updated concert: {} (ID: {})",
+                    detail.performance_name, concert_id
+                ),
+            );
+            Ok(ConcertSyncOutcome::Updated)
+        } else {
+            Logger::success(
+                "KOPIS",
+                &format!(
+                    "Added new concert: {} (ID: {})",
+                    detail.performance_name, concert_id
+                ),
+            );
+            Ok(ConcertSyncOutcome::Added)
+        }
+    }
+
+    /// 공연의 공연장 ID 조회
+    /// 아직 동기화되지 않은 공연장이면 KOPIS에서 상세 조회 후 저장 (실패 시 None)
+    async fn resolve_venue_id(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        detail: &ConcertDetail,
+    ) -> Result<Option<i32>, String> {
+        let venue_id = ConcertRepository::get_venue_id_by_kopis_id(pool, &detail.facility_id)
+            .await
+            .map_err(|e| format!("Failed to get venue_id for {}: {}", detail.facility_id, e))?;
+
+        if venue_id.is_some() {
+            return Ok(venue_id);
+        }
+
+        Logger::info(
+            "KOPIS",
+            &format!(
+                "Venue {} not synced yet, fetching for concert {}",
+                detail.facility_id, detail.performance_name
+            ),
+        );
+
+        // 공연 상세에는 시군구 정보가 없으므로 지역(시도)만 저장, 정기 공연장 동기화 때 보완됨
+        match Self::import_venue(pool, client, &detail.facility_id, None, detail.area.clone()).await
+        {
+            Ok(imported) => Ok(Some(imported.id)),
+            Err(e) => {
+                Logger::warn("KOPIS", &e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// 공연장을 찾지 못한 공연을 대기열에 저장 (다음 동기화 때 재시도)
+    async fn queue_pending_concert(
+        pool: &MySqlPool,
+        performance_id: &str,
+        title: &str,
+        facility_id: &str,
+    ) {
+        Logger::warn(
+            "KOPIS",
+            &format!(
+                "Venue not found for concert {} (facility_id: {}), queued for retry",
+                title, facility_id
+            ),
+        );
+
+        if let Err(e) =
+            KopisRepository::upsert_pending_concert(pool, performance_id, title, facility_id).await
+        {
+            Logger::error(
+                "KOPIS",
+                &format!("Failed to queue pending concert {}: {}", performance_id, e),
+            );
+        }
+    }
+
+    /// 이전 동기화에서 공연장을 찾지 못한 공연 재시도
+    /// MAX_PENDING_ATTEMPTS회 이상 실패하면 대기열에서 제거
+    /// 재시도한 공연은 tried에 기록 (같은 실행의 목록 동기화에서 건너뜀)
+    async fn retry_pending_concerts(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        tried: &mut HashSet<String>,
+    ) -> SyncResult {
+        let mut result = SyncResult {
+            added: 0,
+            updated: 0,
+            errors: 0,
+        };
+
+        let pending = match KopisRepository::find_pending_concerts(pool).await {
+            Ok(pending) => pending,
+            Err(e) => {
+                Logger::warn("KOPIS", &format!("Failed to load pending concerts: {}", e));
+                return result;
+            }
+        };
+
+        if pending.is_empty() {
+            return result;
+        }
+
+        Logger::info(
+            "KOPIS",
+            &format!("Retrying {} pending concerts", pending.len()),
+        );
+
+        for concert in pending {
+            tried.insert(concert.kopis_id.clone());
+
+            let resolved = match Self::sync_concert(pool, client, &concert.kopis_id).await {
+                Ok(ConcertSyncOutcome::Added) => {
+                    result.added += 1;
+                    true
+                }
+                Ok(ConcertSyncOutcome::Updated) => {
+                    result.updated += 1;
+                    true
+                }
+                Ok(ConcertSyncOutcome::VenueMissing(_)) => false,
+                Err(e) => {
+                    Logger::warn("KOPIS", &e);
+                    false
+                }
+            };
+
+            let update = if resolved {
+                KopisRepository::delete_pending_concert(pool, &concert.kopis_id).await
+            } else if concert.attempts + 1 >= MAX_PENDING_ATTEMPTS {
+                result.errors += 1;
+                Logger::warn(
+                    "KOPIS",
+                    &format!(
+                        "Giving up on pending concert {} (facility_id: {}) after {} attempts",
+                        concert.title, concert.venue_kopis_id, MAX_PENDING_ATTEMPTS
+                    ),
+                );
+                KopisRepository::delete_pending_concert(pool, &concert.kopis_id).await
+            } else {
+                result.errors += 1;
+                KopisRepository::increment_pending_attempts(pool, &concert.kopis_id).await
+            };
+
+            if let Err(e) = update {
+                Logger::warn(
+                    "KOPIS",
+                    &format!("Failed to update pending concert {}: {}", concert.kopis_id, e),
+                );
+            }
+        }
+
+        result
+    }
+
+    // ============================================
+    // 공연 대조 (취소/삭제 감지)
+    // ============================================
+
+    /// 저장된 KOPIS 공연을 KOPIS 공연 목록과 대조
+    /// 동기화 범위(장르, 조회 기간) 안인데 목록에서 사라진 공연은 상세를 다시 조회해
+    /// 취소된 공연은 cancelled, 상세도 없는 공연은 stale로 표시 (삭제하지 않음)
+    pub async fn reconcile_concerts(pool: &MySqlPool) -> Result<ReconcileResult, String> {
+        Logger::info("KOPIS", "Starting concert reconciliation");
+
+        let client = KopisClient::from_env()?;
+        let settings = KopisSyncSettings::load(pool).await;
+        let today = clock::today();
+
+        // 조회 기간 전체 목록 수집 (afterdate 없이)
+        // 목록이 하나라도 빠지면 살아있는 공연을 잘못 표시할 수 있으므로 중단
+        let mut listed = HashSet::new();
+        for genre_code in &settings.concert_genres {
+            for (window_start, window_end) in settings.listing_windows(today) {
+                let batch_start = window_start.format("%Y%m%d").to_string();
+                let batch_end = window_end.format("%Y%m%d").to_string();
+
+                let concerts = client
+                    .fetch_all_concerts(&batch_start, &batch_end, Some(genre_code), None)
                     .await
-                {
-                    Ok(response) => {
-                        // TOP 3만 UPSERT (1, 2, 3등 슬롯 업데이트)
-                        for item in response.boxof.iter().take(3) {
-                            // performance_id로 concert_id 찾기
-                            match ConcertRepository::get_by_kopis_id(pool, &item.performance_id)
-                                .await
-                            {
-                                Ok(Some(concert)) => {
-                                    match BoxofficeRepository::upsert_ranking(
-                                        pool,
-                                        concert.id,
-                                        Some(genre_code),
-                                        Some(&item.category),
-                                        *area_code,
-                                        Some(&item.area),
-                                        item.ranking,
-                                        None,
-                                        item.performance_count.unwrap_or(0),
-                                        Some(&item.venue_name),
-                                        item.seat_count,
-                                        &start_date,
-                                        &end_date,
-                                    )
-                                    .await
-                                    {
-                                        Ok(_) => {
-                                            added_count += 1;
-                                            Logger::success(
-                                                "KOPIS",
-                                                &format!(
-                                                    "Updated ranking #{} for concert: {}",
-                                                    item.ranking, item.performance_name
-                                                ),
-                                            );
-                                        }
-                                        Err(e) => {
-                                            Logger::error(
-                                                "KOPIS",
-                                                &format!(
-                                                    "Failed to upsert ranking for {}: {}",
-                                                    item.performance_name, e
-                                                ),
-                                            );
-                                            error_count += 1;
-                                        }
-                                    }
-                                }
-                                Ok(None) => {
-                                    Logger::warn(
-                                        "KOPIS",
-                                        &format!(
-                                            "Concert not found for ranking: {} (kopis_id: {})",
-                                            item.performance_name, item.performance_id
-                                        ),
-                                    );
-                                    error_count += 1;
-                                }
-                                Err(e) => {
-                                    Logger::error(
-                                        "KOPIS",
-                                        &format!(
-                                            "Failed to get concert by kopis_id {}: {}",
-                                            item.performance_id, e
-                                        ),
-                                    );
-                                    error_count += 1;
-                                }
+                    .map_err(|e| {
+                        format!(
+                            "Aborting reconciliation, failed to fetch concerts for {} ({} ~ {}): {}",
+                            genre_code, batch_start, batch_end, e
+                        )
+                    })?;
+
+                listed.extend(concerts.into_iter().map(|c| c.performance_id));
+            }
+        }
+
+        let genres: Vec<&str> = settings
+            .concert_genres
+            .iter()
+            .filter_map(|code| genre_name(code))
+            .collect();
+        let end_limit = today + Duration::days(settings.lookahead_days);
+
+        let candidates =
+            ConcertRepository::find_active_kopis_concerts(pool, &genres, today, end_limit)
+                .await
+                .map_err(|e| format!("Failed to load stored concerts: {}", e))?;
+
+        // 빈 목록은 API 장애일 가능성이 높음
+        if listed.is_empty() && !candidates.is_empty() {
+            return Err("Aborting reconciliation, KOPIS returned no concerts".to_string());
+        }
+
+        let mut result = ReconcileResult {
+            checked: candidates.len() as i32,
+            refreshed: 0,
+            cancelled: 0,
+            stale: 0,
+            errors: 0,
+        };
+
+        for (concert_id, kopis_id, title) in candidates {
+            if listed.contains(&kopis_id) {
+                continue;
+            }
+
+            let detail = match client.fetch_concert_detail(&kopis_id).await {
+                Ok(response) => response.db,
+                Err(e) => {
+                    Logger::warn(
+                        "KOPIS",
+                        &format!("Failed to fetch detail for concert {}: {}", kopis_id, e),
+                    );
+                    result.errors += 1;
+                    continue;
+                }
+            };
+
+            match detail {
+                // 목록에서만 빠진 공연 → 상세로 다시 저장 (취소 상태 포함)
+                Some(detail) => {
+                    let cancelled = detail.parse_status() == "cancelled";
+                    match Self::save_concert_detail(pool, &client, detail).await {
+                        Ok(ConcertSyncOutcome::Added) | Ok(ConcertSyncOutcome::Updated) => {
+                            if cancelled {
+                                Logger::warn("KOPIS", &format!("Concert cancelled: {}", title));
+                                result.cancelled += 1;
+                            } else {
+                                result.refreshed += 1;
                             }
                         }
+                        Ok(ConcertSyncOutcome::VenueMissing(facility_id)) => {
+                            Logger::warn(
+                                "KOPIS",
+                                &format!(
+                                    "Venue not found for concert {} (facility_id: {})",
+                                    title, facility_id
+                                ),
+                            );
+                            result.errors += 1;
+                        }
+                        Err(e) => {
+                            Logger::warn("KOPIS", &e);
+                            result.errors += 1;
+                        }
+                    }
+                }
+                // KOPIS에서 삭제된 공연
+                None => match ConcertRepository::update_status(pool, concert_id, "stale").await {
+                    Ok(_) => {
+                        Logger::warn(
+                            "KOPIS",
+                            &format!("Concert no longer in KOPIS, marked stale: {}", title),
+                        );
+                        result.stale += 1;
                     }
                     Err(e) => {
                         Logger::error(
                             "KOPIS",
-                            &format!(
-                                "Failed to fetch boxoffice rankings for {}, {}: {}",
-                                genre_code, area_display, e
-                            ),
+                            &format!("Failed to mark concert {} stale: {}", kopis_id, e),
                         );
-                        error_count += 1;
+                        result.errors += 1;
                     }
-                }
+                },
             }
         }
 
-        let result = SyncResult {
-            added: added_count,
+        Logger::success(
+            "KOPIS",
+            &format!(
+                "Concert reconciliation completed: {} checked, {} refreshed, {} cancelled, {} stale, {} errors",
+                result.checked, result.refreshed, result.cancelled, result.stale, result.errors
+            ),
+        );
+
+        Ok(result)
+    }
+
+    /// KOPIS API에서 예매상황판 순위 동기화
+    /// 설정된 장르/지역/집계 기간/좌석규모(KopisSyncSettings)별로
+    /// 전체 순위는 이력에, 공연의 순위는 순위 슬롯에 저장
+    /// DB에 없는 순위 공연은 상세 조회 후 공연장까지 가져와 저장
+    pub async fn sync_boxoffice_rankings(pool: &MySqlPool) -> Result<SyncResult, String> {
+        Logger::info("KOPIS", "Starting boxoffice rankings synchronization");
+
+        // 동기화 시작 기록
+        Self::update_sync_status(pool, "boxoffice", "in_progress", None, None, None)
+            .await
+            .map_err(|e| format!("Failed to update sync status: {}", e))?;
+
+        // KOPIS 클라이언트 생성
+        let client = KopisClient::from_env()?;
+
+        // 동기화 범위 설정 (장르, 지역, 집계 기간, 좌석규모)
+        let settings = KopisSyncSettings::load(pool).await;
+        let today = clock::today();
+
+        let mut result = SyncResult {
+            added: 0,
             updated: 0,
-            errors: error_count,
+            errors: 0,
         };
 
+        // 이번 실행에서 가져오지 못한 순위 공연 (다른 범위에서 재조회하지 않음)
+        let mut unresolved = HashSet::new();
+
+        for genre_code in &settings.boxoffice_genres {
+            for area in &settings.areas {
+                for period in &settings.boxoffice_periods {
+                    for seat_scale in &settings.boxoffice_seat_scales {
+                        let scope = RankingScope {
+                            genre_code,
+                            area_code: area,
+                            period,
+                            seat_scale,
+                        };
+                        let (added, errors) = Self::sync_boxoffice_scope(
+                            pool,
+                            &client,
+                            &scope,
+                            today,
+                            &mut unresolved,
+                        )
+                        .await;
+                        result.added += added;
+                        result.errors += errors;
+                    }
+                }
+            }
+        }
+
         // 동기화 완료 기록
-        let today = Utc::now().date_naive();
         Self::update_sync_status(
             pool,
             "boxoffice",
             "success",
-            Some(today),
-            Some(added_count),
+            Some(clock::today()),
+            Some(result.added),
             Some(0),
         )
         .await?;
@@ -828,12 +1000,250 @@ impl KopisService {
             "KOPIS",
             &format!(
                 "Boxoffice sync completed: {} rankings added, {} errors",
-                added_count, error_count
+                result.added, result.errors
             ),
         );
 
         Ok(result)
     }
+
+    /// 장르/지역/집계 기간/좌석규모 하나의 순위 동기화 → (저장한 순위 수, 에러 수)
+    async fn sync_boxoffice_scope(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        scope: &RankingScope<'_>,
+        today: NaiveDate,
+        unresolved: &mut HashSet<String>,
+    ) -> (i32, i32) {
+        let (start, end) = boxoffice_period_range(scope.period, today);
+        let start_date = start.format("%Y%m%d").to_string();
+        let end_date = end.format("%Y%m%d").to_string();
+
+        // "00"(전국), "0"(전체 좌석규모)은 필터 없이 조회
+        let area_code = Some(scope.area_code).filter(|code| *code != NATIONWIDE_AREA_CODE);
+        let seat_scale = Some(scope.seat_scale).filter(|code| *code != ALL_SEAT_SCALE);
+        let description = format!(
+            "{}, {}, {}, {}",
+            describe_genre(scope.genre_code),
+            describe_area(scope.area_code),
+            scope.period,
+            describe_seat_scale(scope.seat_scale)
+        );
+
+        Logger::info(
+            "KOPIS",
+            &format!("Fetching boxoffice rankings for {}", description),
+        );
+
+        let response = match client
+            .fetch_boxoffice_rankings(
+                &start_date,
+                &end_date,
+                Some(scope.genre_code),
+                area_code,
+                Some(scope.period),
+                seat_scale,
+            )
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                Logger::error(
+                    "KOPIS",
+                    &format!("Failed to fetch boxoffice rankings for {}: {}", description, e),
+                );
+                return (0, 1);
+            }
+        };
+
+        let mut added = 0;
+        let mut errors = 0;
+
+        // 전체 순위는 이력으로 보관
+        let snapshot: Vec<NewBoxofficeSnapshot> = response
+            .boxof
+            .iter()
+            .map(|item| NewBoxofficeSnapshot {
+                ranking: item.ranking,
+                kopis_id: item.performance_id.clone(),
+                performance_name: item.performance_name.clone(),
+                venue_name: Some(item.venue_name.clone()),
+                seat_count: item.seat_count,
+                performance_count: item.performance_count,
+            })
+            .collect();
+
+        if let Err(e) = BoxofficeRepository::replace_snapshot(pool, today, scope, &snapshot).await {
+            Logger::error(
+                "KOPIS",
+                &format!("Failed to save boxoffice snapshot for {}: {}", description, e),
+            );
+            errors += 1;
+        }
+
+        // 이전 순위를 지우고 새 순위로 교체
+        if let Err(e) = BoxofficeRepository::delete_rankings_for_scope(pool, scope).await {
+            Logger::error(
+                "KOPIS",
+                &format!("Failed to clear boxoffice rankings for {}: {}", description, e),
+            );
+            return (added, errors + 1);
+        }
+
+        for item in &response.boxof {
+            // performance_id로 concert_id 찾기, 없으면 KOPIS에서 가져오기
+            let concert_id = match ConcertRepository::get_by_kopis_id(pool, &item.performance_id)
+                .await
+            {
+                Ok(Some(concert)) => concert.id,
+                Ok(None) => match Self::import_ranked_concert(pool, client, item, unresolved).await
+                {
+                    Some(concert_id) => concert_id,
+                    None => {
+                        // TOP 3(주목 공연 후보)만 에러로 집계
+                        if item.ranking <= 3 {
+                            Logger::warn(
+                                "KOPIS",
+                                &format!(
+                                    "Concert not found for ranking: {} (kopis_id: {})",
+                                    item.performance_name, item.performance_id
+                                ),
+                            );
+                            errors += 1;
+                        }
+                        continue;
+                    }
+                },
+                Err(e) => {
+                    Logger::error(
+                        "KOPIS",
+                        &format!(
+                            "Failed to get concert by kopis_id {}: {}",
+                            item.performance_id, e
+                        ),
+                    );
+                    errors += 1;
+                    continue;
+                }
+            };
+
+            match BoxofficeRepository::upsert_ranking(
+                pool,
+                concert_id,
+                scope,
+                Some(&item.category),
+                Some(&item.area),
+                item.ranking,
+                item.performance_count.unwrap_or(0),
+                Some(&item.venue_name),
+                item.seat_count,
+                &start_date,
+                &end_date,
+            )
+            .await
+            {
+                Ok(_) => {
+                    added += 1;
+                    Logger::debug(
+                        "KOPIS",
+                        &format!(
+                            "Updated ranking #{} for concert: {}",
+                            item.ranking, item.performance_name
+                        ),
+                    );
+                }
+                Err(e) => {
+                    Logger::error(
+                        "KOPIS",
+                        &format!(
+                            "Failed to upsert ranking for {}: {}",
+                            item.performance_name, e
+                        ),
+                    );
+                    errors += 1;
+                }
+            }
+        }
+
+        Logger::info(
+            "KOPIS",
+            &format!("Saved {} rankings for {} ({} errors)", added, description, errors),
+        );
+        (added, errors)
+    }
+
+    /// 순위에 올랐지만 DB에 없는 공연을 공연 동기화 경로로 가져오기 → 저장된 concert_id
+    /// 가져오지 못한 공연은 이번 실행 동안 다시 조회하지 않음
+    async fn import_ranked_concert(
+        pool: &MySqlPool,
+        client: &KopisClient,
+        item: &BoxofficeItem,
+        unresolved: &mut HashSet<String>,
+    ) -> Option<i32> {
+        if unresolved.contains(&item.performance_id) {
+            return None;
+        }
+
+        let imported = match Self::sync_concert(pool, client, &item.performance_id).await {
+            Ok(ConcertSyncOutcome::Added) | Ok(ConcertSyncOutcome::Updated) => {
+                match ConcertRepository::get_by_kopis_id(pool, &item.performance_id).await {
+                    Ok(concert) => concert.map(|concert| concert.id),
+                    Err(e) => {
+                        Logger::error(
+                            "KOPIS",
+                            &format!(
+                                "Failed to get concert by kopis_id {}: {}",
+                                item.performance_id, e
+                            ),
+                        );
+                        None
+                    }
+                }
+            }
+            Ok(ConcertSyncOutcome::VenueMissing(facility_id)) => {
+                Self::queue_pending_concert(
+                    pool,
+                    &item.performance_id,
+                    &item.performance_name,
+                    &facility_id,
+                )
+                .await;
+                None
+            }
+            Err(e) => {
+                Logger::warn("KOPIS", &e);
+                None
+            }
+        };
+
+        match imported {
+            Some(_) => Logger::info(
+                "KOPIS",
+                &format!(
+                    "Imported ranked concert: {} (kopis_id: {})",
+                    item.performance_name, item.performance_id
+                ),
+            ),
+            None => {
+                unresolved.insert(item.performance_id.clone());
+            }
+        }
+
+        imported
+    }
+}
+
+enum ConcertSyncOutcome {
+    Added,
+    Updated,
+    /// 공연장을 찾지 못함 (KOPIS 공연시설ID)
+    VenueMissing(String),
+}
+
+struct ImportedVenue {
+    id: i32,
+    existed: bool,
+    hall_errors: i32,
 }
 
 #[derive(Debug)]
@@ -842,3 +1252,26 @@ pub struct SyncResult {
     pub updated: i32,
     pub errors: i32,
 }
+
+#[derive(Debug)]
+pub struct ReconcileResult {
+    /// 대조 대상 공연 수
+    pub checked: i32,
+    /// 목록에서 빠졌지만 상세가 남아 있어 다시 저장한 공연
+    pub refreshed: i32,
+    pub cancelled: i32,
+    pub stale: i32,
+    pub errors: i32,
+}
+
+/// 예매상황판 집계 기간별 조회 구간 (어제 기준, 당일 집계는 미완료)
+fn boxoffice_period_range(period: &str, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let end = today - Duration::days(1);
+    let days = match period {
+        "day" => 1,
+        "week" => 7,
+        _ => 30,
+    };
+
+    (end - Duration::days(days - 1), end)
+}
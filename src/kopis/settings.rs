@@ -0,0 +1,239 @@
+use super::codes::{self, ALL_SEAT_SCALE, AREA_CODES, BOXOFFICE_PERIODS, GENRE_CODES, SEAT_SCALES};
+use crate::settings::{self, parse_in_range, split_list, validate_range, KeyValueSettings};
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use sqlx::MySqlPool;
+
+// 설정 키 (kopis_sync_settings.setting_key)
+const KEY_CONCERT_GENRES: &str = "concert_genres";
+const KEY_BOXOFFICE_GENRES: &str = "boxoffice_genres";
+const KEY_AREAS: &str = "areas";
+const KEY_BOXOFFICE_PERIODS: &str = "boxoffice_periods";
+const KEY_BOXOFFICE_SEAT_SCALES: &str = "boxoffice_seat_scales";
+const KEY_LOOKAHEAD_DAYS: &str = "lookahead_days";
+const KEY_BATCH_DAYS: &str = "batch_days";
+
+/// KOPIS API 조회 기간 최대치 (stdate ~ eddate 최대 31일)
+const MAX_BATCH_DAYS: i64 = 31;
+const MAX_LOOKAHEAD_DAYS: i64 = 730;
+
+/// KOPIS 동기화 범위 설정 (kopis_sync_settings, KOPIS_* 환경변수)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KopisSyncSettings {
+    /// 공연 동기화 대상 장르 코드
+    pub concert_genres: Vec<String>,
+    /// 예매상황판 동기화 대상 장르 코드
+    pub boxoffice_genres: Vec<String>,
+    /// 예매상황판 동기화 대상 지역 코드 ("00" = 전국)
+    pub areas: Vec<String>,
+    /// 예매상황판 집계 기간 (day, week, month)
+    pub boxoffice_periods: Vec<String>,
+    /// 예매상황판 좌석규모 코드 ("0" = 전체)
+    pub boxoffice_seat_scales: Vec<String>,
+    /// 공연 조회 기간 (오늘부터 N일 후까지)
+    pub lookahead_days: i64,
+    /// 공연 목록 1회 조회 기간 (일, 최대 31)
+    pub batch_days: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateKopisSyncSettings {
+    pub concert_genres: Option<Vec<String>>,
+    pub boxoffice_genres: Option<Vec<String>>,
+    pub areas: Option<Vec<String>>,
+    pub boxoffice_periods: Option<Vec<String>>,
+    pub boxoffice_seat_scales: Option<Vec<String>>,
+    pub lookahead_days: Option<i64>,
+    pub batch_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KopisCode {
+    pub code: String,
+    pub name: String,
+}
+
+/// 관리자 화면용 응답 (현재 설정 + 선택 가능한 코드 목록)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KopisSyncSettingsResponse {
+    pub settings: KopisSyncSettings,
+    pub genre_codes: Vec<KopisCode>,
+    pub area_codes: Vec<KopisCode>,
+    pub period_codes: Vec<KopisCode>,
+    pub seat_scale_codes: Vec<KopisCode>,
+}
+
+impl Default for KopisSyncSettings {
+    fn default() -> Self {
+        Self {
+            concert_genres: vec![
+                "CCCA".to_string(),
+                "GGGA".to_string(),
+                "CCCC".to_string(),
+                "BBBC".to_string(),
+            ],
+            boxoffice_genres: vec!["CCCA".to_string()],
+            areas: AREA_CODES.iter().map(|(code, _)| code.to_string()).collect(),
+            boxoffice_periods: BOXOFFICE_PERIODS
+                .iter()
+                .map(|(code, _)| code.to_string())
+                .collect(),
+            boxoffice_seat_scales: vec![ALL_SEAT_SCALE.to_string()],
+            lookahead_days: 365,
+            batch_days: MAX_BATCH_DAYS,
+        }
+    }
+}
+
+impl KeyValueSettings for KopisSyncSettings {
+    const TABLE: &'static str = "kopis_sync_settings";
+    const LOG_MODULE: &'static str = "KOPIS";
+    const ENV_KEYS: &'static [(&'static str, &'static str)] = &[
+        ("KOPIS_CONCERT_GENRES", KEY_CONCERT_GENRES),
+        ("KOPIS_BOXOFFICE_GENRES", KEY_BOXOFFICE_GENRES),
+        ("KOPIS_AREAS", KEY_AREAS),
+        ("KOPIS_BOXOFFICE_PERIODS", KEY_BOXOFFICE_PERIODS),
+        ("KOPIS_BOXOFFICE_SEAT_SCALES", KEY_BOXOFFICE_SEAT_SCALES),
+        ("KOPIS_LOOKAHEAD_DAYS", KEY_LOOKAHEAD_DAYS),
+        ("KOPIS_BATCH_DAYS", KEY_BATCH_DAYS),
+    ];
+
+    fn apply(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            KEY_CONCERT_GENRES => self.concert_genres = parse_codes(value, GENRE_CODES)?,
+            KEY_BOXOFFICE_GENRES => self.boxoffice_genres = parse_codes(value, GENRE_CODES)?,
+            KEY_AREAS => self.areas = parse_codes(value, AREA_CODES)?,
+            KEY_BOXOFFICE_PERIODS => {
+                self.boxoffice_periods = parse_codes(value, BOXOFFICE_PERIODS)?
+            }
+            KEY_BOXOFFICE_SEAT_SCALES => {
+                self.boxoffice_seat_scales = parse_codes(value, SEAT_SCALES)?
+            }
+            KEY_LOOKAHEAD_DAYS => {
+                self.lookahead_days = parse_in_range(value, 1, MAX_LOOKAHEAD_DAYS)?
+            }
+            KEY_BATCH_DAYS => self.batch_days = parse_in_range(value, 1, MAX_BATCH_DAYS)?,
+            _ => return Err(format!("Unknown setting key: {}", key)),
+        }
+        Ok(())
+    }
+}
+
+impl KopisSyncSettings {
+    /// 현재 유효한 설정 조회 (스케줄러 실행마다 호출)
+    pub async fn load(pool: &MySqlPool) -> Self {
+        settings::load(pool).await
+    }
+
+    /// 관리자 설정 저장 후 갱신된 설정 반환
+    pub async fn save(
+        pool: &MySqlPool,
+        update: UpdateKopisSyncSettings,
+    ) -> Result<Self, String> {
+        let mut values: Vec<(&str, String)> = Vec::new();
+
+        if let Some(genres) = update.concert_genres {
+            values.push((KEY_CONCERT_GENRES, validate_codes(&genres, GENRE_CODES)?));
+        }
+        if let Some(genres) = update.boxoffice_genres {
+            values.push((KEY_BOXOFFICE_GENRES, validate_codes(&genres, GENRE_CODES)?));
+        }
+        if let Some(areas) = update.areas {
+            values.push((KEY_AREAS, validate_codes(&areas, AREA_CODES)?));
+        }
+        if let Some(periods) = update.boxoffice_periods {
+            values.push((KEY_BOXOFFICE_PERIODS, validate_codes(&periods, BOXOFFICE_PERIODS)?));
+        }
+        if let Some(scales) = update.boxoffice_seat_scales {
+            values.push((KEY_BOXOFFICE_SEAT_SCALES, validate_codes(&scales, SEAT_SCALES)?));
+        }
+        if let Some(days) = update.lookahead_days {
+            values.push((KEY_LOOKAHEAD_DAYS, validate_range(days, 1, MAX_LOOKAHEAD_DAYS)?.to_string()));
+        }
+        if let Some(days) = update.batch_days {
+            values.push((KEY_BATCH_DAYS, validate_range(days, 1, MAX_BATCH_DAYS)?.to_string()));
+        }
+
+        settings::save::<Self>(pool, values).await?;
+        Ok(Self::load(pool).await)
+    }
+
+    pub fn response(self) -> KopisSyncSettingsResponse {
+        let to_codes = |table: &[(&str, &str)]| {
+            table
+                .iter()
+                .map(|(code, name)| KopisCode {
+                    code: code.to_string(),
+                    name: name.to_string(),
+                })
+                .collect()
+        };
+
+        KopisSyncSettingsResponse {
+            settings: self,
+            genre_codes: to_codes(GENRE_CODES),
+            area_codes: to_codes(AREA_CODES),
+            period_codes: to_codes(BOXOFFICE_PERIODS),
+            seat_scale_codes: to_codes(SEAT_SCALES),
+        }
+    }
+
+    /// 공연 목록 조회 구간 (오늘부터 lookahead_days 후까지, batch_days 단위)
+    /// KOPIS API 제한: 1회 최대 31일
+    pub fn listing_windows(&self, today: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let end_limit = today + Duration::days(self.lookahead_days);
+        let mut windows = Vec::new();
+        let mut current_start = today;
+
+        while current_start < end_limit {
+            let current_end = (current_start + Duration::days(self.batch_days - 1)).min(end_limit);
+            windows.push((current_start, current_end));
+            current_start = current_end + Duration::days(1);
+        }
+
+        windows
+    }
+}
+
+/// 쉼표로 구분된 코드 목록 파싱 (예: "CCCA, GGGA")
+fn parse_codes(value: &str, table: &[(&str, &str)]) -> Result<Vec<String>, String> {
+    let codes = split_list(value);
+    validate_codes(&codes, table)?;
+    Ok(codes)
+}
+
+/// 코드 목록 검증 후 저장용 문자열로 변환
+fn validate_codes(codes: &[String], table: &[(&str, &str)]) -> Result<String, String> {
+    if codes.is_empty() {
+        return Err("At least one code is required".to_string());
+    }
+
+    if let Some(unknown) = codes
+        .iter()
+        .find(|code| !table.iter().any(|(c, _)| *c == code.as_str()))
+    {
+        return Err(format!("Unknown KOPIS code: {}", unknown));
+    }
+
+    Ok(codes.join(","))
+}
+
+/// 로그 출력용 코드 표시 (예: "CCCA(서양음악(클래식))")
+pub fn describe_genre(code: &str) -> String {
+    match codes::genre_name(code) {
+        Some(name) => format!("{}({})", code, name),
+        None => code.to_string(),
+    }
+}
+
+pub fn describe_area(code: &str) -> String {
+    codes::area_name(code).unwrap_or(code).to_string()
+}
+
+pub fn describe_seat_scale(code: &str) -> String {
+    codes::seat_scale_name(code).unwrap_or(code).to_string()
+}
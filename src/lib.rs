@@ -5,12 +5,23 @@ extern crate rocket;
 pub mod artist;
 pub mod auth;
 pub mod boxoffice;
+pub mod clock;
+pub mod competition;
 pub mod composer;
 pub mod concert;
 pub mod db;
+pub mod featured;
+pub mod hall;
+pub mod kopis;
 pub mod logger;
+pub mod nullable;
 pub mod performance;
 pub mod performance_sector;
 pub mod piece;
+pub mod recommendation;
 pub mod recording;
+pub mod scheduler;
+pub mod search;
+pub mod settings;
 pub mod user;
+pub mod venue;
@@ -4,17 +4,25 @@ extern crate rocket;
 mod artist;
 mod auth;
 mod boxoffice;
+mod clock;
+mod competition;
 mod composer;
 mod concert;
 mod config;
 mod db;
+mod featured;
 mod hall;
 mod kopis;
 mod logger;
+mod nullable;
 mod performance;
 mod performance_sector;
 mod piece;
+mod recommendation;
 mod recording;
+mod scheduler;
+mod search;
+mod settings;
 mod user;
 mod venue;
 
@@ -36,13 +44,20 @@ async fn rocket() -> _ {
 
     Logger::success("DATABASE", "Connection pool created");
 
-    // KOPIS 공연장 동기화 스케줄러 시작
-    Logger::info("SCHEDULER", "Initializing KOPIS venue sync scheduler...");
-    kopis::VenueSyncScheduler::start(pool.clone()).await;
+    // 작업 스케줄러 시작 (KOPIS 공연장 → 공연 동기화 순서)
+    Logger::info("SCHEDULER", "Initializing job scheduler...");
+    let scheduler = scheduler::JobScheduler::new(pool.clone())
+        .register(kopis::jobs::venue_sync())
+        .register(kopis::jobs::concert_sync())
+        .register(kopis::jobs::concert_reconcile())
+        .register(concert::jobs::status_update())
+        .register(artist::jobs::stats_recompute())
+        .register(recommendation::jobs::similarity_recompute())
+        .start()
+        .await;
 
-    // KOPIS 공연 동기화 스케줄러 시작 (공연장 동기화 후)
-    Logger::info("SCHEDULER", "Initializing KOPIS concert sync scheduler...");
-    kopis::ConcertSyncScheduler::start(pool.clone()).await;
+    // 자동완성 인덱스 생성 (대상 테이블 변경 시 자동 갱신)
+    let suggest_index = search::SuggestIndex::start(pool.clone()).await;
 
     Logger::info("SERVER", "Mounting routes...");
 
@@ -69,11 +84,16 @@ async fn rocket() -> _ {
 
     rocket::build()
         .manage(pool)
+        .manage(scheduler)
+        .manage(suggest_index)
         .attach(cors)
         .mount("/", routes![config::favicon])
         .mount(
             "/api",
             routes![
+                // Search routes
+                search::search,
+                search::get_suggestions,
                 // Composer routes
                 composer::search_composers,
                 composer::get_composers,
@@ -81,38 +101,85 @@ async fn rocket() -> _ {
                 composer::create_composer,
                 composer::update_composer,
                 composer::delete_composer,
+                composer::set_composer_major_pieces,
+                composer::reorder_composer_major_pieces,
+                composer::delete_composer_major_piece,
+                composer::get_timeline,
+                composer::get_composer_relations,
+                composer::create_composer_relation,
+                composer::update_composer_relation,
+                composer::delete_composer_relation,
                 // Piece routes
                 piece::get_pieces,
+                piece::search_pieces,
+                piece::lookup_pieces,
                 piece::get_piece,
                 piece::get_pieces_by_composer,
                 piece::create_piece,
                 piece::update_piece,
                 piece::delete_piece,
+                piece::reparse_piece_catalog,
                 // Artist routes
                 artist::get_artists,
                 artist::get_artist,
+                artist::get_artist_profile,
+                recommendation::get_related_artists,
                 artist::get_artist_concerts,
                 artist::search_artists,
                 artist::create_artist,
                 artist::update_artist,
                 artist::delete_artist,
                 artist::create_artist_award,
+                artist::reorder_artist_awards,
+                artist::update_artist_award,
                 artist::delete_artist_award,
+                artist::search_awards,
+                artist::get_artist_members,
+                artist::get_artist_affiliations,
+                artist::create_artist_membership,
+                artist::update_artist_membership,
+                artist::delete_artist_membership,
+                // Competition routes
+                competition::get_competitions,
+                competition::get_competition,
+                competition::create_competition,
+                competition::update_competition,
+                competition::delete_competition,
+                competition::get_competition_laureates,
+                competition::get_edition_laureates,
+                competition::create_competition_result,
+                competition::cluster_competition_awards,
+                competition::get_pending_competition_results,
+                competition::confirm_competition_result,
+                competition::reject_competition_result,
                 // Concert routes
                 concert::get_concerts,
                 concert::get_concert,
-                concert::get_featured_concerts,
                 concert::get_upcoming_concerts,
                 concert::search_concerts,
+                concert::get_concert_facets,
                 concert::get_ticket_vendors,
                 concert::get_areas,
                 concert::create_concert,
                 concert::update_concert,
+                concert::set_concert_artists,
                 concert::delete_concert,
                 concert::submit_rating,
                 concert::get_user_rating,
+                // Featured routes
+                featured::get_featured_concerts,
+                featured::get_featured_slots,
+                featured::create_featured_slot,
+                featured::update_featured_slot,
+                featured::delete_featured_slot,
+                featured::get_featured_policy,
+                featured::update_featured_policy,
                 // Boxoffice routes
+                boxoffice::api::get_rankings,
                 boxoffice::api::get_top3,
+                boxoffice::api::get_history,
+                boxoffice::api::get_movers,
+                boxoffice::api::get_top_days,
                 // Performance Sector routes
                 performance_sector::get_sectors_by_piece,
                 performance_sector::get_sector,
@@ -152,6 +219,11 @@ async fn rocket() -> _ {
                 venue::search_venues,
                 // KOPIS routes
                 kopis::trigger_venue_sync,
+                kopis::get_sync_settings,
+                kopis::update_sync_settings,
+                // Scheduler routes
+                scheduler::get_jobs,
+                scheduler::update_job,
             ],
         )
 }
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Deserializer};
+
+/// 수정 요청에서 "필드 없음"과 "null"을 구분
+///
+/// 필드 없음 → None (변경 안 함), null → Some(None) (값 삭제), 값 → Some(Some(v))
+/// `#[serde(default, deserialize_with = "crate::nullable::deserialize")]`와 함께 사용
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
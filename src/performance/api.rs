@@ -4,6 +4,9 @@ use crate::db::DbPool;
 use crate::logger::Logger;
 use super::model::{Performance, CreatePerformance, UpdatePerformance};
 use super::service::PerformanceService;
+use crate::performance_sector::service::PerformanceSectorService;
+use crate::piece::hierarchy::{validate_movement, validate_sector_movement};
+use crate::piece::service::PieceService;
 
 #[get("/performances")]
 pub async fn get_performances(pool: &State<DbPool>) -> Result<Json<Vec<Performance>>, Status> {
@@ -66,7 +69,13 @@ pub async fn create_performance(
     performance: Json<CreatePerformance>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match PerformanceService::create_performance(pool, performance.into_inner()).await {
+    let performance = performance.into_inner();
+    // 지정하지 않으면 섹터의 악장을 따르므로 지정한 경우만 검증
+    if performance.movement_id.is_some() {
+        check_movement(pool, performance.movement_id, performance.piece_id, performance.sector_id).await?;
+    }
+
+    match PerformanceService::create_performance(pool, performance).await {
         Ok(id) => Ok(Json(id)),
         Err(e) => {
             Logger::error("API", &format!("Failed to create performance: {}", e));
@@ -82,7 +91,20 @@ pub async fn update_performance(
     performance: Json<UpdatePerformance>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match PerformanceService::update_performance(pool, id, performance.into_inner()).await {
+    let performance = performance.into_inner();
+    if let Some(movement_id) = performance.movement_id {
+        let current = match PerformanceService::get_performance(pool, id).await {
+            Ok(Some(current)) => current,
+            Ok(None) => return Err(Status::NotFound),
+            Err(e) => {
+                Logger::error("API", &format!("Failed to get performance {}: {}", id, e));
+                return Err(Status::InternalServerError);
+            }
+        };
+        check_movement(pool, movement_id, current.piece_id, current.sector_id).await?;
+    }
+
+    match PerformanceService::update_performance(pool, id, performance).await {
         Ok(rows) => Ok(Json(rows)),
         Err(e) => {
             Logger::error("API", &format!("Failed to update performance {}: {}", id, e));
@@ -104,4 +126,40 @@ pub async fn delete_performance(
             Err(Status::InternalServerError)
         }
     }
-}
\ No newline at end of file
+}
+
+/// 연주의 악장은 piece_id 작품의 악장이면서 섹터에 지정된 악장과 같아야 함
+async fn check_movement(
+    pool: &DbPool,
+    movement_id: Option<i32>,
+    piece_id: i32,
+    sector_id: i32,
+) -> Result<(), Status> {
+    if let Some(movement_id) = movement_id {
+        let movement = match PieceService::get_piece_by_id(pool, movement_id).await {
+            Ok(Some(movement)) => movement,
+            Ok(None) => return Err(Status::BadRequest),
+            Err(e) => {
+                Logger::error("API", &format!("Failed to get piece {}: {}", movement_id, e));
+                return Err(Status::InternalServerError);
+            }
+        };
+        if let Err(e) = validate_movement(&movement, piece_id) {
+            Logger::warn("API", &format!("Invalid performance movement: {}", e));
+            return Err(Status::BadRequest);
+        }
+    }
+
+    let sector = match PerformanceSectorService::get_sector(pool, sector_id).await {
+        Ok(Some(sector)) => sector,
+        Ok(None) => return Err(Status::BadRequest),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get sector {}: {}", sector_id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+    validate_sector_movement(movement_id, sector.movement_id).map_err(|e| {
+        Logger::warn("API", &format!("Invalid performance movement: {}", e));
+        Status::BadRequest
+    })
+}
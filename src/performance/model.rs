@@ -7,6 +7,8 @@ pub struct Performance {
     pub id: i32,
     pub sector_id: i32,
     pub piece_id: i32,
+    /// 악장 단위 연주 (piece_id 작품의 악장)
+    pub movement_id: Option<i32>,
     pub artist_id: i32,
     pub video_platform: String,
     pub video_id: String,
@@ -17,11 +19,22 @@ pub struct Performance {
     pub rating: f64,
 }
 
+/// 곡/작곡가 이름을 포함한 연주 영상 (아티스트 프로필용)
+#[derive(Debug, FromRow)]
+pub struct PerformanceWithPiece {
+    #[sqlx(flatten)]
+    pub performance: Performance,
+    pub piece_title: String,
+    pub composer_name: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePerformance {
     pub sector_id: i32,
     pub piece_id: i32, // 하위 호환성 유지
+    /// 없으면 섹터의 악장을 따름
+    pub movement_id: Option<i32>,
     pub artist_id: i32,
     pub video_platform: String,
     pub video_id: String,
@@ -40,4 +53,7 @@ pub struct UpdatePerformance {
     pub characteristic: Option<String>,
     pub view_count: Option<i32>,
     pub rating: Option<f64>,
+    /// null이면 악장 지정 해제
+    #[serde(default, deserialize_with = "crate::nullable::deserialize")]
+    pub movement_id: Option<Option<i32>>,
 }
@@ -1,4 +1,4 @@
-use super::model::{CreatePerformance, Performance, UpdatePerformance};
+use super::model::{CreatePerformance, Performance, PerformanceWithPiece, UpdatePerformance};
 use crate::db::DbPool;
 use sqlx::Error;
 
@@ -7,7 +7,7 @@ pub struct PerformanceRepository;
 impl PerformanceRepository {
     pub async fn find_all(pool: &DbPool) -> Result<Vec<Performance>, Error> {
         sqlx::query_as::<_, Performance>(
-            "SELECT id, sector_id, piece_id, artist_id, video_platform, video_id, start_time, end_time,
+            "SELECT id, sector_id, piece_id, movement_id, artist_id, video_platform, video_id, start_time, end_time,
              characteristic, view_count, CAST(rating AS DOUBLE) as rating
              FROM performances ORDER BY id DESC",
         )
@@ -17,7 +17,7 @@ impl PerformanceRepository {
 
     pub async fn find_by_id(pool: &DbPool, id: i32) -> Result<Option<Performance>, Error> {
         sqlx::query_as::<_, Performance>(
-            "SELECT id, sector_id, piece_id, artist_id, video_platform, video_id, start_time, end_time,
+            "SELECT id, sector_id, piece_id, movement_id, artist_id, video_platform, video_id, start_time, end_time,
              characteristic, view_count, CAST(rating AS DOUBLE) as rating
              FROM performances WHERE id = ?",
         )
@@ -28,7 +28,7 @@ impl PerformanceRepository {
 
     pub async fn find_by_sector(pool: &DbPool, sector_id: i32) -> Result<Vec<Performance>, Error> {
         sqlx::query_as::<_, Performance>(
-            "SELECT id, sector_id, piece_id, artist_id, video_platform, video_id, start_time, end_time,
+            "SELECT id, sector_id, piece_id, movement_id, artist_id, video_platform, video_id, start_time, end_time,
              characteristic, view_count, CAST(rating AS DOUBLE) as rating
              FROM performances WHERE sector_id = ? ORDER BY rating DESC",
         )
@@ -37,20 +37,22 @@ impl PerformanceRepository {
         .await
     }
 
+    /// 곡(또는 악장)의 연주 영상
     pub async fn find_by_piece(pool: &DbPool, piece_id: i32) -> Result<Vec<Performance>, Error> {
         sqlx::query_as::<_, Performance>(
-            "SELECT id, sector_id, piece_id, artist_id, video_platform, video_id, start_time, end_time,
+            "SELECT id, sector_id, piece_id, movement_id, artist_id, video_platform, video_id, start_time, end_time,
              characteristic, view_count, CAST(rating AS DOUBLE) as rating
-             FROM performances WHERE piece_id = ? ORDER BY rating DESC",
+             FROM performances WHERE piece_id = ? OR movement_id = ? ORDER BY rating DESC",
         )
         .bind(piece_id)
+        .bind(piece_id)
         .fetch_all(pool)
         .await
     }
 
     pub async fn find_by_artist(pool: &DbPool, artist_id: i32) -> Result<Vec<Performance>, Error> {
         sqlx::query_as::<_, Performance>(
-            "SELECT id, sector_id, piece_id, artist_id, video_platform, video_id, start_time, end_time,
+            "SELECT id, sector_id, piece_id, movement_id, artist_id, video_platform, video_id, start_time, end_time,
              characteristic, view_count, CAST(rating AS DOUBLE) as rating
              FROM performances WHERE artist_id = ? ORDER BY id DESC",
         )
@@ -59,14 +61,38 @@ impl PerformanceRepository {
         .await
     }
 
+    /// 아티스트의 평점 높은 연주 영상 (곡/작곡가 이름 포함)
+    pub async fn find_top_rated_by_artist(
+        pool: &DbPool,
+        artist_id: i32,
+        limit: i64,
+    ) -> Result<Vec<PerformanceWithPiece>, Error> {
+        sqlx::query_as::<_, PerformanceWithPiece>(
+            "SELECT p.id, p.sector_id, p.piece_id, p.movement_id, p.artist_id, p.video_platform, p.video_id, p.start_time, p.end_time,
+             p.characteristic, p.view_count, CAST(p.rating AS DOUBLE) as rating,
+             pc.title as piece_title, cp.name as composer_name
+             FROM performances p
+             JOIN pieces pc ON p.piece_id = pc.id
+             LEFT JOIN composers cp ON pc.composer_id = cp.id
+             WHERE p.artist_id = ?
+             ORDER BY p.rating DESC, p.view_count DESC, p.id DESC
+             LIMIT ?",
+        )
+        .bind(artist_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn create(pool: &DbPool, performance: CreatePerformance) -> Result<u64, Error> {
         let result = sqlx::query(
-            "INSERT INTO performances (sector_id, piece_id, artist_id, video_platform, video_id,
+            "INSERT INTO performances (sector_id, piece_id, movement_id, artist_id, video_platform, video_id,
              start_time, end_time, characteristic, view_count, rating)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, 0, 0.0)",
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 0, 0.0)",
         )
         .bind(performance.sector_id)
         .bind(performance.piece_id)
+        .bind(performance.movement_id)
         .bind(performance.artist_id)
         .bind(performance.video_platform)
         .bind(performance.video_id)
@@ -92,7 +118,7 @@ impl PerformanceRepository {
 
         let result = sqlx::query(
             "UPDATE performances SET video_platform = ?, video_id = ?, start_time = ?, 
-             end_time = ?, characteristic = ?, view_count = ?, rating = ? 
+             end_time = ?, characteristic = ?, view_count = ?, rating = ?, movement_id = ? 
              WHERE id = ?",
         )
         .bind(performance.video_platform.unwrap_or(current.video_platform))
@@ -102,6 +128,7 @@ impl PerformanceRepository {
         .bind(performance.characteristic.or(current.characteristic))
         .bind(performance.view_count.unwrap_or(current.view_count))
         .bind(performance.rating.unwrap_or(current.rating))
+        .bind(performance.movement_id.unwrap_or(current.movement_id))
         .bind(id)
         .execute(pool)
         .await?;
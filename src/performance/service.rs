@@ -1,6 +1,7 @@
 use crate::db::DbPool;
 use super::model::{Performance, CreatePerformance, UpdatePerformance};
 use super::repository::PerformanceRepository;
+use crate::performance_sector::repository::PerformanceSectorRepository;
 
 pub struct PerformanceService;
 
@@ -35,7 +36,15 @@ impl PerformanceService {
             .map_err(|e| e.to_string())
     }
 
-    pub async fn create_performance(pool: &DbPool, performance: CreatePerformance) -> Result<u64, String> {
+    /// 악장을 지정하지 않으면 섹터의 악장을 따름
+    pub async fn create_performance(pool: &DbPool, mut performance: CreatePerformance) -> Result<u64, String> {
+        if performance.movement_id.is_none() {
+            performance.movement_id = PerformanceSectorRepository::find_by_id(pool, performance.sector_id)
+                .await
+                .map_err(|e| e.to_string())?
+                .and_then(|sector| sector.movement_id);
+        }
+
         PerformanceRepository::create(pool, performance)
             .await
             .map_err(|e| e.to_string())
@@ -3,6 +3,8 @@ use rocket::serde::json::Json;
 use rocket::State;
 
 use crate::auth::ModeratorUser;
+use crate::piece::hierarchy::validate_movement;
+use crate::piece::service::PieceService;
 
 use super::model::{CreatePerformanceSector, PerformanceSector, PerformanceSectorWithCount, UpdatePerformanceSector};
 use super::repository::DbPool;
@@ -48,7 +50,12 @@ pub async fn create_sector(
     sector: Json<CreatePerformanceSector>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match PerformanceSectorService::create_sector(pool, sector.into_inner()).await {
+    let sector = sector.into_inner();
+    if let Some(movement_id) = sector.movement_id {
+        check_movement(pool, movement_id, sector.piece_id).await?;
+    }
+
+    match PerformanceSectorService::create_sector(pool, sector).await {
         Ok(id) => Ok(Json(id)),
         Err(e) => {
             eprintln!("Failed to create sector: {:?}", e);
@@ -66,7 +73,20 @@ pub async fn update_sector(
     sector: Json<UpdatePerformanceSector>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match PerformanceSectorService::update_sector(pool, id, sector.into_inner()).await {
+    let sector = sector.into_inner();
+    if let Some(Some(movement_id)) = sector.movement_id {
+        let existing = match PerformanceSectorService::get_sector(pool, id).await {
+            Ok(Some(existing)) => existing,
+            Ok(None) => return Err(Status::NotFound),
+            Err(e) => {
+                eprintln!("Failed to get sector: {:?}", e);
+                return Err(Status::InternalServerError);
+            }
+        };
+        check_movement(pool, movement_id, existing.piece_id).await?;
+    }
+
+    match PerformanceSectorService::update_sector(pool, id, sector).await {
         Ok(rows) => {
             if rows == 0 {
                 Err(Status::NotFound)
@@ -104,3 +124,20 @@ pub async fn delete_sector(
         }
     }
 }
+
+/// 섹터의 악장은 같은 곡의 악장이어야 함
+async fn check_movement(pool: &DbPool, movement_id: i32, piece_id: i32) -> Result<(), Status> {
+    let movement = match PieceService::get_piece_by_id(pool, movement_id).await {
+        Ok(Some(movement)) => movement,
+        Ok(None) => return Err(Status::BadRequest),
+        Err(e) => {
+            eprintln!("Failed to get piece: {:?}", e);
+            return Err(Status::InternalServerError);
+        }
+    };
+
+    validate_movement(&movement, piece_id).map_err(|e| {
+        eprintln!("Invalid sector movement: {}", e);
+        Status::BadRequest
+    })
+}
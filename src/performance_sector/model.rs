@@ -6,6 +6,8 @@ use sqlx::FromRow;
 pub struct PerformanceSector {
     pub id: i32,
     pub piece_id: i32,
+    /// 섹터가 다루는 악장 (piece_id의 하위 악장)
+    pub movement_id: Option<i32>,
     pub sector_name: String,
     pub description: Option<String>,
     pub display_order: Option<i32>,
@@ -15,6 +17,7 @@ pub struct PerformanceSector {
 #[serde(rename_all = "camelCase")]
 pub struct CreatePerformanceSector {
     pub piece_id: i32,
+    pub movement_id: Option<i32>,
     pub sector_name: String,
     pub description: Option<String>,
     pub display_order: Option<i32>,
@@ -23,6 +26,9 @@ pub struct CreatePerformanceSector {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePerformanceSector {
+    /// null이면 악장 지정 해제
+    #[serde(default, deserialize_with = "crate::nullable::deserialize")]
+    pub movement_id: Option<Option<i32>>,
     pub sector_name: Option<String>,
     pub description: Option<String>,
     pub display_order: Option<i32>,
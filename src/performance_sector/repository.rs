@@ -15,7 +15,7 @@ impl PerformanceSectorRepository {
         sqlx::query_as!(
             PerformanceSector,
             r#"
-            SELECT id, piece_id, sector_name, description, display_order
+            SELECT id, piece_id, movement_id, sector_name, description, display_order
             FROM performance_sectors
             WHERE piece_id = ?
             ORDER BY display_order ASC, id ASC
@@ -34,6 +34,7 @@ impl PerformanceSectorRepository {
         struct SectorWithCount {
             id: i32,
             piece_id: i32,
+            movement_id: Option<i32>,
             sector_name: String,
             description: Option<String>,
             display_order: Option<i32>,
@@ -45,6 +46,7 @@ impl PerformanceSectorRepository {
             SELECT
                 ps.id,
                 ps.piece_id,
+                ps.movement_id,
                 ps.sector_name,
                 ps.description,
                 ps.display_order,
@@ -52,7 +54,7 @@ impl PerformanceSectorRepository {
             FROM performance_sectors ps
             LEFT JOIN performances p ON ps.id = p.sector_id
             WHERE ps.piece_id = ?
-            GROUP BY ps.id, ps.piece_id, ps.sector_name, ps.description, ps.display_order
+            GROUP BY ps.id, ps.piece_id, ps.movement_id, ps.sector_name, ps.description, ps.display_order
             ORDER BY ps.display_order ASC, ps.id ASC
             "#,
             piece_id
@@ -67,6 +69,7 @@ impl PerformanceSectorRepository {
                     PerformanceSector {
                         id: r.id,
                         piece_id: r.piece_id,
+                        movement_id: r.movement_id,
                         sector_name: r.sector_name,
                         description: r.description,
                         display_order: r.display_order,
@@ -82,7 +85,7 @@ impl PerformanceSectorRepository {
         sqlx::query_as!(
             PerformanceSector,
             r#"
-            SELECT id, piece_id, sector_name, description, display_order
+            SELECT id, piece_id, movement_id, sector_name, description, display_order
             FROM performance_sectors
             WHERE id = ?
             "#,
@@ -98,10 +101,11 @@ impl PerformanceSectorRepository {
 
         let result = sqlx::query!(
             r#"
-            INSERT INTO performance_sectors (piece_id, sector_name, description, display_order)
-            VALUES (?, ?, ?, ?)
+            INSERT INTO performance_sectors (piece_id, movement_id, sector_name, description, display_order)
+            VALUES (?, ?, ?, ?, ?)
             "#,
             sector.piece_id,
+            sector.movement_id,
             sector.sector_name,
             sector.description,
             display_order
@@ -128,6 +132,7 @@ impl PerformanceSectorRepository {
         let existing = existing.unwrap();
 
         // 변경되지 않은 필드는 기존 값 유지
+        let movement_id = sector.movement_id.unwrap_or(existing.movement_id);
         let sector_name = sector.sector_name.unwrap_or(existing.sector_name);
         let description = sector.description.or(existing.description);
         let display_order = Some(sector.display_order).unwrap_or(existing.display_order);
@@ -135,9 +140,10 @@ impl PerformanceSectorRepository {
         let result = sqlx::query!(
             r#"
             UPDATE performance_sectors
-            SET sector_name = ?, description = ?, display_order = ?
+            SET movement_id = ?, sector_name = ?, description = ?, display_order = ?
             WHERE id = ?
             "#,
+            movement_id,
             sector_name,
             description,
             display_order,
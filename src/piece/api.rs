@@ -2,7 +2,9 @@ use rocket::{State, serde::json::Json, http::Status};
 use crate::auth::ModeratorUser;
 use crate::db::DbPool;
 use crate::logger::Logger;
-use super::model::{Piece, CreatePiece, UpdatePiece};
+use super::catalog::normalize_system;
+use super::hierarchy::{normalize_piece_type, validate_parent};
+use super::model::{Piece, CreatePiece, CreatedPiece, PieceDetail, UpdatePiece};
 use super::service::PieceService;
 
 #[get("/pieces")]
@@ -16,9 +18,50 @@ pub async fn get_pieces(pool: &State<DbPool>) -> Result<Json<Vec<Piece>>, Status
     }
 }
 
+#[get("/pieces/search?<q>&<composer_id>&<offset>&<limit>")]
+pub async fn search_pieces(
+    pool: &State<DbPool>,
+    q: Option<String>,
+    composer_id: Option<i32>,
+    offset: Option<i64>,
+    limit: Option<i64>,
+) -> Result<Json<Vec<Piece>>, Status> {
+    match PieceService::search_pieces(pool, q, composer_id, offset, limit).await {
+        Ok(pieces) => Ok(Json(pieces)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to search pieces: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 작품 번호로 곡 찾기 (예: /pieces/lookup?catalog=BWV&number=988, /pieces/lookup?catalog=Op&number=9&sub=2)
+#[get("/pieces/lookup?<catalog>&<number>&<sub>&<composer_id>")]
+pub async fn lookup_pieces(
+    pool: &State<DbPool>,
+    catalog: String,
+    number: i32,
+    sub: Option<i32>,
+    composer_id: Option<i32>,
+) -> Result<Json<Vec<Piece>>, Status> {
+    let Some(system) = normalize_system(&catalog) else {
+        Logger::warn("API", &format!("Unknown catalogue: {}", catalog));
+        return Err(Status::BadRequest);
+    };
+
+    match PieceService::lookup_pieces(pool, system, number, sub, composer_id).await {
+        Ok(pieces) => Ok(Json(pieces)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to look up {} {}: {}", system, number, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 곡 상세 (children: 하위 곡 트리, ancestors: 최상위 곡부터 상위 곡)
 #[get("/pieces/<id>")]
-pub async fn get_piece(pool: &State<DbPool>, id: i32) -> Result<Json<Option<Piece>>, Status> {
-    match PieceService::get_piece_by_id(pool, id).await {
+pub async fn get_piece(pool: &State<DbPool>, id: i32) -> Result<Json<Option<PieceDetail>>, Status> {
+    match PieceService::get_piece_detail(pool, id).await {
         Ok(piece) => Ok(Json(piece)),
         Err(e) => {
             Logger::error("API", &format!("Failed to get piece {}: {}", id, e));
@@ -38,14 +81,29 @@ pub async fn get_pieces_by_composer(pool: &State<DbPool>, composer_id: i32) -> R
     }
 }
 
+/// 같은 작곡가의 곡 중 작품 번호가 겹치거나 제목이 같은 곡이 있으면 duplicates로 알려줌
 #[post("/pieces", data = "<piece>")]
 pub async fn create_piece(
     pool: &State<DbPool>,
     piece: Json<CreatePiece>,
     _moderator: ModeratorUser,
-) -> Result<Json<i32>, Status> {
-    match PieceService::create_piece(pool, piece.into_inner()).await {
-        Ok(id) => Ok(Json(id)),
+) -> Result<Json<CreatedPiece>, Status> {
+    let mut piece = piece.into_inner();
+    let Some(piece_type) = normalize_piece_type(&piece.r#type) else {
+        Logger::warn("API", &format!("Unknown piece type: {}", piece.r#type));
+        return Err(Status::BadRequest);
+    };
+    piece.r#type = piece_type.to_string();
+    check_parent(pool, piece_type, piece.composer_id, piece.parent_id).await?;
+
+    match PieceService::create_piece(pool, piece).await {
+        Ok(created) => {
+            if !created.duplicates.is_empty() {
+                let ids: Vec<i32> = created.duplicates.iter().map(|p| p.id).collect();
+                Logger::warn("API", &format!("Piece {} may duplicate pieces {:?}", created.id, ids));
+            }
+            Ok(Json(created))
+        }
         Err(e) => {
             Logger::error("API", &format!("Failed to create piece: {}", e));
             Err(Status::InternalServerError)
@@ -60,7 +118,46 @@ pub async fn update_piece(
     piece: Json<UpdatePiece>,
     _moderator: ModeratorUser,
 ) -> Result<Json<u64>, Status> {
-    match PieceService::update_piece(pool, id, piece.into_inner()).await {
+    let mut piece = piece.into_inner();
+    let current = match PieceService::get_piece_by_id(pool, id).await {
+        Ok(Some(current)) => current,
+        Ok(None) => return Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get piece {}: {}", id, e));
+            return Err(Status::InternalServerError);
+        }
+    };
+
+    let piece_type = match piece.r#type.as_deref() {
+        Some(requested) => {
+            let Some(piece_type) = normalize_piece_type(requested) else {
+                Logger::warn("API", &format!("Unknown piece type: {}", requested));
+                return Err(Status::BadRequest);
+            };
+            piece.r#type = Some(piece_type.to_string());
+            piece_type
+        }
+        None => normalize_piece_type(&current.r#type).unwrap_or(&current.r#type),
+    };
+    // 하위 곡이 있으면 단위를 바꿀 수 없음 (악장 → 작품 → 모음집 관계가 깨짐)
+    if piece_type != current.r#type {
+        match PieceService::count_children(pool, id).await {
+            Ok(0) => {}
+            Ok(_) => {
+                Logger::warn("API", &format!("Piece {} has children; cannot change type to {}", id, piece_type));
+                return Err(Status::BadRequest);
+            }
+            Err(e) => {
+                Logger::error("API", &format!("Failed to count children of piece {}: {}", id, e));
+                return Err(Status::InternalServerError);
+            }
+        }
+    }
+    if piece.r#type.is_some() || piece.parent_id.is_some() {
+        check_parent(pool, piece_type, current.composer_id, piece.parent_id.unwrap_or(current.parent_id)).await?;
+    }
+
+    match PieceService::update_piece(pool, id, piece).await {
         Ok(rows) => Ok(Json(rows)),
         Err(e) => {
             Logger::error("API", &format!("Failed to update piece {}: {}", id, e));
@@ -83,3 +180,41 @@ pub async fn delete_piece(
         }
     }
 }
+
+/// 기존 opus_number에서 작품 번호를 다시 읽음 (읽은 곡 수)
+#[post("/pieces/catalog/reparse")]
+pub async fn reparse_piece_catalog(
+    pool: &State<DbPool>,
+    _moderator: ModeratorUser,
+) -> Result<Json<u64>, Status> {
+    match PieceService::reparse_catalog(pool).await {
+        Ok(parsed) => Ok(Json(parsed)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to reparse piece catalogue numbers: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 상위 곡 확인 (없는 곡이거나 단위/작곡가가 맞지 않으면 400)
+async fn check_parent(pool: &DbPool, piece_type: &str, composer_id: i32, parent_id: Option<i32>) -> Result<(), Status> {
+    let parent = match parent_id {
+        Some(parent_id) => match PieceService::get_piece_by_id(pool, parent_id).await {
+            Ok(Some(parent)) => Some(parent),
+            Ok(None) => {
+                Logger::warn("API", &format!("Parent piece {} not found", parent_id));
+                return Err(Status::BadRequest);
+            }
+            Err(e) => {
+                Logger::error("API", &format!("Failed to get piece {}: {}", parent_id, e));
+                return Err(Status::InternalServerError);
+            }
+        },
+        None => None,
+    };
+
+    validate_parent(piece_type, composer_id, parent.as_ref()).map_err(|e| {
+        Logger::warn("API", &format!("Invalid parent piece: {}", e));
+        Status::BadRequest
+    })
+}
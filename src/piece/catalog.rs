@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+/// 작품 번호 체계 표기 (저장/조회 시 이 표기로 통일)
+pub const CATALOG_OPUS: &str = "Op.";
+pub const CATALOG_BWV: &str = "BWV";
+pub const CATALOG_KOECHEL: &str = "K.";
+pub const CATALOG_DEUTSCH: &str = "D.";
+pub const CATALOG_HOBOKEN: &str = "Hob.";
+pub const CATALOG_WOO: &str = "WoO";
+
+/// 입력 표기 (소문자, 끝의 '.' 제외) → 체계
+const CATALOG_ALIASES: [(&str, &str); 10] = [
+    ("opus", CATALOG_OPUS),
+    ("op", CATALOG_OPUS),
+    ("bwv", CATALOG_BWV),
+    ("kv", CATALOG_KOECHEL),
+    ("k", CATALOG_KOECHEL),
+    ("d", CATALOG_DEUTSCH),
+    ("hob", CATALOG_HOBOKEN),
+    ("woo", CATALOG_WOO),
+    ("köchel", CATALOG_KOECHEL),
+    ("deutsch", CATALOG_DEUTSCH),
+];
+
+/// 구조화된 작품 번호
+///
+/// "Op. 9 No. 2" → (Op., 9, 2), "BWV 1046-1051" → (BWV, 1046, range_end 1051),
+/// "Hob. XVI:52" → (Hob., 16, 52) (호보켄 분류 그룹이 number)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogRef {
+    pub system: String,
+    pub number: i32,
+    pub sub_number: Option<i32>,
+    pub range_end: Option<i32>,
+}
+
+impl CatalogRef {
+    /// 자유 표기에서 첫 번째 작품 번호를 읽음 ("Op. 10, Op. 25" → Op. 10)
+    /// 구분자 뒤가 새 번호 체계로 시작할 때만 나눔 ("Op. 9, No. 2" → Op. 9 No. 2)
+    pub fn parse(opus_number: &str) -> Option<Self> {
+        let mut segments = opus_number.split([',', ';', '/']);
+        let mut first = segments.next()?.trim().to_string();
+        for segment in segments {
+            if split_system(segment.trim()).is_some() {
+                break;
+            }
+            first.push(' ');
+            first.push_str(segment.trim());
+        }
+
+        let (system, rest) = split_system(&first)?;
+        let rest = rest.trim_start_matches(|c: char| c == '.' || c.is_whitespace());
+
+        if system == CATALOG_HOBOKEN {
+            let (group, number) = rest.split_once(':')?;
+            return Some(Self {
+                system: system.to_string(),
+                number: parse_roman(group.trim())?,
+                sub_number: Some(leading_number(number.trim())?.0),
+                range_end: None,
+            });
+        }
+
+        let (number, rest) = leading_number(rest)?;
+        let mut catalog = Self {
+            system: system.to_string(),
+            number,
+            sub_number: None,
+            range_end: None,
+        };
+
+        // "BWV 1006a"처럼 번호 뒤에 붙은 문자는 무시
+        let rest = rest.trim_start_matches(|c: char| c.is_ascii_alphabetic()).trim_start();
+        if let Some(end) = rest.strip_prefix(['-', '–']) {
+            catalog.range_end = leading_number(end.trim_start())
+                .map(|(end, _)| end)
+                .filter(|&end| end > number);
+        } else if let Some(sub) = strip_sub_number_prefix(rest) {
+            catalog.sub_number = leading_number(sub).map(|(sub, _)| sub);
+        }
+        Some(catalog)
+    }
+
+    /// 같은 곡을 가리킬 수 있는지 (체계가 같고 번호 범위가 겹치며 세부 번호가 같음)
+    pub fn overlaps(&self, other: &CatalogRef) -> bool {
+        let end = self.range_end.unwrap_or(self.number);
+        let other_end = other.range_end.unwrap_or(other.number);
+        self.system == other.system
+            && self.number <= other_end
+            && other.number <= end
+            && self.sub_number == other.sub_number
+    }
+}
+
+/// 조회 파라미터의 체계 이름 정규화 (bwv, BWV → BWV / k, KV → K.)
+pub fn normalize_system(system: &str) -> Option<&'static str> {
+    let system = system.trim().trim_end_matches('.').to_lowercase();
+    CATALOG_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == system)
+        .map(|(_, normalized)| *normalized)
+}
+
+/// 체계 표기 뒤에는 공백, '.', 숫자만 올 수 있음 ("D"로 시작하는 제목 등 제외)
+fn split_system(value: &str) -> Option<(&'static str, &str)> {
+    let alphabetic_len = value
+        .char_indices()
+        .find(|(_, c)| !c.is_alphabetic())
+        .map(|(i, _)| i)
+        .unwrap_or(value.len());
+    let (prefix, rest) = value.split_at(alphabetic_len);
+    if rest.is_empty() || !rest.starts_with(|c: char| c == '.' || c.is_whitespace() || c.is_ascii_digit()) {
+        return None;
+    }
+    let system = normalize_system(prefix)?;
+    Some((system, rest))
+}
+
+fn strip_sub_number_prefix(value: &str) -> Option<&str> {
+    let lower = value.to_lowercase();
+    ["no.", "no", "nr.", "nr", "n."]
+        .iter()
+        .find(|prefix| lower.starts_with(*prefix))
+        .map(|prefix| value[prefix.len()..].trim_start())
+}
+
+fn leading_number(value: &str) -> Option<(i32, &str)> {
+    let digits = value.len() - value.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let number = value[..digits].parse().ok()?;
+    Some((number, &value[digits..]))
+}
+
+fn parse_roman(value: &str) -> Option<i32> {
+    let mut total = 0;
+    let mut previous = 0;
+    for c in value.chars().rev() {
+        let digit = match c.to_ascii_uppercase() {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            _ => return None,
+        };
+        if digit < previous {
+            total -= digit;
+        } else {
+            total += digit;
+            previous = digit;
+        }
+    }
+    (total > 0).then_some(total)
+}
+
+/// 중복 비교용 제목 (공백/문장부호 제거, 소문자)
+pub fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,88 @@
+use super::model::{Piece, PieceTree};
+
+pub const PIECE_TYPE_COLLECTION: &str = "collection";
+pub const PIECE_TYPE_WORK: &str = "work";
+pub const PIECE_TYPE_MOVEMENT: &str = "movement";
+
+/// 곡 단위 정규화 (이전 이름 album → collection, song → work)
+pub fn normalize_piece_type(piece_type: &str) -> Option<&'static str> {
+    match piece_type {
+        PIECE_TYPE_COLLECTION | "album" => Some(PIECE_TYPE_COLLECTION),
+        PIECE_TYPE_WORK | "song" => Some(PIECE_TYPE_WORK),
+        PIECE_TYPE_MOVEMENT => Some(PIECE_TYPE_MOVEMENT),
+        _ => None,
+    }
+}
+
+/// 상위 곡이 될 수 있는 단위 (악장 → 작품, 작품 → 모음집, 모음집은 최상위)
+pub fn parent_type_of(piece_type: &str) -> Option<&'static str> {
+    match piece_type {
+        PIECE_TYPE_MOVEMENT => Some(PIECE_TYPE_WORK),
+        PIECE_TYPE_WORK => Some(PIECE_TYPE_COLLECTION),
+        _ => None,
+    }
+}
+
+/// 상위 곡 검증 (악장은 반드시 작품 아래, 같은 작곡가의 곡이어야 함)
+pub fn validate_parent(piece_type: &str, composer_id: i32, parent: Option<&Piece>) -> Result<(), String> {
+    let Some(parent) = parent else {
+        if piece_type == PIECE_TYPE_MOVEMENT {
+            return Err("A movement needs a parent work".to_string());
+        }
+        return Ok(());
+    };
+
+    match parent_type_of(piece_type) {
+        Some(expected) if parent.r#type == expected => {}
+        Some(expected) => {
+            return Err(format!(
+                "Parent of a {} must be a {} (piece {} is a {})",
+                piece_type, expected, parent.id, parent.r#type
+            ))
+        }
+        None => return Err(format!("A {} cannot have a parent", piece_type)),
+    }
+    if parent.composer_id != composer_id {
+        return Err(format!("Parent piece {} is by another composer", parent.id));
+    }
+    Ok(())
+}
+
+/// 섹터/연주에 지정한 악장 검증 (piece_id 작품의 악장이어야 함)
+pub fn validate_movement(movement: &Piece, piece_id: i32) -> Result<(), String> {
+    if movement.r#type != PIECE_TYPE_MOVEMENT {
+        return Err(format!("Piece {} is not a movement", movement.id));
+    }
+    if movement.parent_id != Some(piece_id) {
+        return Err(format!("Movement {} does not belong to piece {}", movement.id, piece_id));
+    }
+    Ok(())
+}
+
+/// 섹터에 악장이 지정되어 있으면 연주도 같은 악장이어야 함
+pub fn validate_sector_movement(movement_id: Option<i32>, sector_movement_id: Option<i32>) -> Result<(), String> {
+    match sector_movement_id {
+        Some(sector_movement_id) if movement_id != Some(sector_movement_id) => Err(format!(
+            "Performance movement {:?} does not match sector movement {}",
+            movement_id, sector_movement_id
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// parent_id 아래의 곡들을 트리로 (descendants: 하위 곡 전체, 순서 무관)
+pub fn build_tree(parent_id: i32, descendants: &[Piece]) -> Vec<PieceTree> {
+    let mut children: Vec<&Piece> = descendants
+        .iter()
+        .filter(|piece| piece.parent_id == Some(parent_id))
+        .collect();
+    children.sort_by_key(|piece| (piece.position, piece.id));
+
+    children
+        .into_iter()
+        .map(|piece| PieceTree {
+            piece: piece.clone(),
+            children: build_tree(piece.id, descendants),
+        })
+        .collect()
+}
@@ -1,4 +1,6 @@
 pub mod api;
+pub mod catalog;
+pub mod hierarchy;
 pub mod model;
 pub mod repository;
 pub mod service;
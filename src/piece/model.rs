@@ -1,11 +1,16 @@
 use serde::{Deserialize, Serialize};
+use super::catalog::{normalize_title, CatalogRef};
 use sqlx::FromRow;
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 #[serde(rename_all = "camelCase")]
 pub struct Piece {
     pub id: i32,
     pub composer_id: i32,
+    /// 상위 곡 (악장 → 작품, 작품 → 모음집)
+    pub parent_id: Option<i32>,
+    /// 상위 곡 안에서의 순서
+    pub position: i32,
     pub title: String,
     pub title_en: Option<String>,
     #[serde(rename = "type")]
@@ -15,17 +20,37 @@ pub struct Piece {
     pub composition_year: Option<i32>,
     pub difficulty_level: Option<i32>,
     pub duration_minutes: Option<i32>,
+    pub tempo_marking: Option<String>,
     pub spotify_url: Option<String>,
     pub apple_music_url: Option<String>,
     pub youtube_music_url: Option<String>,
+    /// opus_number에서 읽은 작품 번호 (Op., BWV, K., D., Hob., WoO)
+    pub catalog_system: Option<String>,
+    pub catalog_number: Option<i32>,
+    pub catalog_sub_number: Option<i32>,
+    pub catalog_range_end: Option<i32>,
+}
+
+impl Piece {
+    pub fn catalog_ref(&self) -> Option<CatalogRef> {
+        Some(CatalogRef {
+            system: self.catalog_system.clone()?,
+            number: self.catalog_number?,
+            sub_number: self.catalog_sub_number,
+            range_end: self.catalog_range_end,
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePiece {
     pub composer_id: i32,
+    pub parent_id: Option<i32>,
+    pub position: Option<i32>,
     pub title: String,
     pub title_en: Option<String>,
+    /// collection, work, movement (이전 이름 album, song도 받음)
     #[serde(rename = "type")]
     pub r#type: String,
     pub description: Option<String>,
@@ -33,6 +58,7 @@ pub struct CreatePiece {
     pub composition_year: Option<i32>,
     pub difficulty_level: Option<i32>,
     pub duration_minutes: Option<i32>,
+    pub tempo_marking: Option<String>,
     pub spotify_url: Option<String>,
     pub apple_music_url: Option<String>,
     pub youtube_music_url: Option<String>,
@@ -41,6 +67,10 @@ pub struct CreatePiece {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePiece {
+    /// 없으면 유지, null이면 상위 곡에서 뺌
+    #[serde(default, deserialize_with = "crate::nullable::deserialize")]
+    pub parent_id: Option<Option<i32>>,
+    pub position: Option<i32>,
     pub title: Option<String>,
     pub title_en: Option<String>,
     #[serde(rename = "type")]
@@ -50,7 +80,54 @@ pub struct UpdatePiece {
     pub composition_year: Option<i32>,
     pub difficulty_level: Option<i32>,
     pub duration_minutes: Option<i32>,
+    pub tempo_marking: Option<String>,
     pub spotify_url: Option<String>,
     pub apple_music_url: Option<String>,
     pub youtube_music_url: Option<String>,
 }
+
+impl CreatePiece {
+    /// 같은 작곡가의 곡 중 작품 번호가 겹치거나 제목이 같은 곡
+    pub fn find_duplicates<'a>(&self, composer_pieces: &'a [Piece]) -> Vec<&'a Piece> {
+        let catalog = self.opus_number.as_deref().and_then(CatalogRef::parse);
+        let title = normalize_title(&self.title);
+
+        composer_pieces
+            .iter()
+            .filter(|piece| piece.composer_id == self.composer_id)
+            .filter(|piece| {
+                let same_catalog = match (&catalog, piece.catalog_ref()) {
+                    (Some(catalog), Some(other)) => catalog.overlaps(&other),
+                    _ => false,
+                };
+                same_catalog || (!title.is_empty() && normalize_title(&piece.title) == title)
+            })
+            .collect()
+    }
+}
+
+/// 곡 등록 결과 (duplicates: 중복일 수 있는 기존 곡, 등록은 그대로 진행됨)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedPiece {
+    pub id: i32,
+    pub duplicates: Vec<Piece>,
+}
+
+/// 곡과 하위 곡 (모음집 → 작품 → 악장, 순서대로)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PieceTree {
+    #[serde(flatten)]
+    pub piece: Piece,
+    pub children: Vec<PieceTree>,
+}
+
+/// 곡 상세 (ancestors: 최상위 곡부터 바로 위 곡까지)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PieceDetail {
+    #[serde(flatten)]
+    pub tree: PieceTree,
+    pub ancestors: Vec<Piece>,
+}
@@ -1,7 +1,17 @@
 use crate::db::DbPool;
+use super::catalog::CatalogRef;
+use super::hierarchy::PIECE_TYPE_MOVEMENT;
 use super::model::{Piece, CreatePiece, UpdatePiece};
+use crate::search::SearchText;
 use sqlx::Error;
 
+/// FULLTEXT 인덱스 ft_pieces_search, ft_composers_name 컬럼
+const PIECE_SEARCH_COLUMNS: [&str; 3] = ["p.title", "p.title_en", "p.description"];
+const COMPOSER_NAME_COLUMNS: [&str; 3] = ["c.name", "c.full_name", "c.english_name"];
+
+/// 작품 번호 순 (번호 없는 곡은 뒤로, 작곡 연도/제목 순)
+const CATALOG_ORDER: &str = "catalog_system IS NULL, catalog_system, catalog_number, catalog_sub_number IS NOT NULL, catalog_sub_number, composition_year, title";
+
 pub struct PieceRepository;
 
 impl PieceRepository {
@@ -18,19 +28,181 @@ impl PieceRepository {
             .await
     }
 
+    /// 작품 번호 순 (악장은 get_piece의 하위 곡으로만 노출)
     pub async fn find_by_composer_id(pool: &DbPool, composer_id: i32) -> Result<Vec<Piece>, Error> {
-        sqlx::query_as::<_, Piece>("SELECT * FROM pieces WHERE composer_id = ?")
-            .bind(composer_id)
+        sqlx::query_as::<_, Piece>(&format!(
+            "SELECT * FROM pieces WHERE composer_id = ? AND type <> ? ORDER BY {}",
+            CATALOG_ORDER
+        ))
+        .bind(composer_id)
+        .bind(PIECE_TYPE_MOVEMENT)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 하위 곡 전체 (모음집이면 작품과 그 악장까지)
+    pub async fn find_descendants(pool: &DbPool, id: i32) -> Result<Vec<Piece>, Error> {
+        sqlx::query_as::<_, Piece>(
+            "WITH RECURSIVE descendants AS (
+                SELECT * FROM pieces WHERE parent_id = ?
+                UNION ALL
+                SELECT p.* FROM pieces p JOIN descendants d ON p.parent_id = d.id
+             )
+             SELECT * FROM descendants ORDER BY position, id"
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 상위 곡 (최상위 곡부터)
+    pub async fn find_ancestors(pool: &DbPool, id: i32) -> Result<Vec<Piece>, Error> {
+        let mut ancestors = sqlx::query_as::<_, Piece>(
+            "WITH RECURSIVE ancestors AS (
+                SELECT parent.* FROM pieces child JOIN pieces parent ON child.parent_id = parent.id
+                WHERE child.id = ?
+                UNION ALL
+                SELECT p.* FROM pieces p JOIN ancestors a ON a.parent_id = p.id
+             )
+             SELECT * FROM ancestors"
+        )
+        .bind(id)
+        .fetch_all(pool)
+        .await?;
+
+        ancestors.reverse();
+        Ok(ancestors)
+    }
+
+    pub async fn count_children(pool: &DbPool, id: i32) -> Result<i64, Error> {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM pieces WHERE parent_id = ?")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+    }
+
+    /// 작품 번호로 찾기 (범위로 등록된 곡은 범위 안의 번호로도 찾음, 예: BWV 1046-1051)
+    pub async fn find_by_catalog(
+        pool: &DbPool,
+        system: &str,
+        number: i32,
+        sub_number: Option<i32>,
+        composer_id: Option<i32>,
+    ) -> Result<Vec<Piece>, Error> {
+        let mut query = "SELECT * FROM pieces
+             WHERE catalog_system = ?
+             AND (catalog_number = ? OR ? BETWEEN catalog_number AND catalog_range_end)"
+            .to_string();
+        if sub_number.is_some() {
+            query.push_str(" AND catalog_sub_number = ?");
+        }
+        if composer_id.is_some() {
+            query.push_str(" AND composer_id = ?");
+        }
+        query.push_str(&format!(" ORDER BY catalog_range_end IS NOT NULL, {}", CATALOG_ORDER));
+
+        let mut sql_query = sqlx::query_as::<_, Piece>(&query)
+            .bind(system)
+            .bind(number)
+            .bind(number);
+        if let Some(sub_number) = sub_number {
+            sql_query = sql_query.bind(sub_number);
+        }
+        if let Some(composer_id) = composer_id {
+            sql_query = sql_query.bind(composer_id);
+        }
+        sql_query.fetch_all(pool).await
+    }
+
+    /// 작품 번호가 적힌 곡 (id, opus_number)
+    pub async fn find_opus_numbers(pool: &DbPool) -> Result<Vec<(i32, String)>, Error> {
+        sqlx::query_as::<_, (i32, String)>("SELECT id, opus_number FROM pieces WHERE opus_number IS NOT NULL")
             .fetch_all(pool)
             .await
     }
 
+    pub async fn update_catalog(pool: &DbPool, id: i32, catalog: Option<&CatalogRef>) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "UPDATE pieces SET catalog_system = ?, catalog_number = ?, catalog_sub_number = ?, catalog_range_end = ?
+             WHERE id = ?"
+        )
+        .bind(catalog.map(|c| c.system.as_str()))
+        .bind(catalog.map(|c| c.number))
+        .bind(catalog.and_then(|c| c.sub_number))
+        .bind(catalog.and_then(|c| c.range_end))
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// 곡 제목/설명 또는 작곡가 이름으로 검색 (관련도 순)
+    pub async fn search_pieces(
+        pool: &DbPool,
+        search: &SearchText,
+        composer_id: Option<i32>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Piece>, Error> {
+        let (piece_score, piece_values) = search.relevance(&PIECE_SEARCH_COLUMNS);
+        let (composer_score, composer_values) = search.relevance(&COMPOSER_NAME_COLUMNS);
+        let (piece_condition, piece_condition_values) = search.condition(&PIECE_SEARCH_COLUMNS);
+        let (composer_condition, composer_condition_values) =
+            search.condition(&COMPOSER_NAME_COLUMNS);
+
+        let mut query = format!(
+            "SELECT p.*, ({}) + ({}) as relevance
+             FROM pieces p
+             JOIN composers c ON p.composer_id = c.id
+             WHERE ({} OR {})",
+            piece_score, composer_score, piece_condition, composer_condition
+        );
+        if composer_id.is_some() {
+            query.push_str(" AND p.composer_id = ?");
+        }
+        query.push_str(" ORDER BY relevance DESC, p.title ASC LIMIT ? OFFSET ?");
+
+        let mut sql_query = sqlx::query_as::<_, Piece>(&query);
+        for value in piece_values
+            .into_iter()
+            .chain(composer_values)
+            .chain(piece_condition_values)
+            .chain(composer_condition_values)
+        {
+            sql_query = sql_query.bind(value);
+        }
+        if let Some(id) = composer_id {
+            sql_query = sql_query.bind(id);
+        }
+
+        sql_query.bind(limit).bind(offset).fetch_all(pool).await
+    }
+
+    /// 상위 곡이 있고 순서를 지정하지 않으면 마지막 순서로 추가
     pub async fn create(pool: &DbPool, piece: CreatePiece) -> Result<i32, Error> {
+        let catalog = piece.opus_number.as_deref().and_then(CatalogRef::parse);
+        let mut tx = pool.begin().await?;
+
+        let position = match (piece.position, piece.parent_id) {
+            (Some(position), _) => position,
+            (None, Some(parent_id)) => {
+                sqlx::query_scalar::<_, i32>("SELECT COALESCE(MAX(position), 0) + 1 FROM pieces WHERE parent_id = ?")
+                    .bind(parent_id)
+                    .fetch_one(&mut *tx)
+                    .await?
+            }
+            (None, None) => 0,
+        };
+
         let result = sqlx::query(
-            "INSERT INTO pieces (composer_id, title, title_en, type, description, opus_number, composition_year, difficulty_level, duration_minutes, spotify_url, apple_music_url, youtube_music_url)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO pieces (composer_id, parent_id, position, title, title_en, type, description, opus_number, composition_year, difficulty_level, duration_minutes, tempo_marking, spotify_url, apple_music_url, youtube_music_url,
+             catalog_system, catalog_number, catalog_sub_number, catalog_range_end)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(piece.composer_id)
+        .bind(piece.parent_id)
+        .bind(position)
         .bind(&piece.title)
         .bind(&piece.title_en)
         .bind(&piece.r#type)
@@ -39,18 +211,26 @@ impl PieceRepository {
         .bind(piece.composition_year)
         .bind(piece.difficulty_level)
         .bind(piece.duration_minutes)
+        .bind(&piece.tempo_marking)
         .bind(&piece.spotify_url)
         .bind(&piece.apple_music_url)
         .bind(&piece.youtube_music_url)
-        .execute(pool)
+        .bind(catalog.as_ref().map(|c| c.system.as_str()))
+        .bind(catalog.as_ref().map(|c| c.number))
+        .bind(catalog.as_ref().and_then(|c| c.sub_number))
+        .bind(catalog.as_ref().and_then(|c| c.range_end))
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
         Ok(result.last_insert_id() as i32)
     }
 
     pub async fn update(pool: &DbPool, id: i32, piece: UpdatePiece) -> Result<u64, Error> {
         let result = sqlx::query(
             "UPDATE pieces SET
+                parent_id = IF(?, ?, parent_id),
+                position = COALESCE(?, position),
                 title = COALESCE(?, title),
                 title_en = COALESCE(?, title_en),
                 type = COALESCE(?, type),
@@ -59,11 +239,15 @@ impl PieceRepository {
                 composition_year = COALESCE(?, composition_year),
                 difficulty_level = COALESCE(?, difficulty_level),
                 duration_minutes = COALESCE(?, duration_minutes),
+                tempo_marking = COALESCE(?, tempo_marking),
                 spotify_url = COALESCE(?, spotify_url),
                 apple_music_url = COALESCE(?, apple_music_url),
                 youtube_music_url = COALESCE(?, youtube_music_url)
              WHERE id = ?"
         )
+        .bind(piece.parent_id.is_some())
+        .bind(piece.parent_id.flatten())
+        .bind(piece.position)
         .bind(&piece.title)
         .bind(&piece.title_en)
         .bind(&piece.r#type)
@@ -72,6 +256,7 @@ impl PieceRepository {
         .bind(piece.composition_year)
         .bind(piece.difficulty_level)
         .bind(piece.duration_minutes)
+        .bind(&piece.tempo_marking)
         .bind(&piece.spotify_url)
         .bind(&piece.apple_music_url)
         .bind(&piece.youtube_music_url)
@@ -79,15 +264,31 @@ impl PieceRepository {
         .execute(pool)
         .await?;
 
+        // 작품 번호가 바뀌면 구조화된 번호도 다시 읽음
+        if let Some(opus_number) = piece.opus_number.as_deref() {
+            Self::update_catalog(pool, id, CatalogRef::parse(opus_number).as_ref()).await?;
+        }
+
         Ok(result.rows_affected())
     }
 
+    /// 악장은 작품과 함께 삭제, 모음집에 속한 작품은 남기고 모음집에서만 뺌
     pub async fn delete(pool: &DbPool, id: i32) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        // parent_id FK는 ON DELETE SET NULL이라 악장은 직접 지움
+        sqlx::query("DELETE FROM pieces WHERE parent_id = ? AND type = ?")
+            .bind(id)
+            .bind(PIECE_TYPE_MOVEMENT)
+            .execute(&mut *tx)
+            .await?;
+
         let result = sqlx::query("DELETE FROM pieces WHERE id = ?")
             .bind(id)
-            .execute(pool)
+            .execute(&mut *tx)
             .await?;
 
+        tx.commit().await?;
         Ok(result.rows_affected())
     }
 }
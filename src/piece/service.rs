@@ -1,6 +1,9 @@
 use crate::db::DbPool;
-use super::model::{Piece, CreatePiece, UpdatePiece};
+use super::catalog::CatalogRef;
+use super::hierarchy;
+use super::model::{Piece, CreatePiece, CreatedPiece, PieceDetail, PieceTree, UpdatePiece};
 use super::repository::PieceRepository;
+use crate::search::SearchText;
 
 pub struct PieceService;
 
@@ -17,18 +20,120 @@ impl PieceService {
             .map_err(|e| e.to_string())
     }
 
+    /// 곡과 하위 곡 트리, 상위 곡
+    pub async fn get_piece_detail(pool: &DbPool, id: i32) -> Result<Option<PieceDetail>, String> {
+        let Some(piece) = PieceRepository::find_by_id(pool, id)
+            .await
+            .map_err(|e| e.to_string())?
+        else {
+            return Ok(None);
+        };
+
+        let descendants = PieceRepository::find_descendants(pool, id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let ancestors = PieceRepository::find_ancestors(pool, id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(Some(PieceDetail {
+            tree: PieceTree {
+                piece,
+                children: hierarchy::build_tree(id, &descendants),
+            },
+            ancestors,
+        }))
+    }
+
+    pub async fn count_children(pool: &DbPool, id: i32) -> Result<i64, String> {
+        PieceRepository::count_children(pool, id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
     pub async fn get_pieces_by_composer(pool: &DbPool, composer_id: i32) -> Result<Vec<Piece>, String> {
         PieceRepository::find_by_composer_id(pool, composer_id)
             .await
             .map_err(|e| e.to_string())
     }
 
-    pub async fn create_piece(pool: &DbPool, piece: CreatePiece) -> Result<i32, String> {
-        PieceRepository::create(pool, piece)
+    /// 검색어가 비어 있으면 빈 목록
+    pub async fn search_pieces(
+        pool: &DbPool,
+        query: Option<String>,
+        composer_id: Option<i32>,
+        offset: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Piece>, String> {
+        let search = match SearchText::build(pool, query.as_deref())
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(search) => search,
+            None => return Ok(Vec::new()),
+        };
+
+        PieceRepository::search_pieces(
+            pool,
+            &search,
+            composer_id,
+            offset.unwrap_or(0),
+            limit.unwrap_or(20),
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    /// 작품 번호 조회 (system: normalize_system으로 정규화한 체계)
+    pub async fn lookup_pieces(
+        pool: &DbPool,
+        system: &str,
+        number: i32,
+        sub_number: Option<i32>,
+        composer_id: Option<i32>,
+    ) -> Result<Vec<Piece>, String> {
+        PieceRepository::find_by_catalog(pool, system, number, sub_number, composer_id)
             .await
             .map_err(|e| e.to_string())
     }
 
+    /// 중복일 수 있는 기존 곡이 있어도 등록하고 함께 돌려줌
+    pub async fn create_piece(pool: &DbPool, piece: CreatePiece) -> Result<CreatedPiece, String> {
+        let composer_pieces = PieceRepository::find_by_composer_id(pool, piece.composer_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let duplicates: Vec<Piece> = piece
+            .find_duplicates(&composer_pieces)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let id = PieceRepository::create(pool, piece)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(CreatedPiece { id, duplicates })
+    }
+
+    /// 기존 opus_number를 모두 다시 읽어 구조화된 작품 번호 저장 (읽은 곡 수)
+    pub async fn reparse_catalog(pool: &DbPool) -> Result<u64, String> {
+        let opus_numbers = PieceRepository::find_opus_numbers(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut parsed = 0;
+        for (id, opus_number) in opus_numbers {
+            let catalog = CatalogRef::parse(&opus_number);
+            if catalog.is_some() {
+                parsed += 1;
+            }
+            PieceRepository::update_catalog(pool, id, catalog.as_ref())
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(parsed)
+    }
+
     pub async fn update_piece(pool: &DbPool, id: i32, piece: UpdatePiece) -> Result<u64, String> {
         PieceRepository::update(pool, id, piece)
             .await
@@ -0,0 +1,23 @@
+use super::model::RecommendedArtist;
+use super::service::RecommendationService;
+use crate::db::DbPool;
+use crate::logger::Logger;
+use rocket::{http::Status, serde::json::Json, State};
+
+/// 관련 아티스트 ("이 아티스트를 좋아한 관객이 좋아한 아티스트")
+/// 함께 출연한 공연, 같은 곡 연주, 공연 평점, 분야/티어/국적으로 계산하며 각 항목에 추천 이유 포함
+/// limit: 결과 수 (기본 6, 최대 20)
+#[get("/artists/<id>/related?<limit>")]
+pub async fn get_related_artists(
+    pool: &State<DbPool>,
+    id: i32,
+    limit: Option<i64>,
+) -> Result<Json<Vec<RecommendedArtist>>, Status> {
+    match RecommendationService::get_related_artists(pool, id, limit).await {
+        Ok(artists) => Ok(Json(artists)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to get related artists for {}: {}", id, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use super::service::RecommendationService;
+use crate::kopis::jobs::CONCERT_SYNC_JOB;
+use crate::scheduler::{JobDefinition, JobFuture};
+use sqlx::MySqlPool;
+
+pub const ARTIST_SIMILARITY_JOB: &str = "artist_similarity";
+
+/// 관련 아티스트 재계산 (매일 새벽 5시, 공연 동기화 이후)
+pub fn similarity_recompute() -> JobDefinition {
+    JobDefinition {
+        name: ARTIST_SIMILARITY_JOB,
+        description: "관련 아티스트 재계산",
+        cron: "0 0 5 * * *",
+        depends_on: &[CONCERT_SYNC_JOB],
+        run: run_similarity_recompute,
+    }
+}
+
+fn run_similarity_recompute(pool: MySqlPool) -> JobFuture {
+    Box::pin(async move {
+        RecommendationService::recompute_similarities(&pool)
+            .await
+            .map(|saved| format!("{} related artists saved", saved).into())
+    })
+}
@@ -0,0 +1,8 @@
+pub mod api;
+pub mod jobs;
+pub mod model;
+pub mod repository;
+pub mod service;
+pub mod similarity;
+
+pub use api::*;
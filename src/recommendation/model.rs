@@ -0,0 +1,60 @@
+use crate::artist::model::RelatedArtist;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// 아티스트 쌍별 집계 (repository 내부용, 양방향으로 한 행씩)
+#[derive(Debug, FromRow)]
+pub struct ArtistPairCount {
+    pub artist_id: i32,
+    pub related_artist_id: i32,
+    pub count: i64,
+}
+
+/// 유사도 계산에 쓰는 아티스트 속성
+#[derive(Debug, Clone, FromRow)]
+pub struct ArtistAttributes {
+    pub id: i32,
+    pub category: String,
+    pub tier: String,
+    pub nationality: String,
+}
+
+/// 계산된 유사도 (artist_similarities 행)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtistSimilarity {
+    pub artist_id: i32,
+    pub related_artist_id: i32,
+    pub score: f64,
+    pub shared_concerts: i32,
+    pub shared_pieces: i32,
+    pub co_rating_users: i32,
+    pub same_category: bool,
+    pub same_tier: bool,
+    pub same_nationality: bool,
+}
+
+/// 관련 아티스트 조회 행 (repository 내부용)
+#[derive(Debug, FromRow)]
+pub struct RelatedArtistRow {
+    #[sqlx(flatten)]
+    pub artist: RelatedArtist,
+    pub nationality: String,
+    pub score: f64,
+    pub shared_concerts: i32,
+    pub shared_pieces: i32,
+    pub co_rating_users: i32,
+    pub same_category: bool,
+    pub same_tier: bool,
+    pub same_nationality: bool,
+}
+
+/// 관련 아티스트와 추천 이유
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendedArtist {
+    #[serde(flatten)]
+    pub artist: RelatedArtist,
+    pub score: f64,
+    /// 추천 이유 (화면 표시용 문장, 중요한 순)
+    pub reasons: Vec<String>,
+}
@@ -0,0 +1,119 @@
+use super::model::{ArtistAttributes, ArtistPairCount, ArtistSimilarity, RelatedArtistRow};
+use crate::db::DbPool;
+use sqlx::Error;
+
+/// 이 평점 이상을 준 공연만 "높게 평가"로 봄
+const CO_RATING_MIN_RATING: f64 = 4.0;
+
+pub struct RecommendationRepository;
+
+impl RecommendationRepository {
+    pub async fn find_artist_attributes(pool: &DbPool) -> Result<Vec<ArtistAttributes>, Error> {
+        sqlx::query_as::<_, ArtistAttributes>(
+            "SELECT id, category, tier, nationality FROM artists ORDER BY id",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 함께 출연한 공연 수
+    pub async fn count_shared_concerts(pool: &DbPool) -> Result<Vec<ArtistPairCount>, Error> {
+        sqlx::query_as::<_, ArtistPairCount>(
+            "SELECT a.artist_id, b.artist_id as related_artist_id, COUNT(DISTINCT a.concert_id) as count
+             FROM concert_artists a
+             JOIN concert_artists b ON a.concert_id = b.concert_id AND a.artist_id <> b.artist_id
+             GROUP BY a.artist_id, b.artist_id",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 둘 다 연주 영상이 있는 곡 수
+    pub async fn count_shared_pieces(pool: &DbPool) -> Result<Vec<ArtistPairCount>, Error> {
+        sqlx::query_as::<_, ArtistPairCount>(
+            "SELECT a.artist_id, b.artist_id as related_artist_id, COUNT(DISTINCT a.piece_id) as count
+             FROM (SELECT DISTINCT artist_id, piece_id FROM performances) a
+             JOIN (SELECT DISTINCT artist_id, piece_id FROM performances) b
+               ON a.piece_id = b.piece_id AND a.artist_id <> b.artist_id
+             GROUP BY a.artist_id, b.artist_id",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 두 아티스트의 공연에 모두 높은 평점을 준 사용자 수
+    pub async fn count_co_rating_users(pool: &DbPool) -> Result<Vec<ArtistPairCount>, Error> {
+        sqlx::query_as::<_, ArtistPairCount>(
+            "SELECT ca1.artist_id, ca2.artist_id as related_artist_id, COUNT(DISTINCT r1.user_id) as count
+             FROM user_concert_ratings r1
+             JOIN concert_artists ca1 ON r1.concert_id = ca1.concert_id
+             JOIN user_concert_ratings r2 ON r1.user_id = r2.user_id
+             JOIN concert_artists ca2 ON r2.concert_id = ca2.concert_id
+             WHERE r1.rating >= ? AND r2.rating >= ? AND ca1.artist_id <> ca2.artist_id
+             GROUP BY ca1.artist_id, ca2.artist_id",
+        )
+        .bind(CO_RATING_MIN_RATING)
+        .bind(CO_RATING_MIN_RATING)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 관련 아티스트 전체 교체
+    pub async fn replace_similarities(
+        pool: &DbPool,
+        similarities: &[ArtistSimilarity],
+    ) -> Result<u64, Error> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM artist_similarities")
+            .execute(&mut *tx)
+            .await?;
+
+        let mut inserted = 0;
+        for similarity in similarities {
+            let result = sqlx::query(
+                "INSERT INTO artist_similarities (
+                    artist_id, related_artist_id, score, shared_concerts, shared_pieces, co_rating_users,
+                    same_category, same_tier, same_nationality
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(similarity.artist_id)
+            .bind(similarity.related_artist_id)
+            .bind(similarity.score)
+            .bind(similarity.shared_concerts)
+            .bind(similarity.shared_pieces)
+            .bind(similarity.co_rating_users)
+            .bind(similarity.same_category)
+            .bind(similarity.same_tier)
+            .bind(similarity.same_nationality)
+            .execute(&mut *tx)
+            .await?;
+            inserted += result.rows_affected();
+        }
+
+        tx.commit().await?;
+        Ok(inserted)
+    }
+
+    /// 점수 높은 관련 아티스트
+    pub async fn find_related(
+        pool: &DbPool,
+        artist_id: i32,
+        limit: i64,
+    ) -> Result<Vec<RelatedArtistRow>, Error> {
+        sqlx::query_as::<_, RelatedArtistRow>(
+            "SELECT a.id, a.name, a.english_name, a.category, a.tier, a.image_url, a.nationality,
+             s.score, s.shared_concerts, s.shared_pieces, s.co_rating_users,
+             s.same_category, s.same_tier, s.same_nationality
+             FROM artist_similarities s
+             JOIN artists a ON s.related_artist_id = a.id
+             WHERE s.artist_id = ?
+             ORDER BY s.score DESC, a.id
+             LIMIT ?",
+        )
+        .bind(artist_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,78 @@
+use super::model::RecommendedArtist;
+use super::repository::RecommendationRepository;
+use super::similarity::{compute_similarities, reasons};
+use crate::artist::repository::ArtistRepository;
+use crate::db::DbPool;
+
+/// 기본 결과 수
+pub const DEFAULT_LIMIT: i64 = 6;
+/// 최대 결과 수 (아티스트별 저장 수)
+pub const MAX_LIMIT: i64 = 20;
+
+pub struct RecommendationService;
+
+impl RecommendationService {
+    /// 관련 아티스트 다시 계산 → 저장한 행 수
+    pub async fn recompute_similarities(pool: &DbPool) -> Result<u64, String> {
+        let artists = RecommendationRepository::find_artist_attributes(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let shared_concerts = RecommendationRepository::count_shared_concerts(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let shared_pieces = RecommendationRepository::count_shared_pieces(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let co_ratings = RecommendationRepository::count_co_rating_users(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let similarities = compute_similarities(
+            &artists,
+            &shared_concerts,
+            &shared_pieces,
+            &co_ratings,
+            MAX_LIMIT as usize,
+        );
+
+        RecommendationRepository::replace_similarities(pool, &similarities)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// 관련 아티스트와 추천 이유
+    /// 아직 계산되지 않은 아티스트(새로 추가 등)는 같은 분야 아티스트로 대신함
+    pub async fn get_related_artists(
+        pool: &DbPool,
+        artist_id: i32,
+        limit: Option<i64>,
+    ) -> Result<Vec<RecommendedArtist>, String> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        let rows = RecommendationRepository::find_related(pool, artist_id, limit)
+            .await
+            .map_err(|e| e.to_string())?;
+        if !rows.is_empty() {
+            return Ok(rows
+                .into_iter()
+                .map(|row| RecommendedArtist {
+                    reasons: reasons(&row),
+                    score: row.score,
+                    artist: row.artist,
+                })
+                .collect());
+        }
+
+        let fallback = ArtistRepository::find_related(pool, artist_id, limit)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(fallback
+            .into_iter()
+            .map(|artist| RecommendedArtist {
+                reasons: vec![format!("같은 분야 ({})", artist.category)],
+                score: 0.0,
+                artist,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,118 @@
+use super::model::{ArtistAttributes, ArtistPairCount, ArtistSimilarity, RelatedArtistRow};
+use std::collections::HashMap;
+
+/// 신호별 가중치
+pub const WEIGHT_SHARED_CONCERT: f64 = 3.0;
+pub const WEIGHT_CO_RATING: f64 = 2.0;
+pub const WEIGHT_SHARED_PIECE: f64 = 1.0;
+pub const WEIGHT_SAME_CATEGORY: f64 = 1.5;
+pub const WEIGHT_SAME_TIER: f64 = 0.5;
+pub const WEIGHT_SAME_NATIONALITY: f64 = 0.5;
+/// 신호별 최대 반영 횟수 (한 신호가 점수를 독점하지 않도록)
+pub const MAX_SIGNAL_COUNT: i32 = 5;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Signals {
+    shared_concerts: i32,
+    shared_pieces: i32,
+    co_rating_users: i32,
+}
+
+/// 아티스트별 관련 아티스트 상위 limit명 계산
+///
+/// 함께 출연한 공연/같은 곡 연주/평점을 함께 준 사용자가 있거나 같은 분야인 아티스트만 후보
+/// (티어나 국적만 같은 경우는 제외)
+pub fn compute_similarities(
+    artists: &[ArtistAttributes],
+    shared_concerts: &[ArtistPairCount],
+    shared_pieces: &[ArtistPairCount],
+    co_ratings: &[ArtistPairCount],
+    limit: usize,
+) -> Vec<ArtistSimilarity> {
+    let mut signals: HashMap<(i32, i32), Signals> = HashMap::new();
+    for pair in shared_concerts {
+        signals.entry((pair.artist_id, pair.related_artist_id)).or_default().shared_concerts = pair.count as i32;
+    }
+    for pair in shared_pieces {
+        signals.entry((pair.artist_id, pair.related_artist_id)).or_default().shared_pieces = pair.count as i32;
+    }
+    for pair in co_ratings {
+        signals.entry((pair.artist_id, pair.related_artist_id)).or_default().co_rating_users = pair.count as i32;
+    }
+
+    let mut result = Vec::new();
+    for artist in artists {
+        let mut candidates: Vec<ArtistSimilarity> = artists
+            .iter()
+            .filter(|other| other.id != artist.id)
+            .filter_map(|other| {
+                let s = signals.get(&(artist.id, other.id)).copied().unwrap_or_default();
+                let same_category = artist.category == other.category;
+                if !same_category && s.shared_concerts == 0 && s.shared_pieces == 0 && s.co_rating_users == 0 {
+                    return None;
+                }
+
+                let mut similarity = ArtistSimilarity {
+                    artist_id: artist.id,
+                    related_artist_id: other.id,
+                    score: 0.0,
+                    shared_concerts: s.shared_concerts,
+                    shared_pieces: s.shared_pieces,
+                    co_rating_users: s.co_rating_users,
+                    same_category,
+                    same_tier: artist.tier == other.tier,
+                    same_nationality: artist.nationality == other.nationality,
+                };
+                similarity.score = score(&similarity);
+                Some(similarity)
+            })
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then(a.related_artist_id.cmp(&b.related_artist_id))
+        });
+        candidates.truncate(limit);
+        result.extend(candidates);
+    }
+
+    result
+}
+
+/// 신호별 가중치 합
+pub fn score(similarity: &ArtistSimilarity) -> f64 {
+    let capped = |count: i32| count.min(MAX_SIGNAL_COUNT) as f64;
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+
+    capped(similarity.shared_concerts) * WEIGHT_SHARED_CONCERT
+        + capped(similarity.co_rating_users) * WEIGHT_CO_RATING
+        + capped(similarity.shared_pieces) * WEIGHT_SHARED_PIECE
+        + flag(similarity.same_category) * WEIGHT_SAME_CATEGORY
+        + flag(similarity.same_tier) * WEIGHT_SAME_TIER
+        + flag(similarity.same_nationality) * WEIGHT_SAME_NATIONALITY
+}
+
+/// 추천 이유 (가중치가 큰 신호부터)
+pub fn reasons(row: &RelatedArtistRow) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if row.shared_concerts > 0 {
+        reasons.push(format!("함께 출연한 공연 {}회", row.shared_concerts));
+    }
+    if row.co_rating_users > 0 {
+        reasons.push(format!("두 아티스트의 공연을 모두 높게 평가한 관객 {}명", row.co_rating_users));
+    }
+    if row.shared_pieces > 0 {
+        reasons.push(format!("같은 곡 연주 {}곡", row.shared_pieces));
+    }
+    if row.same_category {
+        reasons.push(format!("같은 분야 ({})", row.artist.category));
+    }
+    if row.same_tier {
+        reasons.push(format!("같은 티어 ({})", row.artist.tier));
+    }
+    if row.same_nationality {
+        reasons.push(format!("같은 국적 ({})", row.nationality));
+    }
+    reasons
+}
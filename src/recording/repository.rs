@@ -40,6 +40,22 @@ impl RecordingRepository {
         .await
     }
 
+    /// 최근 발매 음반 (발매일이 없으면 연도 기준)
+    pub async fn find_latest_by_artist(pool: &DbPool, artist_id: i32, limit: i64) -> Result<Vec<Recording>, Error> {
+        sqlx::query_as::<_, Recording>(
+            "SELECT id, artist_id, title, year, release_date, label, cover_url, upc, apple_music_id,
+             track_count, is_single, is_compilation, genre_names, copyright, editorial_notes,
+             artwork_width, artwork_height, spotify_url, apple_music_url, youtube_music_url, external_url
+             FROM recordings WHERE artist_id = ?
+             ORDER BY COALESCE(release_date, STR_TO_DATE(CONCAT(year, '-01-01'), '%Y-%m-%d')) DESC, id DESC
+             LIMIT ?"
+        )
+        .bind(artist_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn create(pool: &DbPool, recording: CreateRecording) -> Result<u64, Error> {
         use sqlx::types::chrono::NaiveDate;
         use sqlx::types::JsonValue;
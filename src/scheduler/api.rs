@@ -0,0 +1,40 @@
+use super::model::{JobInfo, UpdateJob};
+use super::service::JobScheduler;
+use crate::auth::AdminUser;
+use crate::logger::Logger;
+use rocket::{http::Status, serde::json::Json, State};
+use std::sync::Arc;
+
+/// 등록된 작업 목록 (다음/마지막 실행 시각 포함)
+/// Admin 권한 필요
+#[get("/scheduler/jobs")]
+pub async fn get_jobs(
+    scheduler: &State<Arc<JobScheduler>>,
+    _admin: AdminUser,
+) -> Json<Vec<JobInfo>> {
+    Json(scheduler.list_jobs())
+}
+
+/// 작업 활성화/비활성화
+/// Admin 권한 필요
+#[put("/scheduler/jobs/<name>", data = "<job>")]
+pub async fn update_job(
+    scheduler: &State<Arc<JobScheduler>>,
+    name: &str,
+    job: Json<UpdateJob>,
+    _admin: AdminUser,
+) -> Result<Json<JobInfo>, Status> {
+    let result = match job.into_inner().enabled {
+        Some(enabled) => scheduler.set_enabled(name, enabled).await,
+        None => Ok(scheduler.get_job(name)),
+    };
+
+    match result {
+        Ok(Some(info)) => Ok(Json(info)),
+        Ok(None) => Err(Status::NotFound),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to update job {}: {}", name, e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
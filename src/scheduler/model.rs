@@ -0,0 +1,40 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// 작업 실행 기록 (scheduler_jobs 테이블)
+#[derive(Debug, Clone, FromRow)]
+pub struct JobRecord {
+    pub job_name: String,
+    pub enabled: Option<bool>,
+    pub last_started_at: Option<DateTime<Utc>>,
+    pub last_finished_at: Option<DateTime<Utc>>,
+    pub last_status: Option<String>,
+    pub last_message: Option<String>,
+}
+
+/// 관리자용 작업 현황
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobInfo {
+    pub name: String,
+    pub description: String,
+    pub cron: String,
+    pub timezone: String,
+    pub enabled: bool,
+    pub run_on_startup: bool,
+    pub catch_up: bool,
+    pub depends_on: Vec<String>,
+    pub running: bool,
+    pub last_started_at: Option<DateTime<FixedOffset>>,
+    pub last_finished_at: Option<DateTime<FixedOffset>>,
+    pub last_status: Option<String>,
+    pub last_message: Option<String>,
+    pub next_run_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateJob {
+    pub enabled: Option<bool>,
+}
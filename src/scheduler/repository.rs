@@ -0,0 +1,79 @@
+use super::model::JobRecord;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+
+pub struct JobRepository;
+
+impl JobRepository {
+    pub async fn find_all(pool: &MySqlPool) -> Result<Vec<JobRecord>, sqlx::Error> {
+        sqlx::query_as::<_, JobRecord>(
+            "SELECT job_name, enabled, last_started_at, last_finished_at, last_status, last_message
+             FROM scheduler_jobs",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set_enabled(
+        pool: &MySqlPool,
+        job_name: &str,
+        enabled: bool,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO scheduler_jobs (job_name, enabled)
+             VALUES (?, ?)
+             ON DUPLICATE KEY UPDATE enabled = VALUES(enabled)",
+        )
+        .bind(job_name)
+        .bind(enabled)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn mark_started(
+        pool: &MySqlPool,
+        job_name: &str,
+        started_at: DateTime<Utc>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO scheduler_jobs (job_name, last_started_at, last_status)
+             VALUES (?, ?, 'running')
+             ON DUPLICATE KEY UPDATE
+                last_started_at = VALUES(last_started_at),
+                last_status = 'running'",
+        )
+        .bind(job_name)
+        .bind(started_at)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn mark_finished(
+        pool: &MySqlPool,
+        job_name: &str,
+        finished_at: DateTime<Utc>,
+        status: &str,
+        message: Option<&str>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO scheduler_jobs (job_name, last_finished_at, last_status, last_message)
+             VALUES (?, ?, ?, ?)
+             ON DUPLICATE KEY UPDATE
+                last_finished_at = VALUES(last_finished_at),
+                last_status = VALUES(last_status),
+                last_message = VALUES(last_message)",
+        )
+        .bind(job_name)
+        .bind(finished_at)
+        .bind(status)
+        .bind(message)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
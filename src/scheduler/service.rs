@@ -0,0 +1,468 @@
+use super::model::{JobInfo, JobRecord};
+use super::repository::JobRepository;
+use crate::logger::Logger;
+use chrono::{DateTime, FixedOffset, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
+use sqlx::MySqlPool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use tokio::time::{sleep, Duration};
+
+/// 컨테이너 타임존과 무관하게 한국 시간 기준으로 실행
+const DEFAULT_TIMEZONE: Tz = chrono_tz::Asia::Seoul;
+
+/// 선행 작업 완료 확인 주기
+const DEPENDENCY_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+const STATUS_RUNNING: &str = "running";
+const STATUS_SUCCESS: &str = "success";
+const STATUS_FAILED: &str = "failed";
+const STATUS_SKIPPED: &str = "skipped";
+
+pub type JobFuture = Pin<Box<dyn Future<Output = Result<JobOutput, String>> + Send>>;
+
+/// 작업 실행 결과
+#[derive(Debug, Clone)]
+pub struct JobOutput {
+    /// 실행 기록에 남기는 요약 메시지
+    pub message: String,
+    /// 추가/갱신/에러 건수 (동기화 작업만)
+    pub counts: Option<JobCounts>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JobCounts {
+    pub added: i32,
+    pub updated: i32,
+    pub errors: i32,
+}
+
+impl From<String> for JobOutput {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            counts: None,
+        }
+    }
+}
+
+/// 작업 정의 (각 도메인 모듈에서 제공)
+pub struct JobDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// 기본 cron 표현식 (초 분 시 일 월 요일)
+    pub cron: &'static str,
+    /// 선행 작업: 실행 중이면 끝날 때까지 대기, 마지막 실행이 실패했으면 건너뜀
+    pub depends_on: &'static [&'static str],
+    /// 성공 시 실행 결과, 실패 시 에러 메시지 반환
+    pub run: fn(MySqlPool) -> JobFuture,
+}
+
+struct Job {
+    definition: JobDefinition,
+    cron: String,
+    schedule: Schedule,
+    run_on_startup: bool,
+    catch_up: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct JobState {
+    enabled: bool,
+    running: bool,
+    last_started_at: Option<DateTime<Utc>>,
+    last_finished_at: Option<DateTime<Utc>>,
+    last_status: Option<String>,
+    last_message: Option<String>,
+}
+
+pub struct JobScheduler {
+    pool: MySqlPool,
+    timezone: Tz,
+    jobs: Vec<Job>,
+    states: Mutex<HashMap<&'static str, JobState>>,
+}
+
+impl JobScheduler {
+    /// 타임존은 SCHEDULER_TIMEZONE 환경변수로 변경 가능 (기본값: Asia/Seoul)
+    pub fn new(pool: MySqlPool) -> Self {
+        let timezone = match std::env::var("SCHEDULER_TIMEZONE") {
+            Ok(name) => name.parse::<Tz>().unwrap_or_else(|e| {
+                Logger::warn(
+                    "SCHEDULER",
+                    &format!("Invalid SCHEDULER_TIMEZONE '{}': {}, using {}", name, e, DEFAULT_TIMEZONE),
+                );
+                DEFAULT_TIMEZONE
+            }),
+            Err(_) => DEFAULT_TIMEZONE,
+        };
+
+        Self {
+            pool,
+            timezone,
+            jobs: Vec::new(),
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 작업 등록 (선행 작업을 먼저 등록해야 함)
+    ///
+    /// 환경변수로 작업별 설정 변경 가능 (예: venue_sync → JOB_VENUE_SYNC_*)
+    /// * `_CRON` - cron 표현식
+    /// * `_ENABLED` - 활성화 여부 (기본값: true)
+    /// * `_RUN_ON_STARTUP` - 서버 시작 시 즉시 실행 (기본값: false)
+    /// * `_CATCH_UP` - 서버가 꺼져 있어 놓친 실행(실행 기록이 없는 경우 포함)을 시작 시 보충 (기본값: true)
+    pub fn register(mut self, definition: JobDefinition) -> Self {
+        let prefix = format!("JOB_{}", definition.name.to_uppercase());
+
+        let cron = std::env::var(format!("{}_CRON", prefix))
+            .ok()
+            .filter(|expr| match Schedule::from_str(expr) {
+                Ok(_) => true,
+                Err(e) => {
+                    Logger::warn(
+                        "SCHEDULER",
+                        &format!("Invalid cron '{}' for job '{}': {}", expr, definition.name, e),
+                    );
+                    false
+                }
+            })
+            .unwrap_or_else(|| definition.cron.to_string());
+
+        let schedule = Schedule::from_str(&cron)
+            .unwrap_or_else(|e| panic!("Invalid default cron for job '{}': {}", definition.name, e));
+
+        for dependency in definition.depends_on {
+            if self.job(dependency).is_none() {
+                Logger::warn(
+                    "SCHEDULER",
+                    &format!(
+                        "Job '{}' depends on unregistered job '{}'",
+                        definition.name, dependency
+                    ),
+                );
+            }
+        }
+
+        let state = JobState {
+            enabled: env_flag(&format!("{}_ENABLED", prefix), true),
+            ..Default::default()
+        };
+        self.states
+            .get_mut()
+            .unwrap()
+            .insert(definition.name, state);
+
+        self.jobs.push(Job {
+            run_on_startup: env_flag(&format!("{}_RUN_ON_STARTUP", prefix), false),
+            catch_up: env_flag(&format!("{}_CATCH_UP", prefix), true),
+            definition,
+            cron,
+            schedule,
+        });
+
+        self
+    }
+
+    /// 실행 기록을 불러온 뒤 작업별 백그라운드 태스크 시작
+    pub async fn start(self) -> Arc<Self> {
+        match JobRepository::find_all(&self.pool).await {
+            Ok(records) => {
+                let mut states = self.states.lock().unwrap();
+                for record in records {
+                    if let Some(state) = states.get_mut(record.job_name.as_str()) {
+                        state.restore(record);
+                    }
+                }
+            }
+            Err(e) => {
+                Logger::warn("SCHEDULER", &format!("Failed to load job history: {}", e));
+            }
+        }
+
+        let scheduler = Arc::new(self);
+
+        for job in &scheduler.jobs {
+            Logger::info(
+                "SCHEDULER",
+                &format!(
+                    "Job '{}' scheduled: {} ({})",
+                    job.definition.name, job.cron, scheduler.timezone
+                ),
+            );
+
+            let runner = scheduler.clone();
+            let name = job.definition.name;
+            tokio::spawn(async move { runner.run_loop(name).await });
+        }
+
+        // 시작 시 실행 / 놓친 실행 보충 (등록 순서대로 실행하여 선행 작업이 먼저 끝나도록 함)
+        let startup = scheduler.clone();
+        tokio::spawn(async move { startup.run_startup_jobs().await });
+
+        Logger::success("SCHEDULER", "Job scheduler started successfully");
+
+        scheduler
+    }
+
+    pub fn list_jobs(&self) -> Vec<JobInfo> {
+        self.jobs.iter().map(|job| self.job_info(job)).collect()
+    }
+
+    pub fn get_job(&self, name: &str) -> Option<JobInfo> {
+        self.job(name).map(|job| self.job_info(job))
+    }
+
+    /// 작업 활성화/비활성화 (DB에 저장되어 재시작 후에도 유지)
+    pub async fn set_enabled(&self, name: &str, enabled: bool) -> Result<Option<JobInfo>, String> {
+        let job = match self.job(name) {
+            Some(job) => job,
+            None => return Ok(None),
+        };
+
+        JobRepository::set_enabled(&self.pool, job.definition.name, enabled)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(state) = self.states.lock().unwrap().get_mut(job.definition.name) {
+            state.enabled = enabled;
+        }
+
+        Logger::info(
+            "SCHEDULER",
+            &format!(
+                "Job '{}' {}",
+                job.definition.name,
+                if enabled { "enabled" } else { "disabled" }
+            ),
+        );
+
+        Ok(Some(self.job_info(job)))
+    }
+
+    /// 작업 실행 (선행 작업 확인 및 중복 실행 방지)
+    pub async fn run_job(&self, name: &str) -> Result<JobOutput, String> {
+        let job = self
+            .job(name)
+            .ok_or_else(|| format!("Unknown job: {}", name))?;
+        let name = job.definition.name;
+
+        for dependency in job.definition.depends_on {
+            while self.state(dependency).is_some_and(|state| state.running) {
+                Logger::info(
+                    "SCHEDULER",
+                    &format!("Job '{}' waiting for '{}' to finish", name, dependency),
+                );
+                sleep(DEPENDENCY_POLL_INTERVAL).await;
+            }
+
+            let failed = self.state(dependency).is_some_and(|state| {
+                state.enabled && state.last_status.as_deref() == Some(STATUS_FAILED)
+            });
+
+            if failed {
+                let message = format!("Skipped: dependency '{}' failed on its last run", dependency);
+                Logger::warn("SCHEDULER", &format!("Job '{}' {}", name, message));
+                self.finish(name, STATUS_SKIPPED, &message).await;
+                return Err(message);
+            }
+        }
+
+        let started_at = Utc::now();
+        {
+            let mut states = self.states.lock().unwrap();
+            let state = states.get_mut(name).unwrap();
+            if state.running {
+                return Err(format!("Job '{}' is already running", name));
+            }
+            state.running = true;
+            state.last_started_at = Some(started_at);
+            state.last_status = Some(STATUS_RUNNING.to_string());
+        }
+
+        if let Err(e) = JobRepository::mark_started(&self.pool, name, started_at).await {
+            Logger::warn("SCHEDULER", &format!("Failed to record job start: {}", e));
+        }
+
+        Logger::info("SCHEDULER", &format!("=== Starting job '{}' ===", name));
+
+        let result = (job.definition.run)(self.pool.clone()).await;
+
+        match &result {
+            Ok(output) => {
+                Logger::success("SCHEDULER", &format!("Job '{}' completed: {}", name, output.message));
+                self.finish(name, STATUS_SUCCESS, &output.message).await;
+            }
+            Err(e) => {
+                Logger::error("SCHEDULER", &format!("Job '{}' failed: {}", name, e));
+                self.finish(name, STATUS_FAILED, e).await;
+            }
+        }
+
+        result
+    }
+
+    /// 다음 실행 시각까지 대기 후 실행 반복
+    async fn run_loop(&self, name: &'static str) {
+        let job = match self.job(name) {
+            Some(job) => job,
+            None => return,
+        };
+
+        let mut after = Utc::now();
+
+        loop {
+            let next_run = match job.schedule.after(&after.with_timezone(&self.timezone)).next() {
+                Some(next_run) => next_run.with_timezone(&Utc),
+                None => {
+                    Logger::warn("SCHEDULER", &format!("Job '{}' has no upcoming runs", name));
+                    return;
+                }
+            };
+
+            let wait = (next_run - Utc::now()).to_std().unwrap_or(Duration::ZERO);
+            sleep(wait).await;
+
+            // 실행이 길어져 지나간 실행 시각은 건너뜀
+            after = next_run.max(Utc::now());
+
+            if !self.state(name).is_some_and(|state| state.enabled) {
+                Logger::info("SCHEDULER", &format!("Job '{}' is disabled, skipping", name));
+                continue;
+            }
+
+            // 실패 로그는 run_job에서 출력
+            let _ = self.run_job(name).await;
+        }
+    }
+
+    async fn run_startup_jobs(&self) {
+        for job in &self.jobs {
+            let name = job.definition.name;
+            let state = match self.state(name) {
+                Some(state) if state.enabled => state,
+                _ => continue,
+            };
+
+            let reason = if job.run_on_startup {
+                "run on startup"
+            } else if job.catch_up && self.missed_run(job, &state) {
+                "catching up missed run"
+            } else {
+                continue;
+            };
+
+            Logger::info("SCHEDULER", &format!("Job '{}': {}", name, reason));
+            let _ = self.run_job(name).await;
+        }
+    }
+
+    /// 마지막 실행 이후 예정된 실행 시각이 이미 지났거나, 이전 실행이 중단됐거나, 실행 기록이 없는 경우
+    /// (첫 배포, scheduler_jobs 초기화 후에는 다음 예정 시각까지 기다리지 않음)
+    fn missed_run(&self, job: &Job, state: &JobState) -> bool {
+        if state.last_status.as_deref() == Some(STATUS_RUNNING) {
+            return true;
+        }
+
+        match state.last_started_at {
+            Some(last_started_at) => job
+                .schedule
+                .after(&last_started_at.with_timezone(&self.timezone))
+                .next()
+                .is_some_and(|next_run| next_run.with_timezone(&Utc) <= Utc::now()),
+            None => true,
+        }
+    }
+
+    async fn finish(&self, name: &'static str, status: &str, message: &str) {
+        let finished_at = Utc::now();
+
+        if let Some(state) = self.states.lock().unwrap().get_mut(name) {
+            state.running = false;
+            state.last_finished_at = Some(finished_at);
+            state.last_status = Some(status.to_string());
+            state.last_message = Some(message.to_string());
+        }
+
+        if let Err(e) =
+            JobRepository::mark_finished(&self.pool, name, finished_at, status, Some(message)).await
+        {
+            Logger::warn("SCHEDULER", &format!("Failed to record job result: {}", e));
+        }
+    }
+
+    fn job(&self, name: &str) -> Option<&Job> {
+        self.jobs.iter().find(|job| job.definition.name == name)
+    }
+
+    fn state(&self, name: &str) -> Option<JobState> {
+        self.states.lock().unwrap().get(name).cloned()
+    }
+
+    fn job_info(&self, job: &Job) -> JobInfo {
+        let state = self.state(job.definition.name).unwrap_or_default();
+        let to_local = |time: DateTime<Utc>| -> DateTime<FixedOffset> {
+            time.with_timezone(&self.timezone).fixed_offset()
+        };
+
+        JobInfo {
+            name: job.definition.name.to_string(),
+            description: job.definition.description.to_string(),
+            cron: job.cron.clone(),
+            timezone: self.timezone.to_string(),
+            enabled: state.enabled,
+            run_on_startup: job.run_on_startup,
+            catch_up: job.catch_up,
+            depends_on: job
+                .definition
+                .depends_on
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            running: state.running,
+            last_started_at: state.last_started_at.map(to_local),
+            last_finished_at: state.last_finished_at.map(to_local),
+            last_status: state.last_status,
+            last_message: state.last_message,
+            next_run_at: if state.enabled {
+                job.schedule
+                    .upcoming(self.timezone)
+                    .next()
+                    .map(|next_run| next_run.fixed_offset())
+            } else {
+                None
+            },
+        }
+    }
+}
+
+impl JobState {
+    /// DB 실행 기록 반영 (enabled는 관리자가 설정한 경우에만 덮어씀)
+    fn restore(&mut self, record: JobRecord) {
+        if let Some(enabled) = record.enabled {
+            self.enabled = enabled;
+        }
+        self.last_started_at = record.last_started_at;
+        self.last_finished_at = record.last_finished_at;
+        self.last_status = record.last_status;
+        self.last_message = record.last_message;
+    }
+}
+
+fn env_flag(key: &str, default: bool) -> bool {
+    match std::env::var(key) {
+        Ok(value) => match value.trim().to_lowercase().as_str() {
+            "true" | "1" | "yes" => true,
+            "false" | "0" | "no" => false,
+            _ => {
+                Logger::warn("SCHEDULER", &format!("Invalid boolean for {}: {}", key, value));
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
@@ -0,0 +1,64 @@
+use super::model::{SearchResults, Suggestion, SEARCH_TYPES, SUGGEST_TYPES};
+use super::service::SearchService;
+use super::suggest::SuggestIndex;
+use crate::db::DbPool;
+use crate::logger::Logger;
+use rocket::{http::Status, serde::json::Json, State};
+use std::sync::Arc;
+
+/// 통합 검색 (공연, 아티스트, 작곡가, 곡, 음반, 공연장)
+/// types: 쉼표로 구분한 검색 대상 (예: concert,artist), limit: 타입별 결과 수 (기본 5, 최대 20)
+#[get("/search?<q>&<types>&<limit>")]
+pub async fn search(
+    pool: &State<DbPool>,
+    q: Option<String>,
+    types: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<SearchResults>, Status> {
+    let types = parse_types(types, &SEARCH_TYPES)?;
+
+    match SearchService::search(pool, q, types, limit).await {
+        Ok(results) => Ok(Json(results)),
+        Err(e) => {
+            Logger::error("API", &format!("Failed to search: {}", e));
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// 입력 중 자동완성 (작곡가, 아티스트, 곡, 공연장 이름, 한글 초성 지원)
+/// types: 쉼표로 구분한 대상 (예: composer,artist), limit: 결과 수 (기본 10, 최대 20)
+#[get("/suggest?<q>&<types>&<limit>")]
+pub fn get_suggestions(
+    index: &State<Arc<SuggestIndex>>,
+    q: Option<String>,
+    types: Option<String>,
+    limit: Option<usize>,
+) -> Result<Json<Vec<Suggestion>>, Status> {
+    let types = parse_types(types, &SUGGEST_TYPES)?;
+    Ok(Json(index.suggest(
+        q.as_deref().unwrap_or_default(),
+        types.as_deref(),
+        limit,
+    )))
+}
+
+/// 쉼표로 구분한 타입 목록 (허용되지 않은 타입이 있으면 400)
+fn parse_types(types: Option<String>, allowed: &[&str]) -> Result<Option<Vec<String>>, Status> {
+    let types = match types {
+        Some(types) => types,
+        None => return Ok(None),
+    };
+
+    let types: Vec<String> = types
+        .split(',')
+        .map(|t| t.trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if let Some(unknown) = types.iter().find(|t| !allowed.contains(&t.as_str())) {
+        Logger::warn("API", &format!("Unknown search type: {}", unknown));
+        return Err(Status::BadRequest);
+    }
+
+    Ok(Some(types))
+}
@@ -0,0 +1,10 @@
+pub mod api;
+pub mod model;
+pub mod repository;
+pub mod service;
+pub mod suggest;
+pub mod text;
+
+pub use api::*;
+pub use suggest::SuggestIndex;
+pub use text::SearchText;
@@ -0,0 +1,88 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// 통합 검색 대상
+pub const TYPE_CONCERT: &str = "concert";
+pub const TYPE_ARTIST: &str = "artist";
+pub const TYPE_COMPOSER: &str = "composer";
+pub const TYPE_PIECE: &str = "piece";
+pub const TYPE_RECORDING: &str = "recording";
+pub const TYPE_VENUE: &str = "venue";
+
+/// 기본 검색 순서 (관련도가 같으면 이 순서)
+pub const SEARCH_TYPES: [&str; 6] = [
+    TYPE_CONCERT,
+    TYPE_ARTIST,
+    TYPE_COMPOSER,
+    TYPE_PIECE,
+    TYPE_RECORDING,
+    TYPE_VENUE,
+];
+
+/// 자동완성 대상
+pub const SUGGEST_TYPES: [&str; 4] = [TYPE_COMPOSER, TYPE_ARTIST, TYPE_PIECE, TYPE_VENUE];
+
+/// 타입별 검색 결과 행 (repository 내부용)
+#[derive(Debug, FromRow)]
+pub struct SearchRow {
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub image_url: Option<String>,
+    /// 강조 구간을 찾을 추가 텍스트 (출연진, 작곡가명 등)
+    pub detail: Option<String>,
+    pub relevance: f64,
+}
+
+/// 통합 검색 결과 항목
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub r#type: String,
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub image_url: Option<String>,
+    /// 검색어와 일치한 부분 (<em>으로 감싼 HTML 이스케이프 문자열)
+    pub highlight: Option<String>,
+    pub score: f64,
+}
+
+/// 타입별 결과 묶음
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchGroup {
+    pub r#type: String,
+    pub hits: Vec<SearchHit>,
+}
+
+/// 통합 검색 응답 (결과가 있는 타입만, 최고 관련도 순)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResults {
+    pub query: String,
+    pub groups: Vec<SearchGroup>,
+}
+
+/// 자동완성 인덱스 원본 행 (repository 내부용)
+#[derive(Debug, FromRow)]
+pub struct SuggestRow {
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub image_url: Option<String>,
+    /// 제목 외에 검색할 이름 (작곡가 전체 이름, 곡 Opus 번호 등)
+    pub alt_name: Option<String>,
+    pub english_name: Option<String>,
+}
+
+/// 자동완성 항목 (통합 검색 결과와 같은 모양)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Suggestion {
+    pub r#type: String,
+    pub id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub image_url: Option<String>,
+}
@@ -0,0 +1,266 @@
+use super::model::{SearchRow, SuggestRow};
+use super::text::SearchText;
+use crate::db::DbPool;
+use chrono::NaiveDateTime;
+use sqlx::Error;
+
+/// FULLTEXT 인덱스 컬럼 (각 테이블 스키마 참고)
+const CONCERT_SEARCH_COLUMNS: [&str; 4] = ["c.title", "c.composer_info", "c.cast", "c.facility_name"];
+const ARTIST_NAME_COLUMNS: [&str; 2] = ["a.name", "a.english_name"];
+const ARTIST_SEARCH_COLUMNS: [&str; 6] = [
+    "a.name",
+    "a.english_name",
+    "a.category",
+    "a.nationality",
+    "a.bio",
+    "a.style",
+];
+const COMPOSER_NAME_COLUMNS: [&str; 3] = ["c.name", "c.full_name", "c.english_name"];
+const PIECE_SEARCH_COLUMNS: [&str; 3] = ["p.title", "p.title_en", "p.description"];
+const RECORDING_SEARCH_COLUMNS: [&str; 2] = ["r.title", "r.label"];
+const VENUE_SEARCH_COLUMNS: [&str; 3] = ["v.name", "v.address", "v.city"];
+
+/// 통합 검색 (타입별로 id, title, subtitle, image_url, detail, relevance를 같은 모양으로 조회)
+pub struct SearchRepository;
+
+impl SearchRepository {
+    /// 관련도 = 제목 점수 * 2 + 전체 점수, KOPIS에서 사라진 공연 제외
+    pub async fn search_concerts(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (title_score, title_values) = search.relevance(&["c.title"]);
+        let (text_score, text_values) = search.relevance(&CONCERT_SEARCH_COLUMNS);
+        let (condition, condition_values) = search.condition(&CONCERT_SEARCH_COLUMNS);
+
+        let query = format!(
+            "SELECT c.id, c.title,
+             CONCAT_WS(' · ', c.facility_name, DATE_FORMAT(c.start_date, '%Y-%m-%d')) as subtitle,
+             c.poster_url as image_url,
+             CONCAT_WS(' ', c.composer_info, c.cast) as detail,
+             CAST(({}) * 2 + ({}) AS DOUBLE) as relevance
+             FROM concerts c
+             WHERE {} AND c.status <> 'stale'
+             ORDER BY relevance DESC, c.start_date DESC
+             LIMIT ?",
+            title_score, text_score, condition
+        );
+
+        Self::fetch(pool, &query, [title_values, text_values, condition_values], limit).await
+    }
+
+    /// 관련도 = 이름 점수 * 2 + 전체 점수
+    pub async fn search_artists(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (name_score, name_values) = search.relevance(&ARTIST_NAME_COLUMNS);
+        let (text_score, text_values) = search.relevance(&ARTIST_SEARCH_COLUMNS);
+        let (condition, condition_values) = search.condition(&ARTIST_SEARCH_COLUMNS);
+
+        let query = format!(
+            "SELECT a.id, a.name as title,
+             CONCAT_WS(' · ', a.english_name, a.category) as subtitle,
+             a.image_url,
+             CONCAT_WS(' ', a.nationality, a.style) as detail,
+             CAST(({}) * 2 + ({}) AS DOUBLE) as relevance
+             FROM artists a
+             WHERE {}
+             ORDER BY relevance DESC, a.rating DESC
+             LIMIT ?",
+            name_score, text_score, condition
+        );
+
+        Self::fetch(pool, &query, [name_values, text_values, condition_values], limit).await
+    }
+
+    pub async fn search_composers(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (score, score_values) = search.relevance(&COMPOSER_NAME_COLUMNS);
+        let (condition, condition_values) = search.condition(&COMPOSER_NAME_COLUMNS);
+
+        let query = format!(
+            "SELECT c.id, c.name as title,
+             CONCAT_WS(' · ', c.english_name, c.period) as subtitle,
+             c.avatar_url as image_url,
+             c.full_name as detail,
+             CAST({} AS DOUBLE) as relevance
+             FROM composers c
+             WHERE {}
+             ORDER BY relevance DESC, c.tier ASC, c.name ASC
+             LIMIT ?",
+            score, condition
+        );
+
+        Self::fetch(pool, &query, [score_values, condition_values, Vec::new()], limit).await
+    }
+
+    /// 곡 정보 또는 작곡가 이름으로 검색 (이미지는 작곡가 아바타)
+    pub async fn search_pieces(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (piece_score, piece_values) = search.relevance(&PIECE_SEARCH_COLUMNS);
+        let (composer_score, composer_values) = search.relevance(&COMPOSER_NAME_COLUMNS);
+        let (piece_condition, mut condition_values) = search.condition(&PIECE_SEARCH_COLUMNS);
+        let (composer_condition, composer_condition_values) =
+            search.condition(&COMPOSER_NAME_COLUMNS);
+        condition_values.extend(composer_condition_values);
+
+        let query = format!(
+            "SELECT p.id, p.title,
+             CONCAT_WS(' · ', c.name, p.title_en) as subtitle,
+             c.avatar_url as image_url,
+             CONCAT_WS(' ', c.full_name, c.english_name) as detail,
+             CAST(({}) + ({}) AS DOUBLE) as relevance
+             FROM pieces p
+             JOIN composers c ON p.composer_id = c.id
+             WHERE ({} OR {})
+             ORDER BY relevance DESC, p.title ASC
+             LIMIT ?",
+            piece_score, composer_score, piece_condition, composer_condition
+        );
+
+        Self::fetch(pool, &query, [piece_values, composer_values, condition_values], limit).await
+    }
+
+    /// 음반 제목/레이블 또는 아티스트 이름으로 검색 (음반 일치 가중)
+    pub async fn search_recordings(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (recording_score, recording_values) = search.relevance(&RECORDING_SEARCH_COLUMNS);
+        let (artist_score, artist_values) = search.relevance(&ARTIST_NAME_COLUMNS);
+        let (recording_condition, mut condition_values) =
+            search.condition(&RECORDING_SEARCH_COLUMNS);
+        let (artist_condition, artist_condition_values) = search.condition(&ARTIST_NAME_COLUMNS);
+        condition_values.extend(artist_condition_values);
+
+        let query = format!(
+            "SELECT r.id, r.title,
+             CONCAT_WS(' · ', a.name, r.year) as subtitle,
+             r.cover_url as image_url,
+             CONCAT_WS(' ', a.english_name, r.label) as detail,
+             CAST(({}) * 2 + ({}) AS DOUBLE) as relevance
+             FROM recordings r
+             JOIN artists a ON r.artist_id = a.id
+             WHERE ({} OR {})
+             ORDER BY relevance DESC, r.release_date DESC
+             LIMIT ?",
+            recording_score, artist_score, recording_condition, artist_condition
+        );
+
+        Self::fetch(pool, &query, [recording_values, artist_values, condition_values], limit).await
+    }
+
+    pub async fn search_venues(
+        pool: &DbPool,
+        search: &SearchText,
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let (score, score_values) = search.relevance(&VENUE_SEARCH_COLUMNS);
+        let (condition, condition_values) = search.condition(&VENUE_SEARCH_COLUMNS);
+
+        let query = format!(
+            "SELECT v.id, v.name as title,
+             v.address as subtitle,
+             CAST(NULL AS CHAR) as image_url,
+             CONCAT_WS(' ', v.province, v.city) as detail,
+             CAST({} AS DOUBLE) as relevance
+             FROM venues v
+             WHERE {}
+             ORDER BY relevance DESC, v.name ASC
+             LIMIT ?",
+            score, condition
+        );
+
+        Self::fetch(pool, &query, [score_values, condition_values, Vec::new()], limit).await
+    }
+
+    pub async fn find_suggest_composers(pool: &DbPool) -> Result<Vec<SuggestRow>, Error> {
+        sqlx::query_as::<_, SuggestRow>(
+            "SELECT id, name as title,
+             CONCAT_WS(' · ', english_name, period) as subtitle,
+             avatar_url as image_url,
+             full_name as alt_name, english_name
+             FROM composers",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_suggest_artists(pool: &DbPool) -> Result<Vec<SuggestRow>, Error> {
+        sqlx::query_as::<_, SuggestRow>(
+            "SELECT id, name as title,
+             CONCAT_WS(' · ', english_name, category) as subtitle,
+             image_url,
+             CAST(NULL AS CHAR) as alt_name, english_name
+             FROM artists",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_suggest_pieces(pool: &DbPool) -> Result<Vec<SuggestRow>, Error> {
+        sqlx::query_as::<_, SuggestRow>(
+            "SELECT p.id, p.title,
+             CONCAT_WS(' · ', c.name, p.title_en) as subtitle,
+             c.avatar_url as image_url,
+             p.opus_number as alt_name, p.title_en as english_name
+             FROM pieces p
+             JOIN composers c ON p.composer_id = c.id",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 운영 중인 공연장만
+    pub async fn find_suggest_venues(pool: &DbPool) -> Result<Vec<SuggestRow>, Error> {
+        sqlx::query_as::<_, SuggestRow>(
+            "SELECT id, name as title,
+             CONCAT_WS(' ', province, city) as subtitle,
+             CAST(NULL AS CHAR) as image_url,
+             CAST(NULL AS CHAR) as alt_name, CAST(NULL AS CHAR) as english_name
+             FROM venues
+             WHERE is_active = TRUE",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 자동완성 대상 테이블의 (행 수, 마지막 수정 시각), 값이 바뀌면 인덱스 재생성
+    pub async fn suggest_fingerprint(
+        pool: &DbPool,
+    ) -> Result<Vec<(i64, Option<NaiveDateTime>)>, Error> {
+        sqlx::query_as::<_, (i64, Option<NaiveDateTime>)>(
+            "SELECT COUNT(*), MAX(updated_at) FROM composers
+             UNION ALL SELECT COUNT(*), MAX(updated_at) FROM artists
+             UNION ALL SELECT COUNT(*), MAX(updated_at) FROM pieces
+             UNION ALL SELECT COUNT(*), MAX(updated_at) FROM venues",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 바인딩 값은 쿼리의 ? 순서대로 (마지막 ?는 limit)
+    async fn fetch(
+        pool: &DbPool,
+        query: &str,
+        bindings: [Vec<String>; 3],
+        limit: i64,
+    ) -> Result<Vec<SearchRow>, Error> {
+        let mut sql_query = sqlx::query_as::<_, SearchRow>(query);
+        for value in bindings.into_iter().flatten() {
+            sql_query = sql_query.bind(value);
+        }
+
+        sql_query.bind(limit).fetch_all(pool).await
+    }
+}
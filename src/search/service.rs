@@ -0,0 +1,93 @@
+use super::model::{
+    SearchGroup, SearchHit, SearchResults, SEARCH_TYPES, TYPE_ARTIST, TYPE_COMPOSER,
+    TYPE_CONCERT, TYPE_PIECE, TYPE_RECORDING, TYPE_VENUE,
+};
+use super::repository::SearchRepository;
+use super::text::SearchText;
+use crate::db::DbPool;
+
+/// 타입별 기본 결과 수
+pub const DEFAULT_LIMIT: i64 = 5;
+/// 타입별 최대 결과 수
+pub const MAX_LIMIT: i64 = 20;
+
+pub struct SearchService;
+
+impl SearchService {
+    /// 통합 검색
+    /// types가 없으면 전체 타입, 각 타입 limit개까지 조회 후 최고 관련도가 높은 타입부터 정렬
+    pub async fn search(
+        pool: &DbPool,
+        q: Option<String>,
+        types: Option<Vec<String>>,
+        limit: Option<i64>,
+    ) -> Result<SearchResults, String> {
+        let query = q.as_deref().map(str::trim).unwrap_or_default().to_string();
+        let search = match SearchText::build(pool, Some(&query))
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(search) => search,
+            None => return Ok(SearchResults { query, groups: Vec::new() }),
+        };
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        let mut groups = Vec::new();
+        for search_type in SEARCH_TYPES {
+            if let Some(types) = &types {
+                if !types.iter().any(|t| t == search_type) {
+                    continue;
+                }
+            }
+
+            let rows = match search_type {
+                TYPE_CONCERT => SearchRepository::search_concerts(pool, &search, limit).await,
+                TYPE_ARTIST => SearchRepository::search_artists(pool, &search, limit).await,
+                TYPE_COMPOSER => SearchRepository::search_composers(pool, &search, limit).await,
+                TYPE_PIECE => SearchRepository::search_pieces(pool, &search, limit).await,
+                TYPE_RECORDING => SearchRepository::search_recordings(pool, &search, limit).await,
+                TYPE_VENUE => SearchRepository::search_venues(pool, &search, limit).await,
+                _ => continue,
+            }
+            .map_err(|e| e.to_string())?;
+
+            if rows.is_empty() {
+                continue;
+            }
+
+            let hits = rows
+                .into_iter()
+                .map(|row| SearchHit {
+                    highlight: search.highlight(&[
+                        Some(&row.title),
+                        row.subtitle.as_deref(),
+                        row.detail.as_deref(),
+                    ]),
+                    r#type: search_type.to_string(),
+                    id: row.id,
+                    title: row.title,
+                    subtitle: row.subtitle,
+                    image_url: row.image_url,
+                    score: row.relevance,
+                })
+                .collect();
+
+            groups.push(SearchGroup {
+                r#type: search_type.to_string(),
+                hits,
+            });
+        }
+
+        // 정렬이 안정적이므로 관련도가 같으면 SEARCH_TYPES 순서 유지 (LIKE 검색은 모두 0)
+        groups.sort_by(|a, b| top_score(b).total_cmp(&top_score(a)));
+
+        Ok(SearchResults {
+            query: search.query,
+            groups,
+        })
+    }
+}
+
+fn top_score(group: &SearchGroup) -> f64 {
+    group.hits.first().map(|hit| hit.score).unwrap_or_default()
+}
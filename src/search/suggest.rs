@@ -0,0 +1,264 @@
+use super::model::{SuggestRow, Suggestion, SUGGEST_TYPES, TYPE_ARTIST, TYPE_COMPOSER, TYPE_PIECE, TYPE_VENUE};
+use super::repository::SearchRepository;
+use crate::db::DbPool;
+use crate::logger::Logger;
+use chrono::NaiveDateTime;
+use sqlx::Error;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::time::{sleep, Duration};
+
+/// 기본 결과 수
+pub const DEFAULT_LIMIT: usize = 10;
+/// 최대 결과 수
+pub const MAX_LIMIT: usize = 20;
+/// 테이블 변경 확인 주기 기본값 (SUGGEST_REFRESH_SECONDS로 변경)
+const DEFAULT_REFRESH_SECONDS: u64 = 30;
+/// 이름 하나에서 키를 만들 단어 수 (단어 시작 위치마다 키 생성)
+const MAX_KEY_WORDS: usize = 8;
+/// 키 최대 길이 (이보다 긴 입력은 앞부분만 비교)
+const MAX_KEY_LENGTH: usize = 40;
+/// 한 번에 비교할 최대 키 수 (한두 글자 입력 시 과도한 순회 방지)
+const MAX_CANDIDATES: usize = 2000;
+
+/// 한글 초성 (호환 자모)
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+#[derive(Debug)]
+struct Entry {
+    r#type: &'static str,
+    suggestion: Suggestion,
+}
+
+#[derive(Debug)]
+struct Key {
+    key: String,
+    entry: usize,
+    /// 이름 중간 단어부터 시작하는 키 (예: "Seong-Jin Cho"의 "cho")
+    inner_word: bool,
+}
+
+/// 정렬된 키 목록 (이진 탐색으로 접두어 범위를 찾음)
+#[derive(Debug, Default)]
+struct PrefixIndex {
+    entries: Vec<Entry>,
+    keys: Vec<Key>,
+}
+
+type Fingerprint = Vec<(i64, Option<NaiveDateTime>)>;
+
+/// 자동완성용 메모리 접두어 인덱스 (작곡가, 아티스트, 곡, 공연장)
+///
+/// 한글/영문 이름과 초성(예: "ㅈㅅㅈ" → 조성진)으로 찾으며, 공백과 기호는 무시
+/// 서버 시작 시 생성하고 대상 테이블의 행 수/수정 시각이 바뀌면 다시 생성
+pub struct SuggestIndex {
+    index: RwLock<PrefixIndex>,
+    fingerprint: Mutex<Option<Fingerprint>>,
+}
+
+impl SuggestIndex {
+    /// DB에서 인덱스 생성
+    pub async fn build(pool: &DbPool) -> Result<Self, Error> {
+        let index = Self {
+            index: RwLock::new(PrefixIndex::default()),
+            fingerprint: Mutex::new(None),
+        };
+        index.refresh(pool).await?;
+        Ok(index)
+    }
+
+    /// 인덱스 생성 후 변경 확인 백그라운드 태스크 시작
+    /// 생성에 실패하면 빈 인덱스로 시작하고 다음 확인 때 다시 시도
+    pub async fn start(pool: DbPool) -> Arc<Self> {
+        let index = match Self::build(&pool).await {
+            Ok(index) => index,
+            Err(e) => {
+                Logger::warn("SEARCH", &format!("Failed to build suggest index: {}", e));
+                Self {
+                    index: RwLock::new(PrefixIndex::default()),
+                    fingerprint: Mutex::new(None),
+                }
+            }
+        };
+        let index = Arc::new(index);
+
+        let interval = std::env::var("SUGGEST_REFRESH_SECONDS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|&v| v > 0)
+            .unwrap_or(DEFAULT_REFRESH_SECONDS);
+
+        let runner = index.clone();
+        tokio::spawn(async move {
+            loop {
+                sleep(Duration::from_secs(interval)).await;
+                if let Err(e) = runner.refresh(&pool).await {
+                    Logger::warn("SEARCH", &format!("Failed to refresh suggest index: {}", e));
+                }
+            }
+        });
+
+        index
+    }
+
+    /// 대상 테이블이 바뀌었으면 다시 생성 (재생성 여부 반환)
+    pub async fn refresh(&self, pool: &DbPool) -> Result<bool, Error> {
+        let fingerprint = SearchRepository::suggest_fingerprint(pool).await?;
+        if self.fingerprint.lock().unwrap().as_ref() == Some(&fingerprint) {
+            return Ok(false);
+        }
+
+        let mut index = PrefixIndex::default();
+        index.add_rows(TYPE_COMPOSER, SearchRepository::find_suggest_composers(pool).await?);
+        index.add_rows(TYPE_ARTIST, SearchRepository::find_suggest_artists(pool).await?);
+        index.add_rows(TYPE_PIECE, SearchRepository::find_suggest_pieces(pool).await?);
+        index.add_rows(TYPE_VENUE, SearchRepository::find_suggest_venues(pool).await?);
+        index.keys.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+
+        Logger::info(
+            "SEARCH",
+            &format!(
+                "Suggest index built: {} entries, {} keys",
+                index.entries.len(),
+                index.keys.len()
+            ),
+        );
+
+        *self.index.write().unwrap() = index;
+        *self.fingerprint.lock().unwrap() = Some(fingerprint);
+        Ok(true)
+    }
+
+    /// 접두어로 시작하는 항목
+    /// 정렬: 입력과 키가 같은 항목 → 이름 첫 단어부터 일치 → 짧은 이름 → 타입 순서
+    pub fn suggest(&self, q: &str, types: Option<&[String]>, limit: Option<usize>) -> Vec<Suggestion> {
+        let prefix = normalize(q);
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        let index = self.index.read().unwrap();
+        let start = index.keys.partition_point(|k| k.key.as_str() < prefix.as_str());
+
+        // 항목별 최고 순위 (정확히 일치 여부, 중간 단어 여부)
+        let mut best: HashMap<usize, (bool, bool)> = HashMap::new();
+        for key in index.keys[start..]
+            .iter()
+            .take_while(|k| k.key.starts_with(&prefix))
+            .take(MAX_CANDIDATES)
+        {
+            let entry = &index.entries[key.entry];
+            if let Some(types) = types {
+                if !types.iter().any(|t| t == entry.r#type) {
+                    continue;
+                }
+            }
+
+            let rank = (key.key != prefix, key.inner_word);
+            best.entry(key.entry)
+                .and_modify(|current| *current = (*current).min(rank))
+                .or_insert(rank);
+        }
+
+        let mut ranked: Vec<(usize, (bool, bool))> = best.into_iter().collect();
+        ranked.sort_by_cached_key(|&(entry, rank)| {
+            let entry = &index.entries[entry];
+            (
+                rank,
+                entry.suggestion.title.chars().count(),
+                SUGGEST_TYPES.iter().position(|t| *t == entry.r#type),
+                entry.suggestion.title.clone(),
+            )
+        });
+
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(entry, _)| index.entries[entry].suggestion.clone())
+            .collect()
+    }
+}
+
+impl PrefixIndex {
+    fn add_rows(&mut self, r#type: &'static str, rows: Vec<SuggestRow>) {
+        for row in rows {
+            let entry = self.entries.len();
+            for name in [Some(row.title.as_str()), row.alt_name.as_deref(), row.english_name.as_deref()]
+                .into_iter()
+                .flatten()
+            {
+                self.add_keys(entry, name);
+            }
+
+            self.entries.push(Entry {
+                r#type,
+                suggestion: Suggestion {
+                    r#type: r#type.to_string(),
+                    id: row.id,
+                    title: row.title,
+                    subtitle: row.subtitle,
+                    image_url: row.image_url,
+                },
+            });
+        }
+    }
+
+    /// 이름 전체와 각 단어부터 시작하는 부분, 한글이면 초성도 키로 추가
+    fn add_keys(&mut self, entry: usize, name: &str) {
+        let word_starts = name
+            .char_indices()
+            .filter(|&(i, c)| {
+                !c.is_whitespace() && (i == 0 || name[..i].ends_with(char::is_whitespace))
+            })
+            .map(|(i, _)| i)
+            .take(MAX_KEY_WORDS);
+
+        let has_hangul = name.chars().any(|c| ('\u{AC00}'..='\u{D7A3}').contains(&c));
+
+        for (n, start) in word_starts.enumerate() {
+            let part = &name[start..];
+            let key = normalize(part);
+            if key.is_empty() {
+                continue;
+            }
+
+            if has_hangul {
+                let initials = normalize(&choseong(part));
+                if initials != key {
+                    self.keys.push(Key {
+                        key: initials,
+                        entry,
+                        inner_word: n > 0,
+                    });
+                }
+            }
+            self.keys.push(Key {
+                key,
+                entry,
+                inner_word: n > 0,
+            });
+        }
+    }
+}
+
+/// 소문자 변환, 공백/기호 제거, 최대 길이로 자름
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .take(MAX_KEY_LENGTH)
+        .collect()
+}
+
+/// 한글 음절을 초성으로 바꾸고 나머지 문자는 그대로 둠
+fn choseong(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{AC00}'..='\u{D7A3}' => CHOSEONG[((c as u32 - 0xAC00) / 588) as usize],
+            _ => c,
+        })
+        .collect()
+}
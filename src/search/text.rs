@@ -0,0 +1,256 @@
+use crate::db::DbPool;
+use sqlx::Error;
+
+/// MySQL ngram_token_size (기본값 2), 이보다 짧은 검색어는 FULLTEXT로 찾을 수 없음
+const NGRAM_TOKEN_SIZE: usize = 2;
+/// 한/영 이름 확장에 사용하는 검색어 단어 수
+const MAX_ALIAS_WORDS: usize = 5;
+/// 단어 하나당 확장할 작곡가/아티스트 수 (검색어 전체 조회 한도 = 단어 수 × 이 값)
+const MAX_ALIASES_PER_WORD: i64 = 5;
+/// 강조 구간 앞뒤로 보여줄 글자 수
+const HIGHLIGHT_CONTEXT: usize = 20;
+
+/// 검색어 (FULLTEXT ngram 검색용)
+///
+/// 검색어에 작곡가/아티스트 이름이 포함되면 다른 언어 이름도 함께 검색
+/// (예: "Chopin" → "Chopin 쇼팽 프레데리크 쇼팽")
+#[derive(Debug, Clone)]
+pub struct SearchText {
+    /// 입력한 검색어 (앞뒤 공백 제거)
+    pub query: String,
+    /// 검색어 + 한/영 대응 이름
+    pub terms: Vec<String>,
+}
+
+impl SearchText {
+    /// 검색어가 비어 있으면 None
+    pub async fn build(pool: &DbPool, query: Option<&str>) -> Result<Option<Self>, Error> {
+        let query = match query.map(str::trim).filter(|q| !q.is_empty()) {
+            Some(query) => query.to_string(),
+            None => return Ok(None),
+        };
+
+        let mut search = Self {
+            terms: vec![query.clone()],
+            query,
+        };
+
+        if search.uses_fulltext() {
+            for alias in find_aliases(pool, &search.query).await? {
+                search.add_term(alias);
+            }
+        }
+
+        Ok(Some(search))
+    }
+
+    /// ngram 토큰보다 짧은 검색어(한 글자)는 LIKE 검색
+    fn uses_fulltext(&self) -> bool {
+        self.query.chars().filter(|c| !c.is_whitespace()).count() >= NGRAM_TOKEN_SIZE
+    }
+
+    fn add_term(&mut self, term: String) {
+        let term = term.trim().to_string();
+        if !term.is_empty() && !self.terms.iter().any(|t| t.eq_ignore_ascii_case(&term)) {
+            self.terms.push(term);
+        }
+    }
+
+    /// 검색어별 MATCH 식과 바인딩 값
+    /// 한글 검색어는 자연어 모드 (ngram 단위 OR 매칭이라 띄어쓰기가 달라도 찾음)
+    /// 그 외(영문 등)는 불리언 모드 구문 검색 (짧은 ngram이 겹치는 다른 이름 제외)
+    fn matches(&self, columns: &[&str]) -> Vec<(String, String)> {
+        let (korean, other): (Vec<&String>, Vec<&String>) =
+            self.terms.iter().partition(|term| term.chars().any(is_hangul));
+        let columns = columns.join(", ");
+        let mut matches = Vec::new();
+
+        if !korean.is_empty() {
+            matches.push((
+                format!("MATCH({}) AGAINST(? IN NATURAL LANGUAGE MODE)", columns),
+                korean.iter().map(|term| term.as_str()).collect::<Vec<_>>().join(" "),
+            ));
+        }
+        if !other.is_empty() {
+            matches.push((
+                format!("MATCH({}) AGAINST(? IN BOOLEAN MODE)", columns),
+                other
+                    .iter()
+                    .map(|term| format!("\"{}\"", term.replace('"', " ")))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ));
+        }
+
+        matches
+    }
+
+    /// WHERE 조건과 바인딩 값
+    /// columns는 FULLTEXT 인덱스의 컬럼 목록과 같아야 함
+    pub fn condition(&self, columns: &[&str]) -> (String, Vec<String>) {
+        if self.uses_fulltext() {
+            let (clauses, values): (Vec<String>, Vec<String>) =
+                self.matches(columns).into_iter().unzip();
+            (format!("({})", clauses.join(" OR ")), values)
+        } else {
+            let pattern = format!("%{}%", self.query);
+            (
+                format!(
+                    "({})",
+                    columns
+                        .iter()
+                        .map(|column| format!("{} LIKE ?", column))
+                        .collect::<Vec<_>>()
+                        .join(" OR ")
+                ),
+                vec![pattern; columns.len()],
+            )
+        }
+    }
+
+    /// 관련도 점수 식과 바인딩 값 (LIKE 검색은 모두 0)
+    pub fn relevance(&self, columns: &[&str]) -> (String, Vec<String>) {
+        if self.uses_fulltext() {
+            let (clauses, values): (Vec<String>, Vec<String>) =
+                self.matches(columns).into_iter().unzip();
+            (format!("({})", clauses.join(" + ")), values)
+        } else {
+            ("0".to_string(), Vec::new())
+        }
+    }
+
+    /// 검색어와 가장 길게 일치하는 부분을 <em>으로 감싼 발췌문 (HTML 이스케이프)
+    /// 띄어쓰기가 다른 경우도 찾도록 단어 일부(2글자 이상)까지 비교, 앞선 텍스트 우선
+    pub fn highlight(&self, texts: &[Option<&str>]) -> Option<String> {
+        let words: Vec<Vec<char>> = self
+            .terms
+            .iter()
+            .flat_map(|term| term.split_whitespace())
+            .map(|word| word.chars().map(fold_case).collect())
+            .collect();
+
+        texts.iter().flatten().find_map(|text| {
+            let chars: Vec<char> = text.chars().collect();
+            let folded: Vec<char> = chars.iter().copied().map(fold_case).collect();
+            longest_match(&folded, &words).map(|(start, len)| snippet(&chars, start, len))
+        })
+    }
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+fn fold_case(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// (시작 위치, 길이)
+fn longest_match(text: &[char], words: &[Vec<char>]) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+
+    for word in words {
+        let min_len = word.len().min(NGRAM_TOKEN_SIZE);
+        for len in (min_len..=word.len()).rev() {
+            if best.is_some_and(|(_, best_len)| best_len >= len) {
+                break;
+            }
+            let found = word
+                .windows(len)
+                .find_map(|part| text.windows(len).position(|window| window == part));
+            if let Some(start) = found {
+                best = Some((start, len));
+                break;
+            }
+        }
+    }
+
+    best
+}
+
+fn snippet(chars: &[char], start: usize, len: usize) -> String {
+    let end = start + len;
+    let from = start.saturating_sub(HIGHLIGHT_CONTEXT);
+    let to = (end + HIGHLIGHT_CONTEXT).min(chars.len());
+
+    let mut snippet = String::new();
+    if from > 0 {
+        snippet.push('…');
+    }
+    push_escaped(&mut snippet, &chars[from..start]);
+    snippet.push_str("<em>");
+    push_escaped(&mut snippet, &chars[start..end]);
+    snippet.push_str("</em>");
+    push_escaped(&mut snippet, &chars[end..to]);
+    if to < chars.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+fn push_escaped(out: &mut String, chars: &[char]) {
+    for &c in chars {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// 검색어 단어와 이름이 겹치는 작곡가/아티스트의 한/영 이름
+/// ft_composers_name, ft_artists_name 인덱스에서 단어별 구문 검색 (이름에 단어가 포함된 경우)
+async fn find_aliases(pool: &DbPool, query: &str) -> Result<Vec<String>, Error> {
+    let mut aliases = Vec::new();
+
+    // 검색어 전체 + 단어별 (예: "조성진 쇼팽" → "조성진 쇼팽", "조성진", "쇼팽")
+    let mut words: Vec<&str> = vec![query];
+    for word in query.split_whitespace().take(MAX_ALIAS_WORDS) {
+        if word.chars().count() >= NGRAM_TOKEN_SIZE && !words.contains(&word) {
+            words.push(word);
+        }
+    }
+
+    // 연산자 없는 구문 나열 = 하나라도 포함되면 일치
+    let against = words
+        .iter()
+        .map(|word| format!("\"{}\"", word.replace('"', " ")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let limit = MAX_ALIASES_PER_WORD * words.len() as i64;
+
+    let composers = sqlx::query_as::<_, (String, String, String)>(
+        "SELECT name, full_name, english_name FROM composers
+         WHERE MATCH(name, full_name, english_name) AGAINST(? IN BOOLEAN MODE)
+         ORDER BY MATCH(name, full_name, english_name) AGAINST(? IN BOOLEAN MODE) DESC
+         LIMIT ?",
+    )
+    .bind(&against)
+    .bind(&against)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    for (name, full_name, english_name) in composers {
+        aliases.extend([name, full_name, english_name]);
+    }
+
+    let artists = sqlx::query_as::<_, (String, String)>(
+        "SELECT name, english_name FROM artists
+         WHERE MATCH(name, english_name) AGAINST(? IN BOOLEAN MODE)
+         ORDER BY MATCH(name, english_name) AGAINST(? IN BOOLEAN MODE) DESC
+         LIMIT ?",
+    )
+    .bind(&against)
+    .bind(&against)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    for (name, english_name) in artists {
+        aliases.extend([name, english_name]);
+    }
+
+    Ok(aliases)
+}
@@ -0,0 +1,115 @@
+use crate::logger::Logger;
+use sqlx::MySqlPool;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// 키/값 테이블(setting_key, setting_value)에 저장하는 관리자 설정
+///
+/// 우선순위: DB > 환경변수 > 기본값 (잘못된 값은 경고 후 무시)
+pub trait KeyValueSettings: Default {
+    /// 설정 테이블 이름
+    const TABLE: &'static str;
+    /// 로그 모듈 이름
+    const LOG_MODULE: &'static str;
+    /// (환경변수, 설정 키)
+    const ENV_KEYS: &'static [(&'static str, &'static str)];
+
+    /// 단일 설정값 적용 (알 수 없는 키, 잘못된 값은 Err)
+    fn apply(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+/// 현재 유효한 설정 조회
+pub async fn load<T: KeyValueSettings>(pool: &MySqlPool) -> T {
+    let mut settings = T::default();
+
+    for (env_key, key) in T::ENV_KEYS {
+        if let Ok(value) = std::env::var(env_key) {
+            apply_or_warn(&mut settings, key, &value);
+        }
+    }
+
+    match sqlx::query_as::<_, (String, String)>(&format!(
+        "SELECT setting_key, setting_value FROM {}",
+        T::TABLE
+    ))
+    .fetch_all(pool)
+    .await
+    {
+        Ok(rows) => {
+            for (key, value) in rows {
+                apply_or_warn(&mut settings, &key, &value);
+            }
+        }
+        Err(e) => {
+            Logger::warn(
+                T::LOG_MODULE,
+                &format!("Failed to load {}, using env/defaults: {}", T::TABLE, e),
+            );
+        }
+    }
+
+    settings
+}
+
+/// 검증을 마친 설정값 저장 (values: (설정 키, 값), 한 트랜잭션으로 저장)
+pub async fn save<T: KeyValueSettings>(
+    pool: &MySqlPool,
+    values: Vec<(&str, String)>,
+) -> Result<(), String> {
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+
+    for (key, value) in values {
+        sqlx::query(&format!(
+            "INSERT INTO {} (setting_key, setting_value)
+             VALUES (?, ?)
+             ON DUPLICATE KEY UPDATE setting_value = VALUES(setting_value)",
+            T::TABLE
+        ))
+        .bind(key)
+        .bind(&value)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to save {} {}: {}", T::TABLE, key, e))?;
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())
+}
+
+fn apply_or_warn<T: KeyValueSettings>(settings: &mut T, key: &str, value: &str) {
+    if let Err(e) = settings.apply(key, value) {
+        Logger::warn(
+            T::LOG_MODULE,
+            &format!("Ignoring {} '{}': {}", T::TABLE, key, e),
+        );
+    }
+}
+
+/// 쉼표로 구분된 목록 (예: "CCCA, GGGA" → ["CCCA", "GGGA"])
+pub fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 숫자 설정값 파싱 후 범위 검증
+pub fn parse_in_range<N>(value: &str, min: N, max: N) -> Result<N, String>
+where
+    N: FromStr + PartialOrd + Display,
+{
+    let number = value
+        .trim()
+        .parse::<N>()
+        .map_err(|_| format!("Invalid number: {}", value))?;
+
+    validate_range(number, min, max)
+}
+
+pub fn validate_range<N: PartialOrd + Display>(value: N, min: N, max: N) -> Result<N, String> {
+    if value < min || value > max {
+        return Err(format!("Value must be between {} and {}", min, max));
+    }
+
+    Ok(value)
+}
@@ -1,5 +1,9 @@
 use sqlx::MySqlPool;
 use super::model::{Venue, CreateVenue, UpdateVenue};
+use crate::search::SearchText;
+
+/// FULLTEXT 인덱스 ft_venues_search 컬럼
+const VENUE_SEARCH_COLUMNS: [&str; 3] = ["name", "address", "city"];
 
 pub struct VenueRepository;
 
@@ -176,38 +180,35 @@ impl VenueRepository {
     }
 
     /// Full-text search across venues with pagination
+    /// 검색어가 있으면 관련도 순, 없으면 이름 순
     pub async fn search_venues(
         pool: &MySqlPool,
-        search_query: Option<&str>,
+        search: Option<&SearchText>,
         offset: i64,
         limit: i64,
     ) -> Result<Vec<Venue>, sqlx::Error> {
-        let search_pattern = search_query
-            .filter(|q| !q.trim().is_empty())
-            .map(|q| format!("%{}%", q));
-
-        let mut query = String::from(
-            "SELECT * FROM venues WHERE 1=1"
-        );
-
-        // Text search across multiple fields
-        if search_pattern.is_some() {
-            query.push_str(
-                " AND (name LIKE ? OR address LIKE ? OR city LIKE ?)"
-            );
-        }
+        let mut bindings: Vec<String> = Vec::new();
+
+        let mut query = match search {
+            Some(search) => {
+                let (score, score_values) = search.relevance(&VENUE_SEARCH_COLUMNS);
+                let (condition, condition_values) = search.condition(&VENUE_SEARCH_COLUMNS);
+                bindings.extend(score_values);
+                bindings.extend(condition_values);
+                format!(
+                    "SELECT *, {} as relevance FROM venues WHERE {}",
+                    score, condition
+                )
+            }
+            None => String::from("SELECT *, 0 as relevance FROM venues WHERE 1=1"),
+        };
 
-        // Order by name
-        query.push_str(" ORDER BY name ASC LIMIT ? OFFSET ?");
+        // Order by relevance, then name
+        query.push_str(" ORDER BY relevance DESC, name ASC LIMIT ? OFFSET ?");
 
         let mut sql_query = sqlx::query_as::<_, Venue>(&query);
-
-        // Bind search query with wildcards
-        if let Some(ref pattern) = search_pattern {
-            sql_query = sql_query
-                .bind(pattern) // name
-                .bind(pattern) // address
-                .bind(pattern); // city
+        for value in bindings {
+            sql_query = sql_query.bind(value);
         }
 
         // Bind pagination
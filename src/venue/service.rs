@@ -2,6 +2,7 @@ use super::model::{Venue, CreateVenue, UpdateVenue};
 use super::repository::VenueRepository;
 use crate::db::DbPool;
 use crate::logger::Logger;
+use crate::search::SearchText;
 
 pub struct VenueService;
 
@@ -55,9 +56,13 @@ impl VenueService {
         offset: i64,
         limit: i64,
     ) -> Result<Vec<Venue>, String> {
+        let search = SearchText::build(pool, search_query.as_deref())
+            .await
+            .map_err(|e| format!("Failed to search venues: {}", e))?;
+
         VenueRepository::search_venues(
             pool,
-            search_query.as_deref(),
+            search.as_ref(),
             offset,
             limit
         )
@@ -0,0 +1,190 @@
+mod common;
+
+use ClassicMap_back::kopis::KopisClient;
+use common::{fixture, KopisStub, TEST_API_KEY};
+
+fn client(stub: &KopisStub) -> KopisClient {
+    KopisClient::new(TEST_API_KEY.to_string(), stub.base_url().to_string())
+}
+
+#[tokio::test]
+async fn fetch_venue_list_parses_fixture() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/prfplc", "prfplc_list.xml");
+
+    let response = client(&stub)
+        .fetch_venue_list(1, 100, Some("20200101"))
+        .await
+        .expect("venue list");
+
+    assert_eq!(response.db.len(), 2);
+    assert_eq!(response.db[0].facility_id, "FC000001");
+    assert_eq!(response.db[0].facility_name, "예술의전당");
+    assert_eq!(response.db[0].parse_opening_year(), Some(1988));
+
+    let request = &stub.requests()[0];
+    assert!(request.contains(&format!("service={}", TEST_API_KEY)));
+    assert!(request.contains("afterdate=20200101"));
+}
+
+#[tokio::test]
+async fn fetch_venue_detail_parses_halls_and_seats() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/prfplc/FC000001", "prfplc_FC000001.xml");
+
+    let detail = client(&stub)
+        .fetch_venue_detail("FC000001")
+        .await
+        .expect("venue detail")
+        .db;
+
+    assert_eq!(detail.parse_seats(), Some(4583));
+
+    let halls = detail.halls.expect("halls").halls;
+    assert_eq!(halls.len(), 2);
+    assert_eq!(halls[1].hall_id, "FC000001-04");
+    assert_eq!(halls[1].parse_seats(), Some(2323));
+}
+
+#[tokio::test]
+async fn fetch_concert_detail_parses_vendors_and_images() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/pblprfr/PF000102", "pblprfr_PF000102.xml");
+
+    let detail = client(&stub)
+        .fetch_concert_detail("PF000102")
+        .await
+        .expect("concert detail")
+        .db
+        .expect("concert");
+
+    assert_eq!(detail.facility_id, "FC000001");
+    assert!(detail.is_visit());
+    assert_eq!(detail.parse_status(), "upcoming");
+    assert_eq!(
+        detail.parse_start_date().map(|d| d.to_string()),
+        Some("2026-11-14".to_string())
+    );
+    assert_eq!(detail.intro_images.expect("images").images.len(), 1);
+    assert_eq!(detail.ticket_vendors.expect("vendors").vendors.len(), 1);
+}
+
+#[tokio::test]
+async fn fetch_concert_detail_maps_cancelled_and_missing() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/pblprfr/PF000102", "pblprfr_PF000102_cancelled.xml")
+        .serve_fixture("/pblprfr/PF000199", "empty_dbs.xml");
+
+    let client = client(&stub);
+
+    let detail = client
+        .fetch_concert_detail("PF000102")
+        .await
+        .expect("concert detail")
+        .db
+        .expect("concert");
+    assert_eq!(detail.parse_status(), "cancelled");
+
+    // 삭제된 공연은 빈 <dbs/>
+    let missing = client
+        .fetch_concert_detail("PF000199")
+        .await
+        .expect("concert detail");
+    assert!(missing.db.is_none());
+}
+
+#[tokio::test]
+async fn fetch_boxoffice_rankings_sends_area_and_genre() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/boxoffice?area=11", "boxoffice_11.xml");
+
+    let response = client(&stub)
+        .fetch_boxoffice_rankings(
+            "20261001",
+            "20261031",
+            Some("CCCA"),
+            Some("11"),
+            Some("month"),
+            Some("1000"),
+        )
+        .await
+        .expect("boxoffice");
+
+    assert_eq!(response.boxof.len(), 4);
+    assert_eq!(response.boxof[0].ranking, 1);
+    assert_eq!(response.boxof[0].performance_id, "PF000102");
+
+    let request = &stub.requests()[0];
+    assert!(request.contains("catecode=CCCA"));
+    assert!(request.contains("stdate=20261001"));
+    assert!(request.contains("ststype=month&date=20261031"));
+    assert!(request.contains("srchseatscale=1000"));
+}
+
+#[tokio::test]
+async fn empty_results_parse_as_empty_lists() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/prfplc", "empty_dbs.xml")
+        .serve_fixture("/pblprfr", "empty_dbs.xml")
+        .serve_fixture("/boxoffice", "empty_boxofs.xml");
+
+    let client = client(&stub);
+
+    assert!(client.fetch_all_venues(None).await.expect("venues").is_empty());
+    assert!(client
+        .fetch_all_concerts("20261101", "20261130", Some("CCCA"), None)
+        .await
+        .expect("concerts")
+        .is_empty());
+    assert!(client
+        .fetch_boxoffice_rankings("20261001", "20261031", None, None, None, None)
+        .await
+        .expect("boxoffice")
+        .boxof
+        .is_empty());
+}
+
+#[tokio::test]
+async fn fetch_all_venues_stops_after_short_page() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/prfplc", "prfplc_list.xml");
+
+    let venues = client(&stub).fetch_all_venues(None).await.expect("venues");
+
+    assert_eq!(venues.len(), 2);
+    assert_eq!(stub.request_count("/prfplc"), 1);
+}
+
+#[tokio::test]
+async fn malformed_xml_is_an_error() {
+    let stub = KopisStub::start().await;
+    stub.serve_fixture("/prfplc", "malformed.xml")
+        .serve_fixture("/pblprfr/PF000101", "malformed.xml")
+        .serve_fixture("/boxoffice", "malformed.xml");
+
+    let client = client(&stub);
+
+    let err = client.fetch_venue_list(1, 100, None).await.unwrap_err();
+    assert!(err.contains("Failed to parse XML"), "{}", err);
+
+    assert!(client.fetch_concert_detail("PF000101").await.is_err());
+    assert!(client
+        .fetch_boxoffice_rankings("20261001", "20261031", None, None, None, None)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn http_error_status_is_an_error() {
+    let stub = KopisStub::start().await;
+    stub.serve_status("/prfplc/FC000001", 500, fixture("empty_dbs.xml"));
+
+    let err = client(&stub)
+        .fetch_venue_detail("FC000001")
+        .await
+        .unwrap_err();
+    assert!(err.contains("KOPIS API returned error"), "{}", err);
+
+    // 등록되지 않은 경로는 404
+    assert!(client(&stub).fetch_venue_detail("FC999999").await.is_err());
+}